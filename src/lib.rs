@@ -27,6 +27,8 @@
 //!
 //! 4. **Serialization-ready** - All types can be converted to JSON for clients.
 //!
+//! Most consumers only need `use runecast_state::prelude::*;`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -56,6 +58,7 @@
 //! app.apply_player_event(1, PlayerEvent::JoinLobby { lobby_id }).unwrap();
 //! ```
 
+pub mod prelude;
 pub mod state;
 
 // Re-export everything from state module at crate root
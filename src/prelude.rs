@@ -0,0 +1,19 @@
+//! Commonly used types in one import.
+//!
+//! ```rust
+//! use runecast_state::prelude::*;
+//!
+//! let mut app = AppState::new();
+//! app.apply_player_event(1, PlayerEvent::Connect).unwrap();
+//! ```
+//!
+//! Everything here is also available from [`crate::state`]; the prelude only
+//! saves listing it.
+
+pub use crate::state::{
+    AppError, AppState, Authorizer, ChatError, Command, Connection, ConnectionManager, Cursor,
+    DefaultAuthorizer, Game, GameConfig, GameError, GameManager, GamePlayer, GameStatus, Grid,
+    GridCell, InvalidTransition, Lobby, LobbyError, LobbyManager, LobbyMember, MessageKind,
+    OutboundMessage, Page, PlayerEvent, PlayerLocation, PlayerState, Position, PresenceChange,
+    RedactionProfile, RngProvider, SeededRng, SyncBundle,
+};
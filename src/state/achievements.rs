@@ -0,0 +1,157 @@
+//! Achievements unlocked during a game.
+//!
+//! `AchievementTracker` looks at plays as they happen and keeps what each
+//! player has unlocked, so every frontend agrees on them. `Game` calls the
+//! hooks; each player's achievements are listed in the results.
+
+use std::collections::HashMap;
+
+/// Letters in a word for `Achievement::LongWord`.
+pub const LONG_WORD_LETTERS: usize = 8;
+
+/// Gems collected by one word for `Achievement::GemHaul`.
+pub const GEM_HAUL: i32 = 3;
+
+/// Points a winner must have trailed the leader by at the end of a round
+/// for `Achievement::Comeback`.
+pub const COMEBACK_DEFICIT: i32 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Achievement {
+    /// Played a word of at least `LONG_WORD_LETTERS` letters
+    LongWord,
+    /// Collected `GEM_HAUL` gems with one word
+    GemHaul,
+    /// Won after trailing by `COMEBACK_DEFICIT` points at the end of a round
+    Comeback,
+}
+
+impl Achievement {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LongWord => "long_word",
+            Self::GemHaul => "gem_haul",
+            Self::Comeback => "comeback",
+        }
+    }
+}
+
+/// An achievement and the round it was unlocked in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unlocked {
+    pub achievement: Achievement,
+    pub round: u8,
+}
+
+impl Unlocked {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "achievement": self.achievement.as_str(),
+            "round": self.round
+        })
+    }
+}
+
+/// Achievements unlocked so far, each at most once per player.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AchievementTracker {
+    unlocked: HashMap<i64, Vec<Unlocked>>,
+    /// Largest gap to the leader at the end of a round, per player
+    deficits: HashMap<i64, i32>,
+}
+
+impl AchievementTracker {
+    /// A word was played. Returns the achievements it unlocked.
+    pub fn on_word(
+        &mut self,
+        player_id: i64,
+        word: &str,
+        gems: i32,
+        round: u8,
+    ) -> Vec<Achievement> {
+        let mut unlocked = Vec::new();
+        if word.chars().count() >= LONG_WORD_LETTERS
+            && self.unlock(player_id, Achievement::LongWord, round)
+        {
+            unlocked.push(Achievement::LongWord);
+        }
+        if gems >= GEM_HAUL && self.unlock(player_id, Achievement::GemHaul, round) {
+            unlocked.push(Achievement::GemHaul);
+        }
+        unlocked
+    }
+
+    /// A round ended with these scores.
+    pub fn on_round_end(&mut self, scores: &[(i64, i32)]) {
+        let Some(lead) = scores.iter().map(|(_, score)| *score).max() else {
+            return;
+        };
+        for (player_id, score) in scores {
+            let deficit = self.deficits.entry(*player_id).or_default();
+            *deficit = (*deficit).max(lead - score);
+        }
+    }
+
+    /// The game ended with these winners. Returns the achievements unlocked.
+    pub fn on_game_end(&mut self, winners: &[i64], round: u8) -> Vec<(i64, Achievement)> {
+        let mut unlocked = Vec::new();
+        for &player_id in winners {
+            let trailed = self.deficits.get(&player_id).is_some_and(|d| *d >= COMEBACK_DEFICIT);
+            if trailed && self.unlock(player_id, Achievement::Comeback, round) {
+                unlocked.push((player_id, Achievement::Comeback));
+            }
+        }
+        unlocked
+    }
+
+    fn unlock(&mut self, player_id: i64, achievement: Achievement, round: u8) -> bool {
+        let unlocked = self.unlocked.entry(player_id).or_default();
+        if unlocked.iter().any(|u| u.achievement == achievement) {
+            return false;
+        }
+        unlocked.push(Unlocked { achievement, round });
+        true
+    }
+
+    /// A player's achievements, in the order they were unlocked.
+    pub fn unlocked(&self, player_id: i64) -> &[Unlocked] {
+        self.unlocked.get(&player_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn has(&self, player_id: i64, achievement: Achievement) -> bool {
+        self.unlocked(player_id).iter().any(|u| u.achievement == achievement)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let players: serde_json::Map<String, serde_json::Value> = self
+            .unlocked
+            .iter()
+            .map(|(player_id, unlocked)| {
+                let list: Vec<_> = unlocked.iter().map(|u| u.to_json()).collect();
+                (player_id.to_string(), list.into())
+            })
+            .collect();
+        serde_json::Value::Object(players)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker() {
+        let mut tracker = AchievementTracker::default();
+        assert_eq!(tracker.on_word(1, "CAT", 3, 1), vec![Achievement::GemHaul]);
+        assert_eq!(tracker.on_word(1, "ELEPHANT", 3, 2), vec![Achievement::LongWord]);
+        assert!(tracker.on_word(1, "ELEPHANTS", 0, 3).is_empty());
+        assert_eq!(tracker.unlocked(1)[1].round, 2);
+
+        tracker.on_round_end(&[(1, 30), (2, 5)]);
+        tracker.on_round_end(&[(1, 30), (2, 40)]);
+        assert_eq!(tracker.on_game_end(&[2], 3), vec![(2, Achievement::Comeback)]);
+        assert!(tracker.on_game_end(&[1], 3).is_empty());
+        assert!(tracker.has(2, Achievement::Comeback));
+        assert_eq!(tracker.to_json()["2"][0]["achievement"], "comeback");
+    }
+}
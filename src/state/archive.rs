@@ -0,0 +1,212 @@
+//! Archive of finished games.
+//!
+//! Terminal games are summarized when they leave the `GameManager`, so lobby
+//! screens can show recent results after the full game state is gone.
+
+use std::collections::VecDeque;
+
+use super::game::{Game, GameStatus};
+use super::pagination::{Cursor, Page, SortOrder};
+
+/// Default number of archived games kept.
+pub const DEFAULT_ARCHIVE_CAPACITY: usize = 500;
+
+/// Summary of a finished or cancelled game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedGame {
+    pub game_id: String,
+    pub lobby_id: String,
+    pub status: GameStatus,
+    /// Highest scorer who didn't forfeit (None for cancelled games, ties and
+    /// empty games)
+    pub winner_id: Option<i64>,
+    pub winner_username: Option<String>,
+    pub winning_score: Option<i32>,
+    pub player_count: usize,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub ended_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ArchivedGame {
+    /// Summarize a game.
+    pub fn from_game(game: &Game) -> Self {
+        let mut winner = None;
+        if game.status == GameStatus::Finished {
+            let contenders = || game.players().filter(|p| !p.forfeited);
+            let best = contenders().map(|p| p.score()).max();
+            let mut leaders = contenders().filter(|p| Some(p.score()) == best);
+            if let (Some(leader), None) = (leaders.next(), leaders.next()) {
+                winner = Some(leader);
+            }
+        }
+
+        Self {
+            game_id: game.id.clone(),
+            lobby_id: game.lobby_id.clone(),
+            status: game.status,
+            winner_id: winner.map(|p| p.player_id),
+            winner_username: winner.map(|p| p.username.clone()),
+            winning_score: winner.map(|p| p.score()),
+            player_count: game.player_count(),
+            started_at: game.started_at,
+            ended_at: game.ended_at,
+        }
+    }
+
+    /// Time from start to end, if the game started.
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        Some(self.ended_at? - self.started_at?)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "game_id": self.game_id,
+            "status": self.status.as_str(),
+            "winner": self.winner_username,
+            "winning_score": self.winning_score,
+            "player_count": self.player_count,
+            "duration_secs": self.duration().map(|d| d.num_seconds()),
+            "ended_at": self.ended_at.map(|t| t.to_rfc3339())
+        })
+    }
+}
+
+/// Bounded archive of game summaries, oldest first.
+#[derive(Debug, Clone)]
+pub struct GameArchive {
+    games: VecDeque<ArchivedGame>,
+    capacity: usize,
+}
+
+impl Default for GameArchive {
+    fn default() -> Self {
+        Self::new(DEFAULT_ARCHIVE_CAPACITY)
+    }
+}
+
+impl GameArchive {
+    /// Create an archive keeping at most `capacity` games.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            games: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Add a summary, dropping the oldest once full.
+    pub fn push(&mut self, game: ArchivedGame) {
+        if self.games.len() >= self.capacity {
+            self.games.pop_front();
+        }
+        self.games.push_back(game);
+    }
+
+    /// Get the summary of an archived game.
+    pub fn get(&self, game_id: &str) -> Option<&ArchivedGame> {
+        self.games.iter().rev().find(|g| g.game_id == game_id)
+    }
+
+    /// Get up to `n` archived games for a lobby, most recent first.
+    pub fn for_lobby(&self, lobby_id: &str, n: usize) -> Vec<&ArchivedGame> {
+        self.games
+            .iter()
+            .rev()
+            .filter(|g| g.lobby_id == lobby_id)
+            .take(n)
+            .collect()
+    }
+
+    /// List archived games, most recent first, optionally for one lobby.
+    pub fn page(
+        &self,
+        lobby_id: Option<&str>,
+        after: Option<&Cursor>,
+        limit: usize,
+    ) -> Page<&ArchivedGame> {
+        let games = self
+            .games
+            .iter()
+            .filter(|g| lobby_id.is_none_or(|id| g.lobby_id == id))
+            .collect();
+        Page::from_items(games, after, limit, SortOrder::Descending, |g| {
+            Cursor::new(g.ended_at.unwrap_or_default(), g.game_id.as_str())
+        })
+    }
+
+    /// Number of archived games.
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    /// Check if the archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::game::{GamePlayer, GridCell, ScoreSource};
+
+    fn make_game(id: &str, scores: &[i32]) -> Game {
+        let grid = std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')));
+        let mut game = Game::new(id.to_string(), "lobby-1".to_string(), grid);
+        for (i, score) in scores.iter().enumerate() {
+            let pid = i as i64 + 1;
+            let mut player =
+                GamePlayer::new(pid, pid.to_string(), format!("P{}", pid), None, i as u8);
+            player.add_score(ScoreSource::Adjustment("test".to_string()), *score);
+            game.add_player(player).unwrap();
+        }
+        game.start().unwrap();
+        game.end().unwrap();
+        game
+    }
+
+    #[test]
+    fn test_summary_winner() {
+        let summary = ArchivedGame::from_game(&make_game("g1", &[3, 9, 4]));
+        assert_eq!(summary.winner_id, Some(2));
+        assert_eq!(summary.winning_score, Some(9));
+        assert!(summary.duration().is_some());
+
+        // Ties have no winner
+        assert_eq!(ArchivedGame::from_game(&make_game("g2", &[5, 5])).winner_id, None);
+    }
+
+    #[test]
+    fn test_archive_bounded() {
+        let mut archive = GameArchive::new(2);
+        for id in ["g1", "g2", "g3"] {
+            archive.push(ArchivedGame::from_game(&make_game(id, &[1])));
+        }
+
+        let ids: Vec<&str> = archive
+            .for_lobby("lobby-1", 5)
+            .iter()
+            .map(|g| g.game_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["g3", "g2"]);
+        assert!(archive.get("g1").is_none());
+        assert_eq!(archive.get("g2").unwrap().game_id, "g2");
+    }
+
+    #[test]
+    fn test_archive_page() {
+        let mut archive = GameArchive::default();
+        let start = chrono::Utc::now();
+        for (i, id) in ["g1", "g2", "g3"].into_iter().enumerate() {
+            let mut summary = ArchivedGame::from_game(&make_game(id, &[1]));
+            summary.ended_at = Some(start + chrono::Duration::seconds(i as i64));
+            archive.push(summary);
+        }
+
+        let first = archive.page(Some("lobby-1"), None, 2);
+        assert_eq!(first.items[0].game_id, "g3");
+        assert_eq!(first.total, 3);
+        let cursor = Cursor::decode(first.next_cursor.as_deref().unwrap()).unwrap();
+        assert_eq!(archive.page(None, Some(&cursor), 2).items[0].game_id, "g1");
+        assert_eq!(archive.page(Some("other"), None, 2).total, 0);
+    }
+}
@@ -0,0 +1,111 @@
+//! Audit log of privileged commands.
+//!
+//! Every command applied through `AppState::execute` is recorded with who ran
+//! it, so moderator actions can be reviewed after the fact.
+
+use std::collections::VecDeque;
+
+use super::authz::Command;
+
+/// Default number of audit entries kept.
+pub const DEFAULT_AUDIT_LOG_CAPACITY: usize = 1000;
+
+/// A command that was applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub actor_id: i64,
+    pub command: Command,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+impl AuditEntry {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "actor_id": self.actor_id,
+            "command": self.command.to_json(),
+            "at": self.at.to_rfc3339()
+        })
+    }
+}
+
+/// Bounded audit history, oldest first.
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    entries: VecDeque<AuditEntry>,
+    capacity: usize,
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_AUDIT_LOG_CAPACITY)
+    }
+}
+
+impl AuditLog {
+    /// Create a log keeping at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Record a command, dropping the oldest entry once full.
+    pub fn record(&mut self, actor_id: i64, command: Command) {
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(AuditEntry {
+            actor_id,
+            command,
+            at: chrono::Utc::now(),
+        });
+    }
+
+    /// Get all entries, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &AuditEntry> {
+        self.entries.iter()
+    }
+
+    /// Get entries for commands targeting a game, oldest first.
+    pub fn for_game<'a>(&'a self, game_id: &'a str) -> impl Iterator<Item = &'a AuditEntry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.command.game_id() == Some(game_id))
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the log is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_log_bounded() {
+        let mut log = AuditLog::new(2);
+        for game_id in ["g1", "g2", "g1"] {
+            log.record(
+                1,
+                Command::ForceSkipTurn {
+                    game_id: game_id.to_string(),
+                },
+            );
+        }
+
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.for_game("g1").count(), 1);
+        assert_eq!(
+            log.entries().next().unwrap().to_json()["command"]["type"],
+            "force_skip_turn"
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Command authorization.
+//!
+//! `AppState::execute` asks an [`Authorizer`] whether an actor may run a
+//! command before applying it. The default policy uses lobby roles and game
+//! membership, plus a set of admins who may run anything; deployments can
+//! swap in their own with `AppState::set_authorizer`.
+
+use super::game::GameError;
+use super::lobby::LobbyError;
+use super::{AppError, AppState};
+
+/// A privileged action on a lobby or game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Remove a member from a lobby
+    KickPlayer { lobby_id: String, player_id: i64 },
+    /// Hand the host role to another member
+    TransferHost { lobby_id: String, player_id: i64 },
+    /// Change the lobby's player limit
+    SetMaxPlayers { lobby_id: String, max_players: usize },
+    /// Change the lobby's locale
+    SetLocale { lobby_id: String, locale: String },
+    /// Cancel a game, started or not
+    CancelGame { game_id: String, reason: String },
+    /// Abort a game that hasn't started yet
+    AbortGame { game_id: String },
+    /// Skip the current player's turn (admin only)
+    ForceSkipTurn { game_id: String },
+    /// Change a player's score, recording why (admin only)
+    AdjustScore {
+        game_id: String,
+        player_id: i64,
+        amount: i32,
+        reason: String,
+    },
+    /// End a game now with the current scores (admin only)
+    ForceEndGame { game_id: String },
+    /// Remove a player from a game (admin only)
+    RemoveGamePlayer { game_id: String, player_id: i64 },
+}
+
+impl Command {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::KickPlayer { .. } => "kick_player",
+            Self::TransferHost { .. } => "transfer_host",
+            Self::SetMaxPlayers { .. } => "set_max_players",
+            Self::SetLocale { .. } => "set_locale",
+            Self::CancelGame { .. } => "cancel_game",
+            Self::AbortGame { .. } => "abort_game",
+            Self::ForceSkipTurn { .. } => "force_skip_turn",
+            Self::AdjustScore { .. } => "adjust_score",
+            Self::ForceEndGame { .. } => "force_end_game",
+            Self::RemoveGamePlayer { .. } => "remove_game_player",
+        }
+    }
+
+    /// Check if only admins may run this command.
+    pub fn requires_admin(&self) -> bool {
+        matches!(
+            self,
+            Self::ForceSkipTurn { .. }
+                | Self::AdjustScore { .. }
+                | Self::ForceEndGame { .. }
+                | Self::RemoveGamePlayer { .. }
+        )
+    }
+
+    /// The game this command targets, if any.
+    pub fn game_id(&self) -> Option<&str> {
+        match self {
+            Self::CancelGame { game_id, .. }
+            | Self::AbortGame { game_id }
+            | Self::ForceSkipTurn { game_id }
+            | Self::AdjustScore { game_id, .. }
+            | Self::ForceEndGame { game_id }
+            | Self::RemoveGamePlayer { game_id, .. } => Some(game_id),
+            Self::KickPlayer { .. }
+            | Self::TransferHost { .. }
+            | Self::SetMaxPlayers { .. }
+            | Self::SetLocale { .. } => None,
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut json = match self {
+            Self::KickPlayer {
+                lobby_id,
+                player_id,
+            }
+            | Self::TransferHost {
+                lobby_id,
+                player_id,
+            } => serde_json::json!({ "lobby_id": lobby_id, "player_id": player_id }),
+            Self::SetMaxPlayers {
+                lobby_id,
+                max_players,
+            } => serde_json::json!({ "lobby_id": lobby_id, "max_players": max_players }),
+            Self::SetLocale { lobby_id, locale } => {
+                serde_json::json!({ "lobby_id": lobby_id, "locale": locale })
+            }
+            Self::CancelGame { game_id, reason } => {
+                serde_json::json!({ "game_id": game_id, "reason": reason })
+            }
+            Self::AbortGame { game_id }
+            | Self::ForceSkipTurn { game_id }
+            | Self::ForceEndGame { game_id } => serde_json::json!({ "game_id": game_id }),
+            Self::AdjustScore {
+                game_id,
+                player_id,
+                amount,
+                reason,
+            } => serde_json::json!({
+                "game_id": game_id,
+                "player_id": player_id,
+                "amount": amount,
+                "reason": reason
+            }),
+            Self::RemoveGamePlayer { game_id, player_id } => {
+                serde_json::json!({ "game_id": game_id, "player_id": player_id })
+            }
+        };
+        json["type"] = self.name().into();
+        json
+    }
+}
+
+/// Decides whether an actor may run a command.
+pub trait Authorizer: std::fmt::Debug + Send + Sync {
+    /// Return Ok if `actor_id` may run `command` against the current state.
+    fn authorize(&self, state: &AppState, actor_id: i64, command: &Command) -> Result<(), AppError>;
+}
+
+/// Role-based policy.
+///
+/// - Players in `AppState::admin_ids` may run any command, including the
+///   admin-only game controls; nobody else may run those.
+/// - Lobby commands require the lobby host. Hostless (channel) lobbies let
+///   any member change settings, but nobody can kick or transfer host.
+/// - Game commands require the lobby host if there is one, otherwise any
+///   player in the game.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultAuthorizer;
+
+impl Authorizer for DefaultAuthorizer {
+    fn authorize(
+        &self,
+        state: &AppState,
+        actor_id: i64,
+        command: &Command,
+    ) -> Result<(), AppError> {
+        if state.admin_ids.contains(&actor_id) {
+            return Ok(());
+        }
+        if command.requires_admin() {
+            return Err(AppError::Forbidden);
+        }
+
+        match command {
+            Command::KickPlayer { lobby_id, .. }
+            | Command::TransferHost { lobby_id, .. }
+            | Command::SetMaxPlayers { lobby_id, .. }
+            | Command::SetLocale { lobby_id, .. } => {
+                let lobby = state.lobbies.get(lobby_id).ok_or(AppError::LobbyNotFound)?;
+                if !lobby.has_member(actor_id) {
+                    return Err(LobbyError::NotMember.into());
+                }
+                let host_only = !matches!(
+                    command,
+                    Command::SetMaxPlayers { .. } | Command::SetLocale { .. }
+                );
+                match lobby.host_id {
+                    Some(host_id) if host_id != actor_id => Err(LobbyError::NotHost.into()),
+                    None if host_only => Err(LobbyError::NotHost.into()),
+                    _ => Ok(()),
+                }
+            }
+            Command::CancelGame { game_id, .. } | Command::AbortGame { game_id } => {
+                let game = state.games.get(game_id).ok_or(AppError::GameNotFound)?;
+                match state.lobbies.get(&game.lobby_id).and_then(|l| l.host_id) {
+                    Some(host_id) if host_id != actor_id => Err(LobbyError::NotHost.into()),
+                    None if !game.has_player(actor_id) => Err(GameError::NotPlayer.into()),
+                    _ => Ok(()),
+                }
+            }
+            Command::ForceSkipTurn { .. }
+            | Command::AdjustScore { .. }
+            | Command::ForceEndGame { .. }
+            | Command::RemoveGamePlayer { .. } => Err(AppError::Forbidden),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::lobby::{Lobby, LobbyMember};
+
+    fn state_with_lobby(lobby: Lobby) -> AppState {
+        let mut state = AppState::new();
+        state.lobbies.add(lobby);
+        state
+    }
+
+    #[test]
+    fn test_host_only_lobby_commands() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        for pid in [1, 2] {
+            lobby
+                .add_member(LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None))
+                .unwrap();
+        }
+        let lobby_id = lobby.id.clone();
+        let state = state_with_lobby(lobby);
+        let kick = Command::KickPlayer {
+            lobby_id,
+            player_id: 2,
+        };
+
+        assert!(DefaultAuthorizer.authorize(&state, 1, &kick).is_ok());
+        assert_eq!(
+            DefaultAuthorizer.authorize(&state, 2, &kick),
+            Err(AppError::Lobby(LobbyError::NotHost))
+        );
+        assert_eq!(
+            DefaultAuthorizer.authorize(&state, 3, &kick),
+            Err(AppError::Lobby(LobbyError::NotMember))
+        );
+    }
+
+    #[test]
+    fn test_admin_commands() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        lobby
+            .add_member(LobbyMember::new(1, "1".to_string(), "P1".to_string(), None))
+            .unwrap();
+        let lobby_id = lobby.id.clone();
+        let mut state = state_with_lobby(lobby);
+        state.admin_ids.insert(99);
+
+        let skip = Command::ForceSkipTurn {
+            game_id: "game-1".to_string(),
+        };
+        // Even the host can't use admin controls
+        assert_eq!(DefaultAuthorizer.authorize(&state, 1, &skip), Err(AppError::Forbidden));
+        assert!(DefaultAuthorizer.authorize(&state, 99, &skip).is_ok());
+
+        // Admins don't need to be lobby members
+        let kick = Command::KickPlayer {
+            lobby_id,
+            player_id: 1,
+        };
+        assert!(DefaultAuthorizer.authorize(&state, 99, &kick).is_ok());
+    }
+
+    #[test]
+    fn test_hostless_lobby_settings() {
+        let mut lobby = Lobby::new_channel("chan-1".to_string(), None);
+        lobby
+            .add_member(LobbyMember::new(1, "1".to_string(), "P1".to_string(), None))
+            .unwrap();
+        let lobby_id = lobby.id.clone();
+        let state = state_with_lobby(lobby);
+
+        let settings = Command::SetMaxPlayers {
+            lobby_id: lobby_id.clone(),
+            max_players: 4,
+        };
+        assert!(DefaultAuthorizer.authorize(&state, 1, &settings).is_ok());
+
+        let kick = Command::KickPlayer {
+            lobby_id,
+            player_id: 1,
+        };
+        assert!(DefaultAuthorizer.authorize(&state, 1, &kick).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! Simultaneous-play ("blitz") bookkeeping.
+//!
+//! In `GameMode::Blitz` there's no turn rotation: every player searches the
+//! same board until the window closes. Each find is kept with the time it
+//! was made and only scored when the game ends, so a word found by several
+//! players can be settled by the game's `DuplicateRule`.
+
+use std::collections::{HashMap, HashSet};
+
+use super::game::{GameError, Position};
+
+/// How points are settled when several players find the same word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateRule {
+    /// The earliest find scores; later ones score nothing
+    #[default]
+    EarliestWins,
+    /// Everyone who found it shares the points, rounded down
+    Split,
+}
+
+impl DuplicateRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::EarliestWins => "earliest_wins",
+            Self::Split => "split",
+        }
+    }
+}
+
+/// A word found during the window, scored against the board at the time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlitzFind {
+    pub player_id: i64,
+    /// Uppercased
+    pub word: String,
+    pub path: Vec<Position>,
+    pub points: i32,
+    pub gems: i32,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Points a find earned once duplicates were settled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlitzAward {
+    pub find: BlitzFind,
+    pub points: i32,
+    /// Gems go to the earliest find only
+    pub gems: i32,
+    /// Players who found the same word, including this one
+    pub finders: usize,
+}
+
+/// Everything found so far in a blitz game.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlitzState {
+    finds: Vec<BlitzFind>,
+    found: HashMap<i64, HashSet<String>>,
+}
+
+impl BlitzState {
+    /// Record a find. Fails with `WordUsed` if the player already found it.
+    pub fn add(&mut self, find: BlitzFind) -> Result<(), GameError> {
+        let found = self.found.entry(find.player_id).or_default();
+        if !found.insert(find.word.clone()) {
+            return Err(GameError::WordUsed);
+        }
+        self.finds.push(find);
+        Ok(())
+    }
+
+    /// Check if a player has found a word.
+    pub fn has_found(&self, player_id: i64, word: &str) -> bool {
+        self.found
+            .get(&player_id)
+            .is_some_and(|words| words.contains(&word.to_uppercase()))
+    }
+
+    /// Number of words a player has found.
+    pub fn found_count(&self, player_id: i64) -> usize {
+        self.found.get(&player_id).map_or(0, HashSet::len)
+    }
+
+    /// All finds, in the order they were submitted.
+    pub fn finds(&self) -> &[BlitzFind] {
+        &self.finds
+    }
+
+    /// Settle duplicates and return every find with its points, earliest
+    /// first. Finds made at the same instant count as one earliest find in
+    /// submission order.
+    pub fn resolve(&self, rule: DuplicateRule) -> Vec<BlitzAward> {
+        let mut finds = self.finds.clone();
+        finds.sort_by_key(|f| f.at);
+
+        let mut finders: HashMap<&str, usize> = HashMap::new();
+        for find in &self.finds {
+            *finders.entry(find.word.as_str()).or_default() += 1;
+        }
+        let mut claimed = HashSet::new();
+        finds
+            .into_iter()
+            .map(|find| {
+                let sharing = finders.get(find.word.as_str()).copied().unwrap_or(1);
+                let earliest = claimed.insert(find.word.clone());
+                let points = match rule {
+                    DuplicateRule::EarliestWins if !earliest => 0,
+                    DuplicateRule::EarliestWins => find.points,
+                    DuplicateRule::Split => find.points / sharing as i32,
+                };
+                BlitzAward {
+                    points,
+                    gems: if earliest { find.gems } else { 0 },
+                    finders: sharing,
+                    find,
+                }
+            })
+            .collect()
+    }
+
+    /// Words found per player; the words themselves stay hidden until the end.
+    pub fn to_json(&self) -> serde_json::Value {
+        let found: serde_json::Map<String, serde_json::Value> = self
+            .found
+            .iter()
+            .map(|(player_id, words)| (player_id.to_string(), words.len().into()))
+            .collect();
+        serde_json::json!({ "found": found })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(player_id: i64, word: &str, points: i32, secs: i64) -> BlitzFind {
+        BlitzFind {
+            player_id,
+            word: word.to_string(),
+            path: Vec::new(),
+            points,
+            gems: 1,
+            at: chrono::DateTime::UNIX_EPOCH + chrono::Duration::seconds(secs),
+        }
+    }
+
+    #[test]
+    fn test_resolve_duplicates() {
+        let mut state = BlitzState::default();
+        state.add(find(2, "CAT", 6, 5)).unwrap();
+        state.add(find(1, "CAT", 6, 3)).unwrap();
+        state.add(find(1, "DOG", 5, 4)).unwrap();
+        assert_eq!(state.add(find(1, "CAT", 6, 9)), Err(GameError::WordUsed));
+        assert!(state.has_found(1, "cat"));
+        assert_eq!(state.found_count(1), 2);
+
+        let earliest = state.resolve(DuplicateRule::EarliestWins);
+        let points: Vec<_> = earliest.iter().map(|a| (a.find.player_id, a.points)).collect();
+        assert_eq!(points, vec![(1, 6), (1, 5), (2, 0)]);
+        assert_eq!((earliest[2].gems, earliest[2].finders), (0, 2));
+
+        let split = state.resolve(DuplicateRule::Split);
+        let points: Vec<_> = split.iter().map(|a| a.points).collect();
+        assert_eq!(points, vec![3, 5, 3]);
+        assert_eq!(state.to_json()["found"]["1"], 2);
+    }
+}
@@ -0,0 +1,92 @@
+//! Bot players.
+//!
+//! A `BotStrategy` picks the move for a bot's turn from the game state, and
+//! `Game::play_bot_turn` applies it through the same paths as a human move,
+//! so scoring, the move log and replays don't need to know about bots.
+
+use super::game::{Game, Position};
+
+/// A move chosen by a bot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotMove {
+    /// Play the word traced along this path
+    PlayWord(Vec<Position>),
+    Pass,
+}
+
+impl BotMove {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::PlayWord(path) => serde_json::json!({
+                "type": "play_word",
+                "path": path.iter().map(|p| p.to_json()).collect::<Vec<_>>()
+            }),
+            Self::Pass => serde_json::json!({ "type": "pass" }),
+        }
+    }
+}
+
+/// Chooses moves for a bot player.
+pub trait BotStrategy {
+    /// Pick a move for `player_id`, whose turn it is.
+    fn pick_move(&self, game: &Game, player_id: i64) -> BotMove;
+}
+
+/// Plays the highest-scoring unused word on the board, optionally capped to
+/// make weaker opponents. Passes without a dictionary or a suitable word.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GreedyBot {
+    /// Only consider words worth at most this many points (None = any)
+    pub max_points: Option<i32>,
+}
+
+impl BotStrategy for GreedyBot {
+    fn pick_move(&self, game: &Game, _player_id: i64) -> BotMove {
+        game.missed_words()
+            .into_iter()
+            .find(|w| self.max_points.is_none_or(|max| w.points <= max))
+            .map_or(BotMove::Pass, |w| BotMove::PlayWord(w.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::dictionary::WordList;
+    use crate::state::game::{Grid, GridCell};
+    use std::sync::Arc;
+
+    fn make_grid() -> Grid {
+        let mut grid: Grid = std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('Q')));
+        grid[0][0] = GridCell::new('C');
+        grid[0][1] = GridCell::new('A');
+        grid[0][2] = GridCell::new('T');
+        grid[1][1] = GridCell::new('Z');
+        grid
+    }
+
+    #[test]
+    fn test_greedy_bot() {
+        let game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_dictionary(Arc::new(WordList::from_words(["CAT", "ZAT"])));
+
+        let best = GreedyBot::default().pick_move(&game, 1);
+        assert_eq!(
+            best,
+            BotMove::PlayWord(vec![Position::new(1, 1), Position::new(0, 1), Position::new(0, 2)])
+        );
+
+        // CAT is worth 5
+        let capped = GreedyBot {
+            max_points: Some(5),
+        };
+        let BotMove::PlayWord(path) = capped.pick_move(&game, 1) else {
+            panic!("expected a word");
+        };
+        assert_eq!(path[0], Position::new(0, 0));
+
+        // Nothing to find without a dictionary
+        let game = Game::new("game-2".to_string(), "lobby-1".to_string(), make_grid());
+        assert_eq!(GreedyBot::default().pick_move(&game, 1), BotMove::Pass);
+    }
+}
@@ -0,0 +1,209 @@
+//! Chat buffers.
+//!
+//! Bounded message history with per-sender rate limiting.
+
+use std::collections::{HashMap, VecDeque};
+
+/// Default number of messages kept per buffer.
+pub const DEFAULT_CHAT_CAPACITY: usize = 100;
+
+/// Default minimum time between messages from one sender (milliseconds).
+pub const DEFAULT_CHAT_MIN_INTERVAL_MS: i64 = 1000;
+
+/// Maximum message length in characters.
+pub const MAX_CHAT_MESSAGE_LEN: usize = 500;
+
+/// A single chat message.
+#[derive(Debug, Clone)]
+pub struct ChatMessage {
+    pub player_id: i64,
+    pub user_id: String,
+    pub username: String,
+    pub text: String,
+    pub sent_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ChatMessage {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "user_id": self.user_id,
+            "username": self.username,
+            "text": self.text,
+            "sent_at": self.sent_at.to_rfc3339()
+        })
+    }
+}
+
+/// Bounded chat history.
+#[derive(Debug, Clone)]
+pub struct ChatBuffer {
+    messages: VecDeque<ChatMessage>,
+    capacity: usize,
+    min_interval: chrono::Duration,
+    /// Last post time per sender
+    last_post: HashMap<i64, chrono::DateTime<chrono::Utc>>,
+}
+
+impl Default for ChatBuffer {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_CHAT_CAPACITY,
+            chrono::Duration::milliseconds(DEFAULT_CHAT_MIN_INTERVAL_MS),
+        )
+    }
+}
+
+impl ChatBuffer {
+    /// Create a buffer keeping at most `capacity` messages.
+    pub fn new(capacity: usize, min_interval: chrono::Duration) -> Self {
+        Self {
+            messages: VecDeque::new(),
+            capacity,
+            min_interval,
+            last_post: HashMap::new(),
+        }
+    }
+
+    /// Append a message, enforcing length and rate limits.
+    ///
+    /// The oldest message is dropped once the buffer is full.
+    pub fn post(&mut self, message: ChatMessage) -> Result<&ChatMessage, ChatError> {
+        let text = message.text.trim();
+        if text.is_empty() {
+            return Err(ChatError::Empty);
+        }
+        if text.chars().count() > MAX_CHAT_MESSAGE_LEN {
+            return Err(ChatError::TooLong);
+        }
+
+        if let Some(last) = self.last_post.get(&message.player_id) {
+            if message.sent_at - *last < self.min_interval {
+                return Err(ChatError::RateLimited);
+            }
+        }
+
+        self.last_post.insert(message.player_id, message.sent_at);
+        if self.messages.len() >= self.capacity {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(ChatMessage {
+            text: text.to_string(),
+            ..message
+        });
+        Ok(self.messages.back().unwrap())
+    }
+
+    /// Get all messages, oldest first.
+    pub fn messages(&self) -> impl Iterator<Item = &ChatMessage> {
+        self.messages.iter()
+    }
+
+    /// Message count.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Check if buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Convert messages to JSON.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Array(self.messages.iter().map(|m| m.to_json()).collect())
+    }
+}
+
+/// Chat errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatError {
+    Empty,
+    TooLong,
+    RateLimited,
+}
+
+impl std::fmt::Display for ChatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Message is empty"),
+            Self::TooLong => write!(f, "Message is too long"),
+            Self::RateLimited => write!(f, "Sending messages too quickly"),
+        }
+    }
+}
+
+impl ChatError {
+    /// Stable identifier clients use to look up a localized message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Empty => "chat_empty",
+            Self::TooLong => "chat_too_long",
+            Self::RateLimited => "chat_rate_limited",
+        }
+    }
+}
+
+impl std::error::Error for ChatError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_message(player_id: i64, text: &str, at: chrono::DateTime<chrono::Utc>) -> ChatMessage {
+        ChatMessage {
+            player_id,
+            user_id: format!("{}", player_id * 1000),
+            username: format!("Player{}", player_id),
+            text: text.to_string(),
+            sent_at: at,
+        }
+    }
+
+    #[test]
+    fn test_chat_rate_limit() {
+        let mut chat = ChatBuffer::default();
+        let now = chrono::Utc::now();
+
+        chat.post(make_message(1, "hi", now)).unwrap();
+        assert_eq!(
+            chat.post(make_message(1, "again", now)).unwrap_err(),
+            ChatError::RateLimited
+        );
+
+        // Other senders aren't affected
+        chat.post(make_message(2, "hello", now)).unwrap();
+
+        let later = now + chrono::Duration::seconds(2);
+        chat.post(make_message(1, "again", later)).unwrap();
+        assert_eq!(chat.len(), 3);
+    }
+
+    #[test]
+    fn test_chat_bounded() {
+        let mut chat = ChatBuffer::new(2, chrono::Duration::zero());
+        let now = chrono::Utc::now();
+
+        for text in ["one", "two", "three"] {
+            chat.post(make_message(1, text, now)).unwrap();
+        }
+
+        let texts: Vec<&str> = chat.messages().map(|m| m.text.as_str()).collect();
+        assert_eq!(texts, vec!["two", "three"]);
+    }
+
+    #[test]
+    fn test_chat_rejects_invalid() {
+        let mut chat = ChatBuffer::default();
+        let now = chrono::Utc::now();
+
+        assert_eq!(
+            chat.post(make_message(1, "   ", now)).unwrap_err(),
+            ChatError::Empty
+        );
+        let long = "x".repeat(MAX_CHAT_MESSAGE_LEN + 1);
+        assert_eq!(
+            chat.post(make_message(1, &long, now)).unwrap_err(),
+            ChatError::TooLong
+        );
+    }
+}
@@ -3,9 +3,12 @@
 //! Tracks WebSocket connections and their associated metadata.
 //! Handles reconnection with grace period.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::time::{Duration, Instant};
 
+use super::player::PlayerLocation;
+use super::redaction::RedactionProfile;
+
 /// Default grace period for reconnection (60 seconds).
 pub const DEFAULT_RECONNECT_GRACE_PERIOD: Duration = Duration::from_secs(60);
 
@@ -15,6 +18,118 @@ pub const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
 /// Default heartbeat timeout (45 seconds).
 pub const DEFAULT_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(45);
 
+/// Heartbeat timeout for players in a game (90 seconds).
+pub const IN_GAME_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Idle timeout for players not in a lobby (5 minutes).
+pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Idle timeout for players sitting in a lobby (15 minutes).
+pub const IN_LOBBY_IDLE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+/// Idle timeout for players in a game (30 minutes).
+pub const IN_GAME_IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Grace period for players not in a lobby (30 seconds).
+pub const CONNECTED_RECONNECT_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// Grace period for players in a game (120 seconds).
+pub const IN_GAME_RECONNECT_GRACE_PERIOD: Duration = Duration::from_secs(120);
+
+/// Connection policy settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionConfig {
+    /// Grace period for players connected but not in a lobby
+    pub connected_grace: Duration,
+    /// Grace period for players in a lobby
+    pub in_lobby_grace: Duration,
+    /// Grace period for players spectating a game
+    pub spectating_grace: Duration,
+    /// Grace period for players in a game
+    pub in_game_grace: Duration,
+}
+
+impl Default for ConnectionConfig {
+    fn default() -> Self {
+        Self {
+            connected_grace: CONNECTED_RECONNECT_GRACE_PERIOD,
+            in_lobby_grace: DEFAULT_RECONNECT_GRACE_PERIOD,
+            spectating_grace: DEFAULT_RECONNECT_GRACE_PERIOD,
+            in_game_grace: IN_GAME_RECONNECT_GRACE_PERIOD,
+        }
+    }
+}
+
+impl ConnectionConfig {
+    /// Get the reconnect grace period for a player at `location`.
+    pub fn grace_period_for(&self, location: &PlayerLocation) -> Duration {
+        match location {
+            PlayerLocation::Disconnected | PlayerLocation::Connected => self.connected_grace,
+            PlayerLocation::InLobby { .. } => self.in_lobby_grace,
+            PlayerLocation::Spectating { .. } => self.spectating_grace,
+            PlayerLocation::InGame { .. } => self.in_game_grace,
+        }
+    }
+}
+
+/// How long a request's result is kept for deduplicating retries (5 minutes).
+pub const DEFAULT_REQUEST_DEDUPE_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Maximum number of request results kept per connection.
+pub const MAX_CACHED_REQUESTS: usize = 64;
+
+/// What a connected player is currently doing.
+///
+/// Used to pick heartbeat and idle thresholds: a player mid-game on a flaky
+/// mobile network deserves more slack than someone idling in the menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActivityClass {
+    /// Connected but not in a lobby
+    #[default]
+    Idle,
+    /// In a lobby (or spectating)
+    InLobby,
+    /// Playing in a game
+    InGame,
+}
+
+impl ActivityClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::InLobby => "in_lobby",
+            Self::InGame => "in_game",
+        }
+    }
+
+    /// Heartbeat timeout for this class.
+    pub fn heartbeat_timeout(&self) -> Duration {
+        match self {
+            Self::Idle | Self::InLobby => DEFAULT_HEARTBEAT_TIMEOUT,
+            Self::InGame => IN_GAME_HEARTBEAT_TIMEOUT,
+        }
+    }
+
+    /// Idle (no activity) timeout for this class.
+    pub fn idle_timeout(&self) -> Duration {
+        match self {
+            Self::Idle => DEFAULT_IDLE_TIMEOUT,
+            Self::InLobby => IN_LOBBY_IDLE_TIMEOUT,
+            Self::InGame => IN_GAME_IDLE_TIMEOUT,
+        }
+    }
+}
+
+impl From<&PlayerLocation> for ActivityClass {
+    fn from(location: &PlayerLocation) -> Self {
+        match location {
+            PlayerLocation::Disconnected | PlayerLocation::Connected => Self::Idle,
+            PlayerLocation::InLobby { .. } | PlayerLocation::Spectating { .. } => Self::InLobby,
+            PlayerLocation::InGame { .. } => Self::InGame,
+        }
+    }
+}
+
 /// Connection state for a single player.
 #[derive(Debug, Clone)]
 pub struct Connection {
@@ -48,6 +163,9 @@ pub struct Connection {
     /// Last acknowledged sequence from client
     pub ack_seq: u64,
 
+    /// Next sequence number expected from the client (envelope protocol)
+    pub next_expected_recv_seq: u64,
+
     /// Messages pending acknowledgment (for replay on reconnect)
     pub pending_messages: Vec<PendingMessage>,
 
@@ -56,6 +174,24 @@ pub struct Connection {
 
     /// Whether this connection is using envelope protocol
     pub uses_envelope: bool,
+
+    /// Activity hint used for timeout thresholds
+    pub activity_class: ActivityClass,
+
+    /// Discord guild the connection was opened from (for per-guild quotas)
+    pub guild_id: Option<String>,
+
+    /// Missed a heartbeat window but hasn't timed out yet
+    pub degraded: bool,
+
+    /// Results of recent client requests, oldest first (for retry dedupe)
+    pub recent_requests: VecDeque<CachedResponse>,
+
+    /// Client-reported platform/version string
+    pub client_info: Option<String>,
+
+    /// Most recent measured round-trip time
+    pub latency: Option<Duration>,
 }
 
 /// Connection status.
@@ -102,10 +238,199 @@ impl ConnectionStatus {
 #[derive(Debug, Clone)]
 pub struct PendingMessage {
     pub seq: u64,
+    pub kind: MessageKind,
+    pub priority: MessagePriority,
     pub message: serde_json::Value,
     pub sent_at: Instant,
 }
 
+/// What an outbound message carries, so queue policies can treat kinds
+/// differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MessageKind {
+    /// Full state snapshot (supersedes earlier snapshots)
+    Snapshot,
+    /// Incremental state change
+    Event,
+    /// Chat message
+    Chat,
+    /// Keep-alive
+    Heartbeat,
+    /// Error response to a client command
+    Error,
+    /// Untyped message
+    #[default]
+    Other,
+}
+
+impl MessageKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Snapshot => "snapshot",
+            Self::Event => "event",
+            Self::Chat => "chat",
+            Self::Heartbeat => "heartbeat",
+            Self::Error => "error",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Delivery priority of an outbound message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum MessagePriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// A typed outbound message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboundMessage {
+    pub kind: MessageKind,
+    pub payload: serde_json::Value,
+    pub priority: MessagePriority,
+}
+
+impl OutboundMessage {
+    /// Create a message with normal priority.
+    pub fn new(kind: MessageKind, payload: serde_json::Value) -> Self {
+        Self {
+            kind,
+            payload,
+            priority: MessagePriority::Normal,
+        }
+    }
+
+    /// Set the priority.
+    pub fn with_priority(mut self, priority: MessagePriority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl From<serde_json::Value> for OutboundMessage {
+    fn from(payload: serde_json::Value) -> Self {
+        Self::new(MessageKind::Other, payload)
+    }
+}
+
+/// Result of a client request, kept so a retry gets the same response.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub request_id: String,
+    pub result: serde_json::Value,
+    pub recorded_at: Instant,
+}
+
+/// How reachable a player currently is, as shown to other players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    /// Connected with healthy heartbeats
+    Online,
+    /// Connected but missed a heartbeat window
+    Unstable,
+    /// Disconnected, within the reconnection grace period
+    Reconnecting,
+    /// Gone (expired or never connected)
+    Offline,
+}
+
+impl Presence {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Unstable => "unstable",
+            Self::Reconnecting => "reconnecting",
+            Self::Offline => "offline",
+        }
+    }
+}
+
+/// Connection health changes surfaced by the manager.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// Missed a heartbeat window
+    Degraded { player_id: i64 },
+    /// Heartbeat arrived after being degraded
+    Recovered { player_id: i64 },
+}
+
+/// Inbound message arrived out of sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeqGap {
+    pub expected: u64,
+    pub received: u64,
+}
+
+impl SeqGap {
+    /// The message was already received (replay or duplicate).
+    pub fn is_duplicate(&self) -> bool {
+        self.received < self.expected
+    }
+
+    /// Sequence numbers that were skipped and should be retransmitted.
+    pub fn missing(&self) -> std::ops::Range<u64> {
+        if self.is_duplicate() {
+            self.expected..self.expected
+        } else {
+            self.expected..self.received
+        }
+    }
+}
+
+impl std::fmt::Display for SeqGap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_duplicate() {
+            write!(f, "Duplicate message {} (expected {})", self.received, self.expected)
+        } else {
+            write!(f, "Missing messages {}..{}", self.expected, self.received)
+        }
+    }
+}
+
+impl std::error::Error for SeqGap {}
+
+/// Messages to replay to a resuming client.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayPlan {
+    /// Last sequence the client acknowledged
+    pub ack_seq: u64,
+    /// Unacknowledged messages, in send order
+    pub messages: Vec<PendingMessage>,
+}
+
+impl ReplayPlan {
+    /// Check if there is nothing to replay.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Sequence of the last message to replay.
+    pub fn last_seq(&self) -> Option<u64> {
+        self.messages.last().map(|m| m.seq)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let messages: Vec<serde_json::Value> = self
+            .messages
+            .iter()
+            .map(|m| {
+                serde_json::json!({
+                    "seq": m.seq,
+                    "kind": m.kind.as_str(),
+                    "message": m.message
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "ack_seq": self.ack_seq,
+            "messages": messages
+        })
+    }
+}
+
 impl Connection {
     /// Create a new connection.
     pub fn new(
@@ -127,12 +452,36 @@ impl Connection {
             last_heartbeat: now,
             send_seq: 0,
             ack_seq: 0,
+            next_expected_recv_seq: 1,
             pending_messages: Vec::new(),
             session_token,
             uses_envelope: false,
+            activity_class: ActivityClass::Idle,
+            guild_id: None,
+            degraded: false,
+            recent_requests: VecDeque::new(),
+            client_info: None,
+            latency: None,
         }
     }
 
+    /// Set the client-reported platform/version string.
+    pub fn with_client_info(mut self, client_info: String) -> Self {
+        self.client_info = Some(client_info);
+        self
+    }
+
+    /// Record a measured round-trip time (e.g. from a ping/pong pair).
+    pub fn record_latency(&mut self, rtt: Duration) {
+        self.latency = Some(rtt);
+    }
+
+    /// Set the guild the connection belongs to.
+    pub fn with_guild(mut self, guild_id: String) -> Self {
+        self.guild_id = Some(guild_id);
+        self
+    }
+
     /// Mark as disconnected with grace period.
     pub fn disconnect(&mut self) {
         self.disconnect_with_grace(DEFAULT_RECONNECT_GRACE_PERIOD);
@@ -147,6 +496,14 @@ impl Connection {
         };
     }
 
+    /// Messages the client still needs, without changing connection state.
+    pub fn replay_plan(&self) -> ReplayPlan {
+        ReplayPlan {
+            ack_seq: self.ack_seq,
+            messages: self.pending_messages.clone(),
+        }
+    }
+
     /// Reconnect (restore Connected status).
     pub fn reconnect(&mut self) -> Result<Vec<PendingMessage>, &'static str> {
         match &self.status {
@@ -160,6 +517,7 @@ impl Connection {
                     self.status = ConnectionStatus::Connected;
                     self.last_activity = Instant::now();
                     self.last_heartbeat = Instant::now();
+                    self.degraded = false;
                     // Return pending messages for replay
                     Ok(self.pending_messages.clone())
                 } else {
@@ -185,6 +543,7 @@ impl Connection {
     pub fn heartbeat(&mut self) {
         self.last_heartbeat = Instant::now();
         self.last_activity = Instant::now();
+        self.degraded = false;
     }
 
     /// Process acknowledgment from client.
@@ -194,12 +553,37 @@ impl Connection {
         self.pending_messages.retain(|m| m.seq > ack);
     }
 
+    /// Verify the sequence number of an inbound message.
+    ///
+    /// Only enforced under the envelope protocol. A gap leaves the expected
+    /// sequence unchanged so the client can retransmit from there; duplicates
+    /// should simply be dropped.
+    pub fn record_incoming(&mut self, seq: u64) -> Result<(), SeqGap> {
+        if !self.uses_envelope {
+            return Ok(());
+        }
+        if seq != self.next_expected_recv_seq {
+            return Err(SeqGap {
+                expected: self.next_expected_recv_seq,
+                received: seq,
+            });
+        }
+        self.next_expected_recv_seq += 1;
+        Ok(())
+    }
+
     /// Get next sequence number and record pending message.
-    pub fn send(&mut self, message: serde_json::Value) -> u64 {
+    ///
+    /// Accepts a typed [`OutboundMessage`] or a bare JSON value (sent as
+    /// `MessageKind::Other`).
+    pub fn send(&mut self, message: impl Into<OutboundMessage>) -> u64 {
+        let message = message.into();
         self.send_seq += 1;
         self.pending_messages.push(PendingMessage {
             seq: self.send_seq,
-            message,
+            kind: message.kind,
+            priority: message.priority,
+            message: message.payload,
             sent_at: Instant::now(),
         });
         self.send_seq
@@ -208,7 +592,110 @@ impl Connection {
     /// Check if heartbeat has timed out.
     pub fn is_heartbeat_timeout(&self) -> bool {
         self.status.is_connected()
-            && self.last_heartbeat.elapsed() > DEFAULT_HEARTBEAT_TIMEOUT
+            && self.last_heartbeat.elapsed() > self.activity_class.heartbeat_timeout()
+    }
+
+    /// Get the stored result of an earlier request with this ID.
+    pub fn cached_response(&self, request_id: &str) -> Option<&serde_json::Value> {
+        self.recent_requests
+            .iter()
+            .find(|r| {
+                r.request_id == request_id
+                    && r.recorded_at.elapsed() < DEFAULT_REQUEST_DEDUPE_WINDOW
+            })
+            .map(|r| &r.result)
+    }
+
+    /// Store the result of a request so retries can be answered from cache.
+    /// Expired entries are pruned and the oldest dropped once full.
+    pub fn record_response(&mut self, request_id: String, result: serde_json::Value) {
+        self.recent_requests
+            .retain(|r| r.recorded_at.elapsed() < DEFAULT_REQUEST_DEDUPE_WINDOW);
+        if self.recent_requests.len() >= MAX_CACHED_REQUESTS {
+            self.recent_requests.pop_front();
+        }
+        self.recent_requests.push_back(CachedResponse {
+            request_id,
+            result,
+            recorded_at: Instant::now(),
+        });
+    }
+
+    /// Convert to JSON as seen by an audience.
+    ///
+    /// Everyone sees identity and presence; sequence numbers, buffers and
+    /// timers are diagnostics for admins only. The session token is never
+    /// included.
+    pub fn to_json_for(&self, profile: RedactionProfile) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "username": self.username,
+            "avatar_url": self.avatar_url,
+            "presence": self.presence().as_str()
+        });
+        if profile.shows_user_ids() {
+            json["user_id"] = self.user_id.clone().into();
+        }
+        if profile.shows_diagnostics() {
+            json["diagnostics"] = self.to_diagnostics_json();
+        }
+        json
+    }
+
+    /// Session health details for support tooling.
+    ///
+    /// Never includes the session token.
+    pub fn to_diagnostics_json(&self) -> serde_json::Value {
+        let (status, grace_remaining_secs) = match &self.status {
+            ConnectionStatus::Connected => ("connected", None),
+            ConnectionStatus::Disconnected { grace_until, .. } => (
+                "disconnected",
+                Some(grace_until.saturating_duration_since(Instant::now()).as_secs()),
+            ),
+            ConnectionStatus::Expired => ("expired", None),
+        };
+
+        serde_json::json!({
+            "player_id": self.player_id,
+            "status": status,
+            "presence": self.presence().as_str(),
+            "grace_remaining_secs": grace_remaining_secs,
+            "activity_class": self.activity_class.as_str(),
+            "guild_id": self.guild_id,
+            "client_info": self.client_info,
+            "latency_ms": self.latency.map(|d| d.as_millis() as u64),
+            "connected_secs": self.connected_at.elapsed().as_secs(),
+            "idle_secs": self.last_activity.elapsed().as_secs(),
+            "heartbeat_age_secs": self.last_heartbeat.elapsed().as_secs(),
+            "degraded": self.degraded,
+            "uses_envelope": self.uses_envelope,
+            "send_seq": self.send_seq,
+            "ack_seq": self.ack_seq,
+            "next_expected_recv_seq": self.next_expected_recv_seq,
+            "pending_messages": self.pending_messages.len(),
+            "cached_requests": self.recent_requests.len()
+        })
+    }
+
+    /// Check if a heartbeat window was missed without timing out yet.
+    pub fn is_heartbeat_late(&self) -> bool {
+        self.status.is_connected()
+            && self.last_heartbeat.elapsed() > DEFAULT_HEARTBEAT_INTERVAL
+            && !self.is_heartbeat_timeout()
+    }
+
+    /// Get the player's presence as shown to others.
+    pub fn presence(&self) -> Presence {
+        match &self.status {
+            ConnectionStatus::Connected if self.degraded => Presence::Unstable,
+            ConnectionStatus::Connected => Presence::Online,
+            status if status.is_reconnectable() => Presence::Reconnecting,
+            _ => Presence::Offline,
+        }
+    }
+
+    /// Check if the player has been idle long enough to be kicked.
+    pub fn is_idle_timeout(&self) -> bool {
+        self.status.is_connected() && self.idle_time() > self.activity_class.idle_timeout()
     }
 
     /// Get time since last activity.
@@ -286,6 +773,117 @@ impl ConnectionManager {
         }
     }
 
+    /// Get session health details for a player's connection.
+    pub fn diagnostics(&self, player_id: i64) -> Option<serde_json::Value> {
+        self.connections.get(&player_id).map(|c| c.to_diagnostics_json())
+    }
+
+    /// Record a heartbeat for a player.
+    /// Returns `Recovered` if the connection had been degraded.
+    pub fn heartbeat(&mut self, player_id: i64) -> Option<ConnectionEvent> {
+        let conn = self.connections.get_mut(&player_id)?;
+        let was_degraded = conn.degraded;
+        conn.heartbeat();
+        was_degraded.then_some(ConnectionEvent::Recovered { player_id })
+    }
+
+    /// Mark connections that missed a heartbeat window as degraded.
+    /// Returns a `Degraded` event for each newly degraded connection.
+    pub fn check_heartbeats(&mut self) -> Vec<ConnectionEvent> {
+        let mut events = Vec::new();
+        for (player_id, conn) in &mut self.connections {
+            if !conn.degraded && conn.is_heartbeat_late() {
+                conn.degraded = true;
+                events.push(ConnectionEvent::Degraded {
+                    player_id: *player_id,
+                });
+            }
+        }
+        events
+    }
+
+    /// Update a player's display name and avatar.
+    /// Returns false if the player has no connection.
+    pub fn update_identity(
+        &mut self,
+        player_id: i64,
+        username: String,
+        avatar_url: Option<String>,
+    ) -> bool {
+        match self.connections.get_mut(&player_id) {
+            Some(conn) => {
+                conn.username = username;
+                conn.avatar_url = avatar_url;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Update the activity class for a connection.
+    /// Returns false if the player has no connection.
+    pub fn set_activity_class(&mut self, player_id: i64, class: ActivityClass) -> bool {
+        match self.connections.get_mut(&player_id) {
+            Some(conn) => {
+                conn.activity_class = class;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get connected players that have exceeded their idle timeout.
+    pub fn idle_players(&self) -> Vec<i64> {
+        self.connections
+            .iter()
+            .filter(|(_, c)| c.is_idle_timeout())
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Reconcile connections restored from a snapshot.
+    ///
+    /// Restored connections have no live socket behind them, so every
+    /// non-expired one is put into the `Disconnected` state with a fresh grace
+    /// deadline. Session tokens stay valid, letting clients resume after a
+    /// restart. Returns the reconciled player IDs.
+    pub fn reconcile_restored(&mut self, grace_period: Duration) -> Vec<i64> {
+        let now = Instant::now();
+        let mut reconciled = Vec::new();
+
+        for (player_id, conn) in &mut self.connections {
+            if conn.status == ConnectionStatus::Expired {
+                continue;
+            }
+            conn.status = ConnectionStatus::Disconnected {
+                since: now,
+                grace_until: now + grace_period,
+            };
+            conn.last_activity = now;
+            conn.last_heartbeat = now;
+            reconciled.push(*player_id);
+        }
+
+        reconciled
+    }
+
+    /// Resume a session by token.
+    ///
+    /// Reconnects the connection and returns it with the messages the client
+    /// still needs to receive (none if it was still connected).
+    pub fn resume_session(
+        &mut self,
+        token: &str,
+    ) -> Result<(&Connection, ReplayPlan), &'static str> {
+        let conn = self.get_by_session_mut(token).ok_or("Unknown session")?;
+        let messages = conn.reconnect()?;
+        let plan = ReplayPlan {
+            ack_seq: conn.ack_seq,
+            messages,
+        };
+        Ok((conn, plan))
+    }
+
     /// Check for and expire timed-out connections.
     /// Returns list of expired player IDs.
     pub fn expire_stale(&mut self) -> Vec<i64> {
@@ -404,6 +1002,32 @@ mod tests {
         assert_eq!(conn.pending_messages[0].seq, 3);
     }
 
+    #[test]
+    fn test_incoming_sequence() {
+        let mut conn = make_connection(1);
+
+        // Not enforced without the envelope protocol
+        assert!(conn.record_incoming(7).is_ok());
+
+        conn.uses_envelope = true;
+        assert!(conn.record_incoming(1).is_ok());
+        assert!(conn.record_incoming(2).is_ok());
+
+        // Dropped message
+        let gap = conn.record_incoming(5).unwrap_err();
+        assert!(!gap.is_duplicate());
+        assert_eq!(gap.missing(), 3..5);
+
+        // Replayed message
+        let dup = conn.record_incoming(2).unwrap_err();
+        assert!(dup.is_duplicate());
+        assert!(dup.missing().is_empty());
+
+        // Retransmission resumes the sequence
+        assert!(conn.record_incoming(3).is_ok());
+        assert_eq!(conn.next_expected_recv_seq, 4);
+    }
+
     #[test]
     fn test_reconnect_replay() {
         let mut conn = make_connection(1);
@@ -419,6 +1043,196 @@ mod tests {
         assert_eq!(pending.len(), 2);
     }
 
+    #[test]
+    fn test_activity_class_timeouts() {
+        let mut conn = make_connection(1);
+        conn.last_heartbeat = Instant::now()
+            .checked_sub(Duration::from_secs(60))
+            .unwrap();
+
+        assert!(conn.is_heartbeat_timeout());
+
+        conn.activity_class = ActivityClass::InGame;
+        assert!(!conn.is_heartbeat_timeout());
+    }
+
+    #[test]
+    fn test_manager_idle_players() {
+        let mut manager = ConnectionManager::new();
+        manager.add(make_connection(1));
+        manager.add(make_connection(2));
+
+        for pid in [1, 2] {
+            manager.get_mut(pid).unwrap().last_activity = Instant::now()
+                .checked_sub(Duration::from_secs(10 * 60))
+                .unwrap();
+        }
+        assert!(manager.set_activity_class(2, ActivityClass::InLobby));
+        assert!(!manager.set_activity_class(3, ActivityClass::InLobby));
+
+        assert_eq!(manager.idle_players(), vec![1]);
+    }
+
+    #[test]
+    fn test_manager_resume_after_restore() {
+        let mut manager = ConnectionManager::new();
+        manager.add(make_connection(1));
+        {
+            let conn = manager.get_mut(1).unwrap();
+            conn.send(serde_json::json!({"type": "test1"}));
+            conn.send(serde_json::json!({"type": "test2"}));
+            conn.acknowledge(1);
+        }
+
+        assert_eq!(
+            manager.reconcile_restored(DEFAULT_RECONNECT_GRACE_PERIOD),
+            vec![1]
+        );
+        assert!(manager.get(1).unwrap().status.is_reconnectable());
+
+        assert!(manager.resume_session("invalid").is_err());
+        let (conn, plan) = manager.resume_session("session-1").unwrap();
+        assert!(conn.status.is_connected());
+        assert_eq!(plan.ack_seq, 1);
+        assert_eq!(plan.last_seq(), Some(2));
+
+        // Resuming a live session replays nothing
+        let (_, plan) = manager.resume_session("session-1").unwrap();
+        assert_eq!(plan.ack_seq, 1);
+        assert!(plan.messages.is_empty());
+    }
+
+    #[test]
+    fn test_manager_update_identity() {
+        let mut manager = ConnectionManager::new();
+        manager.add(make_connection(1));
+
+        assert!(manager.update_identity(1, "Renamed".to_string(), Some("a.png".to_string())));
+        assert!(!manager.update_identity(2, "Nobody".to_string(), None));
+
+        let conn = manager.get(1).unwrap();
+        assert_eq!(conn.username, "Renamed");
+        assert_eq!(conn.avatar_url.as_deref(), Some("a.png"));
+    }
+
+    #[test]
+    fn test_connection_redaction() {
+        let conn = make_connection(1);
+
+        let public = conn.to_json_for(RedactionProfile::Public);
+        assert!(public.get("user_id").is_none());
+        assert!(public.get("diagnostics").is_none());
+        assert_eq!(public["presence"], "online");
+
+        let admin = conn.to_json_for(RedactionProfile::Admin);
+        assert_eq!(admin["user_id"], "1000");
+        assert_eq!(admin["diagnostics"]["status"], "connected");
+        assert!(admin.to_string().find(&conn.session_token).is_none());
+    }
+
+    #[test]
+    fn test_manager_diagnostics() {
+        let mut manager = ConnectionManager::new();
+        let mut conn = make_connection(1).with_client_info("web/1.4.0".to_string());
+        conn.record_latency(Duration::from_millis(85));
+        conn.send(serde_json::json!({"type": "hello"}));
+        manager.add(conn);
+
+        let diagnostics = manager.diagnostics(1).unwrap();
+        assert_eq!(diagnostics["status"], "connected");
+        assert_eq!(diagnostics["client_info"], "web/1.4.0");
+        assert_eq!(diagnostics["latency_ms"], 85);
+        assert_eq!(diagnostics["pending_messages"], 1);
+        assert!(manager.diagnostics(2).is_none());
+
+        manager.disconnect(1);
+        let diagnostics = manager.diagnostics(1).unwrap();
+        assert_eq!(diagnostics["status"], "disconnected");
+        assert!(diagnostics["grace_remaining_secs"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_send_typed_messages() {
+        let mut conn = make_connection(1);
+        conn.send(serde_json::json!({"type": "legacy"}));
+        conn.send(
+            OutboundMessage::new(MessageKind::Snapshot, serde_json::json!({"type": "snapshot"}))
+                .with_priority(MessagePriority::High),
+        );
+
+        assert_eq!(conn.pending_messages[0].kind, MessageKind::Other);
+        assert_eq!(conn.pending_messages[1].kind, MessageKind::Snapshot);
+        assert_eq!(conn.pending_messages[1].priority, MessagePriority::High);
+        assert_eq!(conn.pending_messages[1].message["type"], "snapshot");
+    }
+
+    #[test]
+    fn test_request_dedupe() {
+        let mut conn = make_connection(1);
+        assert!(conn.cached_response("req-1").is_none());
+
+        conn.record_response("req-1".to_string(), serde_json::json!({"ok": true}));
+        assert_eq!(conn.cached_response("req-1"), Some(&serde_json::json!({"ok": true})));
+
+        // Expired results are ignored
+        conn.recent_requests[0].recorded_at = Instant::now()
+            .checked_sub(DEFAULT_REQUEST_DEDUPE_WINDOW + Duration::from_secs(1))
+            .unwrap();
+        assert!(conn.cached_response("req-1").is_none());
+
+        for i in 0..MAX_CACHED_REQUESTS + 1 {
+            conn.record_response(format!("r{}", i), serde_json::Value::Null);
+        }
+        assert_eq!(conn.recent_requests.len(), MAX_CACHED_REQUESTS);
+        assert!(conn.cached_response("r0").is_none());
+    }
+
+    #[test]
+    fn test_config_grace_by_location() {
+        let config = ConnectionConfig::default();
+        assert_eq!(
+            config.grace_period_for(&PlayerLocation::Connected),
+            CONNECTED_RECONNECT_GRACE_PERIOD
+        );
+        assert_eq!(
+            config.grace_period_for(&PlayerLocation::InGame {
+                lobby_id: "l".to_string(),
+                game_id: "g".to_string(),
+            }),
+            IN_GAME_RECONNECT_GRACE_PERIOD
+        );
+    }
+
+    #[test]
+    fn test_manager_heartbeat_degradation() {
+        let mut manager = ConnectionManager::new();
+        manager.add(make_connection(1));
+        manager.add(make_connection(2));
+        manager.get_mut(1).unwrap().last_heartbeat = Instant::now()
+            .checked_sub(DEFAULT_HEARTBEAT_INTERVAL + Duration::from_secs(1))
+            .unwrap();
+
+        assert_eq!(
+            manager.check_heartbeats(),
+            vec![ConnectionEvent::Degraded { player_id: 1 }]
+        );
+        assert_eq!(manager.get(1).unwrap().presence(), Presence::Unstable);
+        assert_eq!(manager.get(2).unwrap().presence(), Presence::Online);
+
+        // Only reported once
+        assert!(manager.check_heartbeats().is_empty());
+
+        assert_eq!(
+            manager.heartbeat(1),
+            Some(ConnectionEvent::Recovered { player_id: 1 })
+        );
+        assert_eq!(manager.heartbeat(2), None);
+        assert_eq!(manager.get(1).unwrap().presence(), Presence::Online);
+
+        manager.disconnect(2);
+        assert_eq!(manager.get(2).unwrap().presence(), Presence::Reconnecting);
+    }
+
     #[test]
     fn test_manager_basic() {
         let mut manager = ConnectionManager::new();
@@ -0,0 +1,154 @@
+//! Daily challenges.
+//!
+//! A `DailyChallenge` pins a seed to a date. Every challenge game created
+//! from it through `GameManager::create_challenge_game` gets the same board
+//! and in-game rolls, and players are ranked by their best finished score
+//! over all of their attempts that day.
+
+use std::collections::HashMap;
+
+use super::game::{Game, GameStatus, Grid, GridExt};
+
+/// A player's best result in a daily challenge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeScore {
+    pub player_id: i64,
+    pub username: String,
+    pub score: i32,
+    /// Game the score was set in
+    pub game_id: String,
+}
+
+impl ChallengeScore {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "player_id": self.player_id,
+            "username": self.username,
+            "score": self.score,
+            "game_id": self.game_id
+        })
+    }
+}
+
+/// A seeded challenge shared by every game created for its date.
+#[derive(Debug, Clone)]
+pub struct DailyChallenge {
+    pub date: chrono::NaiveDate,
+    pub seed: u64,
+    /// Challenge game IDs, oldest first
+    game_ids: Vec<String>,
+    /// Best scores from finished games, by player
+    best_scores: HashMap<i64, ChallengeScore>,
+}
+
+impl DailyChallenge {
+    pub fn new(date: chrono::NaiveDate, seed: u64) -> Self {
+        Self {
+            date,
+            seed,
+            game_ids: Vec::new(),
+            best_scores: HashMap::new(),
+        }
+    }
+
+    /// The board every challenge game is played on.
+    pub fn grid(&self) -> Grid {
+        Grid::generate_seeded(self.seed)
+    }
+
+    /// IDs of the games created for this challenge, oldest first.
+    pub fn game_ids(&self) -> &[String] {
+        &self.game_ids
+    }
+
+    /// Check if a game was created for this challenge.
+    pub fn has_game(&self, game_id: &str) -> bool {
+        self.game_ids.iter().any(|id| id == game_id)
+    }
+
+    pub(crate) fn add_game(&mut self, game_id: String) {
+        self.game_ids.push(game_id);
+    }
+
+    /// Keep each player's best score from a finished game. Players who
+    /// forfeited aren't ranked.
+    pub(crate) fn record(&mut self, game: &Game) {
+        if game.status != GameStatus::Finished || !self.has_game(&game.id) {
+            return;
+        }
+        for player in game.players().filter(|p| !p.forfeited) {
+            let best = self.best_scores.get(&player.player_id);
+            if best.is_some_and(|b| b.score >= player.score()) {
+                continue;
+            }
+            self.best_scores.insert(
+                player.player_id,
+                ChallengeScore {
+                    player_id: player.player_id,
+                    username: player.username.clone(),
+                    score: player.score(),
+                    game_id: game.id.clone(),
+                },
+            );
+        }
+    }
+
+    /// Get a player's best recorded score.
+    pub fn best_score(&self, player_id: i64) -> Option<&ChallengeScore> {
+        self.best_scores.get(&player_id)
+    }
+
+    /// Best recorded scores, highest first (ties by player ID).
+    pub fn leaderboard(&self) -> Vec<ChallengeScore> {
+        let mut scores: Vec<ChallengeScore> = self.best_scores.values().cloned().collect();
+        scores.sort_by_key(|s| (std::cmp::Reverse(s.score), s.player_id));
+        scores
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "date": self.date.to_string(),
+            "seed": self.seed,
+            "game_count": self.game_ids.len(),
+            "leaderboard": self.leaderboard().iter().map(|s| s.to_json()).collect::<Vec<_>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::game::{GamePlayer, ScoreSource};
+
+    fn finished_game(id: &str, scores: &[(i64, i32)]) -> Game {
+        let mut game = Game::new(id.to_string(), "lobby-1".to_string(), Grid::generate_seeded(1));
+        for (order, (player_id, score)) in scores.iter().enumerate() {
+            let name = format!("player{}", player_id);
+            let mut player = GamePlayer::new(*player_id, name.clone(), name, None, order as u8);
+            player.add_score(ScoreSource::Adjustment("test".to_string()), *score);
+            game.add_player(player).unwrap();
+        }
+        game.start().unwrap();
+        game.end().unwrap();
+        game
+    }
+
+    #[test]
+    fn test_best_scores() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let mut challenge = DailyChallenge::new(date, 7);
+        challenge.add_game("g1".to_string());
+        challenge.add_game("g2".to_string());
+
+        challenge.record(&finished_game("g1", &[(1, 10), (2, 30)]));
+        challenge.record(&finished_game("g2", &[(1, 40), (2, 20)]));
+        // Games from outside the challenge don't count
+        challenge.record(&finished_game("other", &[(3, 99)]));
+
+        let board = challenge.leaderboard();
+        assert_eq!(board.len(), 2);
+        assert_eq!((board[0].player_id, board[0].score), (1, 40));
+        assert_eq!(challenge.best_score(2).unwrap().game_id, "g1");
+        assert_eq!(challenge.to_json()["date"], "2024-03-01");
+    }
+}
@@ -0,0 +1,197 @@
+//! Word dictionaries.
+//!
+//! The state crate doesn't ship a word list; callers load one and hand it to
+//! anything that needs to know which words are valid.
+
+use std::collections::{BTreeSet, HashMap};
+
+/// A set of valid words.
+///
+/// Implementations must treat lookups case-insensitively.
+pub trait Dictionary: std::fmt::Debug + Send + Sync {
+    /// Check if a word is valid.
+    fn contains(&self, word: &str) -> bool;
+
+    /// Check if any valid word starts with `prefix`.
+    fn is_prefix(&self, prefix: &str) -> bool;
+}
+
+/// Sorted-set backed dictionary.
+#[derive(Debug, Clone, Default)]
+pub struct WordList {
+    words: BTreeSet<String>,
+}
+
+impl WordList {
+    /// Build a dictionary from words. Entries are uppercased and blanks skipped.
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let words = words
+            .into_iter()
+            .map(|w| w.as_ref().trim().to_uppercase())
+            .filter(|w| !w.is_empty())
+            .collect();
+        Self { words }
+    }
+
+    /// Word count.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Check if dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl Dictionary for WordList {
+    fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_uppercase())
+    }
+
+    fn is_prefix(&self, prefix: &str) -> bool {
+        let prefix = prefix.to_uppercase();
+        self.words
+            .range(prefix.clone()..)
+            .next()
+            .is_some_and(|w| w.starts_with(&prefix))
+    }
+}
+
+/// Trie-backed dictionary, for fast prefix checks on large word lists.
+#[derive(Debug, Clone)]
+pub struct Trie {
+    /// Node 0 is the root
+    nodes: Vec<TrieNode>,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: HashMap<char, usize>,
+    is_word: bool,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+            len: 0,
+        }
+    }
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a trie from words (e.g. the lines of a word list file).
+    /// Entries are uppercased and blanks skipped.
+    pub fn from_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut trie = Self::new();
+        for word in words {
+            trie.insert(word.as_ref());
+        }
+        trie
+    }
+
+    /// Add a word. Returns false if it was blank or already present.
+    pub fn insert(&mut self, word: &str) -> bool {
+        let word = word.trim().to_uppercase();
+        if word.is_empty() {
+            return false;
+        }
+        let mut node = 0;
+        for c in word.chars() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&next) => next,
+                None => {
+                    self.nodes.push(TrieNode::default());
+                    let next = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(c, next);
+                    next
+                }
+            };
+        }
+        let added = !self.nodes[node].is_word;
+        self.nodes[node].is_word = true;
+        if added {
+            self.len += 1;
+        }
+        added
+    }
+
+    /// Word count.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn find(&self, s: &str) -> Option<&TrieNode> {
+        let mut node = 0;
+        for c in s.to_uppercase().chars() {
+            node = *self.nodes[node].children.get(&c)?;
+        }
+        Some(&self.nodes[node])
+    }
+}
+
+impl Dictionary for Trie {
+    fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|n| n.is_word)
+    }
+
+    fn is_prefix(&self, prefix: &str) -> bool {
+        self.find(prefix)
+            .is_some_and(|n| n.is_word || !n.children.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_list() {
+        let dict = WordList::from_words(["cat", "Cats", " dog ", ""]);
+        assert_eq!(dict.len(), 3);
+
+        assert!(dict.contains("CAT"));
+        assert!(dict.contains("cats"));
+        assert!(!dict.contains("ca"));
+
+        assert!(dict.is_prefix("ca"));
+        assert!(dict.is_prefix("CATS"));
+        assert!(!dict.is_prefix("catz"));
+        assert!(!dict.is_prefix("e"));
+    }
+
+    #[test]
+    fn test_trie() {
+        let mut dict = Trie::from_words("cat\nCats\n dog \n\ncat".lines());
+        assert_eq!(dict.len(), 3);
+        assert!(!dict.insert("DOG"));
+
+        assert!(dict.contains("CAT"));
+        assert!(dict.contains("cats"));
+        assert!(!dict.contains("ca"));
+
+        assert!(dict.is_prefix("ca"));
+        assert!(dict.is_prefix("CATS"));
+        assert!(!dict.is_prefix("catz"));
+        assert!(!dict.is_prefix("e"));
+    }
+}
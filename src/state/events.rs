@@ -0,0 +1,504 @@
+//! Per-entity event buffers.
+//!
+//! Every game and lobby numbers its state changes and keeps the most recent
+//! ones, so a client that missed a few updates can catch up with
+//! `events_since(seq)` instead of fetching a full snapshot.
+
+use std::collections::VecDeque;
+
+use super::achievements::Achievement;
+use super::game::{CancelReason, CellChange, ChallengeOutcome, LetterChange, Position};
+use super::powerup::PowerUp;
+
+/// Default number of events kept per entity.
+pub const DEFAULT_EVENT_BUFFER_CAPACITY: usize = 256;
+
+/// An event with its sequence number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequencedEvent<E> {
+    pub seq: u64,
+    pub event: E,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Bounded buffer of recent events with a monotonically increasing sequence.
+///
+/// Sequence numbers start at 1 and never repeat, even after old events have
+/// been dropped.
+#[derive(Debug, Clone)]
+pub struct EventBuffer<E> {
+    events: VecDeque<SequencedEvent<E>>,
+    capacity: usize,
+    last_seq: u64,
+}
+
+impl<E> Default for EventBuffer<E> {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUFFER_CAPACITY)
+    }
+}
+
+impl<E> EventBuffer<E> {
+    /// Create a buffer keeping at most `capacity` events.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            events: VecDeque::new(),
+            capacity,
+            last_seq: 0,
+        }
+    }
+
+    /// Append an event. Returns its sequence number.
+    pub fn push(&mut self, event: E) -> u64 {
+        self.last_seq += 1;
+        if self.events.len() >= self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(SequencedEvent {
+            seq: self.last_seq,
+            event,
+            at: chrono::Utc::now(),
+        });
+        self.last_seq
+    }
+
+    /// Sequence number of the latest event (0 if none yet).
+    pub fn last_seq(&self) -> u64 {
+        self.last_seq
+    }
+
+    /// Get every event after `seq`, oldest first.
+    ///
+    /// Returns None if some of those events were already dropped (or `seq`
+    /// is in the future), meaning the client needs a full snapshot.
+    pub fn events_since(&self, seq: u64) -> Option<Vec<&SequencedEvent<E>>> {
+        if seq > self.last_seq {
+            return None;
+        }
+        let oldest = self.events.front().map_or(self.last_seq + 1, |e| e.seq);
+        if seq + 1 < oldest {
+            return None;
+        }
+        Some(self.events.iter().filter(|e| e.seq > seq).collect())
+    }
+
+    /// Number of buffered events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check if no events are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// A change to a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    PlayerJoined { player_id: i64 },
+    PlayerRemoved { player_id: i64 },
+    /// Knocked out at the end of `round` in elimination mode
+    PlayerEliminated { player_id: i64, round: u8 },
+    PlayerForfeited { player_id: i64 },
+    /// Player IDs in their new turn order
+    TurnOrderChanged { order: Vec<i64> },
+    CountdownStarted { starts_at: chrono::DateTime<chrono::Utc> },
+    CountdownCancelled,
+    Started,
+    TurnAdvanced { player_id: i64, round: u8 },
+    Passed { player_id: i64, round: u8 },
+    TurnExpired { player_id: i64, round: u8 },
+    TurnSkipped { player_id: i64, round: u8 },
+    TimerVoteStarted {
+        initiator_id: i64,
+        target_player_id: i64,
+        votes_needed: u32,
+    },
+    KickVoteStarted {
+        initiator_id: i64,
+        target_player_id: i64,
+        votes_needed: u32,
+    },
+    /// A timer vote passed; `deadline` is the target's new turn deadline
+    TimerStarted {
+        target_player_id: i64,
+        deadline: chrono::DateTime<chrono::Utc>,
+    },
+    PowerUpUsed { player_id: i64, power_up: PowerUp },
+    /// The hint itself is only returned to the player
+    HintUsed { player_id: i64 },
+    WordPlayed {
+        player_id: i64,
+        word: String,
+        path: Vec<Position>,
+        points: i32,
+        gems: i32,
+    },
+    /// `score` is the player's score after the adjustment
+    ScoreAdjusted { player_id: i64, score: i32 },
+    TurnExtended { player_id: i64, deadline: chrono::DateTime<chrono::Utc> },
+    GemsSpawned { positions: Vec<Position> },
+    /// Bonuses moved to new cells at the start of `round`
+    GridDelta { round: u8, changes: Vec<CellChange> },
+    /// Cells given new letters after a play
+    TilesRefreshed { round: u8, changes: Vec<LetterChange> },
+    /// `count` is the number of spectators after the change. Who joined or
+    /// left isn't included, as spectator identities are only shown to the
+    /// host and admins.
+    SpectatorJoined { count: usize },
+    SpectatorLeft { count: usize },
+    ChatPosted { player_id: i64, text: String },
+    Paused { reason: String },
+    Resumed,
+    /// The game was tied and went to overtime among `player_ids`
+    OvertimeStarted { round: u8, player_ids: Vec<i64> },
+    /// A blitz find; the word stays hidden until the game ends
+    BlitzWordFound { player_id: i64, found: usize },
+    AchievementUnlocked { player_id: i64, achievement: Achievement },
+    ChallengeStarted {
+        challenger_id: i64,
+        player_id: i64,
+        word: String,
+    },
+    ChallengeResolved { outcome: ChallengeOutcome },
+    Ended,
+    Cancelled { reason: CancelReason },
+}
+
+impl GameEvent {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::PlayerJoined { player_id } => {
+                serde_json::json!({ "type": "player_joined", "player_id": player_id })
+            }
+            Self::PlayerRemoved { player_id } => {
+                serde_json::json!({ "type": "player_removed", "player_id": player_id })
+            }
+            Self::PlayerForfeited { player_id } => serde_json::json!({
+                "type": "player_forfeited",
+                "player_id": player_id
+            }),
+            Self::PlayerEliminated { player_id, round } => serde_json::json!({
+                "type": "player_eliminated",
+                "player_id": player_id,
+                "round": round
+            }),
+            Self::TurnOrderChanged { order } => {
+                serde_json::json!({ "type": "turn_order_changed", "order": order })
+            }
+            Self::CountdownStarted { starts_at } => serde_json::json!({
+                "type": "countdown_started",
+                "starts_at": starts_at.to_rfc3339()
+            }),
+            Self::CountdownCancelled => serde_json::json!({ "type": "countdown_cancelled" }),
+            Self::Started => serde_json::json!({ "type": "started" }),
+            Self::TurnAdvanced { player_id, round } => serde_json::json!({
+                "type": "turn_advanced",
+                "player_id": player_id,
+                "round": round
+            }),
+            Self::Passed { player_id, round } => serde_json::json!({
+                "type": "passed",
+                "player_id": player_id,
+                "round": round
+            }),
+            Self::WordPlayed {
+                player_id,
+                word,
+                path,
+                points,
+                gems,
+            } => serde_json::json!({
+                "type": "word_played",
+                "player_id": player_id,
+                "word": word,
+                "path": path.iter().map(|p| p.to_json()).collect::<Vec<_>>(),
+                "points": points,
+                "gems": gems
+            }),
+            Self::TimerVoteStarted {
+                initiator_id,
+                target_player_id,
+                votes_needed,
+            } => serde_json::json!({
+                "type": "timer_vote_started",
+                "initiator_id": initiator_id,
+                "target_player_id": target_player_id,
+                "votes_needed": votes_needed
+            }),
+            Self::KickVoteStarted {
+                initiator_id,
+                target_player_id,
+                votes_needed,
+            } => serde_json::json!({
+                "type": "kick_vote_started",
+                "initiator_id": initiator_id,
+                "target_player_id": target_player_id,
+                "votes_needed": votes_needed
+            }),
+            Self::TimerStarted {
+                target_player_id,
+                deadline,
+            } => serde_json::json!({
+                "type": "timer_started",
+                "target_player_id": target_player_id,
+                "deadline": deadline.to_rfc3339()
+            }),
+            Self::TurnExpired { player_id, round } => serde_json::json!({
+                "type": "turn_expired",
+                "player_id": player_id,
+                "round": round
+            }),
+            Self::TurnSkipped { player_id, round } => serde_json::json!({
+                "type": "turn_skipped",
+                "player_id": player_id,
+                "round": round
+            }),
+            Self::HintUsed { player_id } => serde_json::json!({
+                "type": "hint_used",
+                "player_id": player_id
+            }),
+            Self::PowerUpUsed {
+                player_id,
+                power_up,
+            } => serde_json::json!({
+                "type": "power_up_used",
+                "player_id": player_id,
+                "power_up": power_up.as_str()
+            }),
+            Self::ScoreAdjusted { player_id, score } => serde_json::json!({
+                "type": "score_adjusted",
+                "player_id": player_id,
+                "score": score
+            }),
+            Self::TurnExtended {
+                player_id,
+                deadline,
+            } => serde_json::json!({
+                "type": "turn_extended",
+                "player_id": player_id,
+                "deadline": deadline.to_rfc3339()
+            }),
+            Self::GemsSpawned { positions } => serde_json::json!({
+                "type": "gems_spawned",
+                "positions": positions.iter().map(|p| p.to_json()).collect::<Vec<_>>()
+            }),
+            Self::TilesRefreshed { round, changes } => serde_json::json!({
+                "type": "tiles_refreshed",
+                "round": round,
+                "changes": changes.iter().map(|c| c.to_json()).collect::<Vec<_>>()
+            }),
+            Self::GridDelta { round, changes } => serde_json::json!({
+                "type": "grid_delta",
+                "round": round,
+                "changes": changes.iter().map(|c| c.to_json()).collect::<Vec<_>>()
+            }),
+            Self::SpectatorJoined { count } => {
+                serde_json::json!({ "type": "spectator_joined", "count": count })
+            }
+            Self::SpectatorLeft { count } => {
+                serde_json::json!({ "type": "spectator_left", "count": count })
+            }
+            Self::ChatPosted { player_id, text } => serde_json::json!({
+                "type": "chat_posted",
+                "player_id": player_id,
+                "text": text
+            }),
+            Self::Paused { reason } => serde_json::json!({ "type": "paused", "reason": reason }),
+            Self::Resumed => serde_json::json!({ "type": "resumed" }),
+            Self::OvertimeStarted { round, player_ids } => serde_json::json!({
+                "type": "overtime_started",
+                "round": round,
+                "player_ids": player_ids
+            }),
+            Self::BlitzWordFound { player_id, found } => serde_json::json!({
+                "type": "blitz_word_found",
+                "player_id": player_id,
+                "found": found
+            }),
+            Self::AchievementUnlocked {
+                player_id,
+                achievement,
+            } => serde_json::json!({
+                "type": "achievement_unlocked",
+                "player_id": player_id,
+                "achievement": achievement.as_str()
+            }),
+            Self::ChallengeStarted {
+                challenger_id,
+                player_id,
+                word,
+            } => serde_json::json!({
+                "type": "challenge_started",
+                "challenger_id": challenger_id,
+                "player_id": player_id,
+                "word": word
+            }),
+            Self::ChallengeResolved { outcome } => {
+                let mut json = outcome.to_json();
+                json["type"] = "challenge_resolved".into();
+                json
+            }
+            Self::Ended => serde_json::json!({ "type": "ended" }),
+            Self::Cancelled { reason } => serde_json::json!({
+                "type": "cancelled",
+                "reason": reason.to_json()
+            }),
+        }
+    }
+}
+
+/// A change to a lobby.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LobbyEvent {
+    MemberJoined { player_id: i64 },
+    MemberLeft { player_id: i64 },
+    ReadyChanged { player_id: i64, ready: bool },
+    ConnectionChanged { player_id: i64, connected: bool },
+    HostChanged { host_id: Option<i64> },
+    HostOffered { from: i64, to: i64 },
+    /// A pending host offer was declined, expired or invalidated
+    HostOfferWithdrawn { to: i64 },
+    ActiveGameChanged { game_id: Option<String> },
+    LocaleChanged { locale: String },
+    /// The host changed the lobby settings
+    SettingsChanged,
+    /// `players` have to confirm they're ready before `expires_at`
+    ReadyCheckStarted {
+        players: Vec<i64>,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    },
+    ReadyCheckEnded { passed: bool },
+    /// `position` is 1-based
+    WaitlistJoined { player_id: i64, position: usize },
+    WaitlistLeft { player_id: i64 },
+    /// Moved from the waitlist into a seat (followed by `MemberJoined`)
+    WaitlistPromoted { player_id: i64 },
+}
+
+impl LobbyEvent {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::MemberJoined { player_id } => {
+                serde_json::json!({ "type": "member_joined", "player_id": player_id })
+            }
+            Self::MemberLeft { player_id } => {
+                serde_json::json!({ "type": "member_left", "player_id": player_id })
+            }
+            Self::ReadyChanged { player_id, ready } => serde_json::json!({
+                "type": "ready_changed",
+                "player_id": player_id,
+                "ready": ready
+            }),
+            Self::ConnectionChanged {
+                player_id,
+                connected,
+            } => serde_json::json!({
+                "type": "connection_changed",
+                "player_id": player_id,
+                "connected": connected
+            }),
+            Self::HostChanged { host_id } => {
+                serde_json::json!({ "type": "host_changed", "host_id": host_id })
+            }
+            Self::HostOffered { from, to } => {
+                serde_json::json!({ "type": "host_offered", "from": from, "to": to })
+            }
+            Self::HostOfferWithdrawn { to } => {
+                serde_json::json!({ "type": "host_offer_withdrawn", "to": to })
+            }
+            Self::ActiveGameChanged { game_id } => {
+                serde_json::json!({ "type": "active_game_changed", "game_id": game_id })
+            }
+            Self::WaitlistJoined {
+                player_id,
+                position,
+            } => serde_json::json!({
+                "type": "waitlist_joined",
+                "player_id": player_id,
+                "position": position
+            }),
+            Self::WaitlistLeft { player_id } => {
+                serde_json::json!({ "type": "waitlist_left", "player_id": player_id })
+            }
+            Self::WaitlistPromoted { player_id } => {
+                serde_json::json!({ "type": "waitlist_promoted", "player_id": player_id })
+            }
+            Self::LocaleChanged { locale } => {
+                serde_json::json!({ "type": "locale_changed", "locale": locale })
+            }
+            Self::SettingsChanged => serde_json::json!({ "type": "settings_changed" }),
+            Self::ReadyCheckStarted {
+                players,
+                expires_at,
+            } => serde_json::json!({
+                "type": "ready_check_started",
+                "players": players,
+                "expires_at": expires_at.to_rfc3339()
+            }),
+            Self::ReadyCheckEnded { passed } => {
+                serde_json::json!({ "type": "ready_check_ended", "passed": passed })
+            }
+        }
+    }
+}
+
+/// Convert sequenced events to JSON, oldest first.
+pub fn events_to_json<E>(
+    events: &[&SequencedEvent<E>],
+    to_json: impl Fn(&E) -> serde_json::Value,
+) -> serde_json::Value {
+    serde_json::Value::Array(
+        events
+            .iter()
+            .map(|e| {
+                let mut json = to_json(&e.event);
+                json["seq"] = e.seq.into();
+                json["at"] = e.at.to_rfc3339().into();
+                json
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_since() {
+        let mut buffer = EventBuffer::new(3);
+        for i in 0..5 {
+            buffer.push(i);
+        }
+        assert_eq!(buffer.last_seq(), 5);
+        assert_eq!(buffer.len(), 3);
+
+        let seqs: Vec<u64> = buffer.events_since(3).unwrap().iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![4, 5]);
+        assert!(buffer.events_since(5).unwrap().is_empty());
+
+        // Event 2 was dropped; 3 is the oldest kept
+        assert!(buffer.events_since(1).is_none());
+        assert_eq!(buffer.events_since(2).unwrap().len(), 3);
+        assert!(buffer.events_since(6).is_none());
+    }
+
+    #[test]
+    fn test_empty_buffer() {
+        let buffer: EventBuffer<GameEvent> = EventBuffer::default();
+        assert!(buffer.events_since(0).unwrap().is_empty());
+        assert!(buffer.events_since(1).is_none());
+    }
+
+    #[test]
+    fn test_events_to_json() {
+        let mut buffer = EventBuffer::default();
+        buffer.push(LobbyEvent::MemberJoined { player_id: 1 });
+        let json = events_to_json(&buffer.events_since(0).unwrap(), LobbyEvent::to_json);
+
+        assert_eq!(json[0]["type"], "member_joined");
+        assert_eq!(json[0]["seq"], 1);
+    }
+}
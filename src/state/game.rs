@@ -2,7 +2,31 @@
 //!
 //! Tracks active game sessions including grid, players, turns, and scoring.
 
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use super::achievements::AchievementTracker;
+use super::archive::{ArchivedGame, GameArchive};
+use super::blitz::{BlitzFind, BlitzState, DuplicateRule};
+use super::bot::{BotMove, BotStrategy};
+use super::chat::{ChatBuffer, ChatError, ChatMessage};
+use super::daily::{ChallengeScore, DailyChallenge};
+use super::dictionary::Dictionary;
+use super::events::{EventBuffer, GameEvent, SequencedEvent};
+use super::generation::{GridBuilder, GridConfig, LetterWeighting, LetterWeights};
+use super::letters::{LetterSet, LetterValues, Tile};
+use super::path::WordPath;
+use super::hint::{Hint, HintConfig};
+use super::lobby::DEFAULT_LOCALE;
+use super::pagination::{Cursor, Page, SortOrder};
+use super::powerup::{PowerUp, PowerUpConfig, PowerUpEffect, PowerUpTarget, PowerUpUse};
+use super::redaction::{self, RedactionProfile};
+use super::replay::{Replay, ReplayLog, ReplayStep};
+use super::rng::{RngProvider, SeededRng};
+use super::scoring::{Handicap, ScoringRules, WordScore};
+use super::solver::{self, FoundWord, GridQuality};
+use super::stats::{GameResult, GameStats};
 
 /// Grid dimensions.
 pub const GRID_SIZE: usize = 5;
@@ -10,6 +34,577 @@ pub const GRID_SIZE: usize = 5;
 /// Maximum rounds per game.
 pub const DEFAULT_MAX_ROUNDS: u8 = 5;
 
+/// Minimum letters in a playable word, unless `GameConfig::min_word_length`
+/// says otherwise.
+pub const MIN_WORD_LENGTH: usize = 3;
+
+/// Gem placement weight for a plain cell (multiplier cells use `GemConfig::multiplier_weight`).
+pub const PLAIN_CELL_GEM_WEIGHT: u32 = 100;
+
+/// Per-game rule configuration.
+#[derive(Debug, Clone, Default)]
+pub struct GameConfig {
+    /// Multiplayer or solo practice
+    pub mode: GameMode,
+
+    /// Maximum number of passes each player may make (None = unlimited)
+    pub max_passes_per_player: Option<u32>,
+
+    /// Minimum letters in a playable word (None = `MIN_WORD_LENGTH`)
+    pub min_word_length: Option<usize>,
+
+    /// End the game once every player has passed this many times in a row
+    /// (None = never)
+    pub end_after_pass_rounds: Option<u32>,
+
+    /// Gem economy
+    pub gems: GemConfig,
+
+    /// Anti-spam limits on word submissions
+    pub submissions: SubmissionLimits,
+
+    /// Minimum board quality required to start (None = accept any board)
+    pub min_quality: Option<GridQuality>,
+
+    /// How turn order is decided when the game is created from a lobby
+    pub turn_order: TurnOrderStrategy,
+
+    /// How boards are generated
+    pub grid: GridConfig,
+
+    /// When players may post to the game chat
+    pub chat: ChatPolicy,
+
+    /// Most spectators at once (None = unlimited). Eliminated players
+    /// always stay on as spectators.
+    pub max_spectators: Option<usize>,
+
+    /// When spectators may join
+    pub spectator_join: SpectatorJoin,
+
+    /// Which bonuses move to new cells between rounds
+    pub reroll: RerollConfig,
+
+    /// Time each player has for their turn (None = no deadline)
+    pub turn_time_limit: Option<chrono::Duration>,
+
+    /// Longest a game may exist, from creation, before [`Game::tick`] ends
+    /// it (None = no limit)
+    pub max_duration: Option<chrono::Duration>,
+
+    /// Buying extra turn time with gems (None = not allowed)
+    pub turn_extensions: Option<TurnExtensionConfig>,
+
+    /// Timing of votes to put the current player on a timer
+    pub timer_vote: TimerVoteConfig,
+
+    /// Timing of votes to remove a player
+    pub kick_vote: KickVoteConfig,
+
+    /// Penalty for failed word challenges (None = challenges disabled)
+    pub challenges: Option<ChallengeConfig>,
+
+    /// Rematches start with the player after this game's first player
+    pub rotate_rematch_order: bool,
+
+    /// Power-up prices (None = power-ups disabled)
+    pub power_ups: Option<PowerUpConfig>,
+
+    /// Hint price and limits (None = hints disabled)
+    pub hints: Option<HintConfig>,
+
+    /// Flat bonuses for long, rare-letter and many-letter words
+    pub scoring: ScoringRules,
+
+    /// Point values of English letters (letter sets carry their own)
+    pub letter_values: LetterValues,
+
+    /// Scoring handicaps, by player ID
+    pub handicaps: HashMap<i64, Handicap>,
+
+    /// How often each multiplier cell may be claimed
+    pub multiplier_use: MultiplierUse,
+
+    /// Replace the letters of each played word with new ones ("cascade")
+    pub refresh_played_cells: bool,
+
+    /// Eliminate the lowest scorer at the end of each round; the game ends
+    /// when one player remains
+    pub elimination: bool,
+
+    /// What [`Game::end`] does with a tied lead
+    pub tie_break: TieBreak,
+
+    /// Skip disconnected players' turns once they've missed this many in a
+    /// row (None = never skip)
+    pub skip_disconnected_after: Option<u32>,
+
+    /// Cancel a started game when removals leave fewer players than this
+    /// (None = only once nobody is left)
+    pub min_players: Option<usize>,
+
+    /// Word list picked in the lobby settings. Callers look it up and attach
+    /// it with [`Game::with_dictionary`].
+    pub dictionary_id: Option<String>,
+}
+
+/// Pricing and limits for buying extra turn time with gems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnExtensionConfig {
+    /// Seconds bought per gem; partial gems round up
+    pub seconds_per_gem: u32,
+    /// Maximum seconds a player may add to a single turn
+    pub max_seconds_per_turn: Option<u32>,
+    /// Maximum seconds a player may add over the whole game
+    pub max_seconds_per_game: Option<u32>,
+}
+
+impl Default for TurnExtensionConfig {
+    fn default() -> Self {
+        Self {
+            seconds_per_gem: 10,
+            max_seconds_per_turn: Some(30),
+            max_seconds_per_game: None,
+        }
+    }
+}
+
+/// Timing of timer votes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerVoteConfig {
+    /// How long a vote stays open
+    pub vote_seconds: u32,
+    /// Time the current player has left once a vote passes
+    pub timer_seconds: u32,
+    /// Wait after a timer before another vote may start
+    pub cooldown_seconds: u32,
+}
+
+impl Default for TimerVoteConfig {
+    fn default() -> Self {
+        Self {
+            vote_seconds: 30,
+            timer_seconds: 30,
+            cooldown_seconds: 60,
+        }
+    }
+}
+
+/// Timing of kick votes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KickVoteConfig {
+    /// How long a vote stays open
+    pub vote_seconds: u32,
+    /// Wait after a vote closes before another may start
+    pub cooldown_seconds: u32,
+}
+
+impl Default for KickVoteConfig {
+    fn default() -> Self {
+        Self {
+            vote_seconds: 30,
+            cooldown_seconds: 120,
+        }
+    }
+}
+
+/// Rules for challenging the previous play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChallengeConfig {
+    /// Points the challenger loses when the word stands
+    pub penalty: i32,
+}
+
+impl Default for ChallengeConfig {
+    fn default() -> Self {
+        Self { penalty: 5 }
+    }
+}
+
+impl TurnExtensionConfig {
+    /// Gems charged for `seconds` of extra time (None if it doesn't fit in
+    /// an `i32`).
+    pub fn cost(&self, seconds: u32) -> Option<i32> {
+        i32::try_from(seconds.div_ceil(self.seconds_per_gem.max(1))).ok()
+    }
+}
+
+/// How often a multiplier cell may be claimed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MultiplierUse {
+    /// Every word through the cell gets the multiplier
+    #[default]
+    Unlimited,
+    /// Only the first word through the cell gets it
+    OncePerGame,
+    /// Each player's first word through the cell gets it
+    OncePerPlayer,
+}
+
+impl MultiplierUse {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unlimited => "unlimited",
+            Self::OncePerGame => "once_per_game",
+            Self::OncePerPlayer => "once_per_player",
+        }
+    }
+}
+
+/// Score given to a spectator who joins a game in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StartingScore {
+    #[default]
+    Zero,
+    /// The lowest current score
+    Lowest,
+    /// The current average score, rounded down
+    Average,
+}
+
+impl StartingScore {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::Lowest => "lowest",
+            Self::Average => "average",
+        }
+    }
+}
+
+/// Which bonuses are moved to new cells at each round boundary.
+///
+/// Both are off by default, keeping bonus positions fixed for the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RerollConfig {
+    /// Move every multiplier to a random cell
+    pub multipliers: bool,
+    /// Clear gems and place the same number again (weighted as in `GemConfig`)
+    pub gems: bool,
+}
+
+impl RerollConfig {
+    /// Check if anything is rerolled.
+    pub fn is_enabled(&self) -> bool {
+        self.multipliers || self.gems
+    }
+
+    /// Move bonuses on the grid. Returns the cells that changed.
+    pub fn apply(&self, grid: &mut Grid, gems: &GemConfig, rng: &mut SeededRng) -> Vec<CellChange> {
+        let before = grid.clone();
+
+        if self.multipliers {
+            let multipliers: Vec<Multiplier> = grid
+                .iter_mut()
+                .flatten()
+                .filter_map(|c| c.multiplier.take())
+                .collect();
+            let mut cells: Vec<Position> = (0..GRID_SIZE)
+                .flat_map(|row| (0..GRID_SIZE).map(move |col| Position::new(row, col)))
+                .filter(|p| !grid[p.row][p.col].blocked)
+                .collect();
+            rng.shuffle(&mut cells);
+            for (pos, multiplier) in cells.into_iter().zip(multipliers) {
+                grid[pos.row][pos.col].multiplier = Some(multiplier);
+            }
+        }
+
+        if self.gems {
+            let mut count = 0;
+            for cell in grid.iter_mut().flatten().filter(|c| c.has_gem) {
+                cell.has_gem = false;
+                count += 1;
+            }
+            gems.place_gems(grid, count, rng);
+        }
+
+        let mut changes = Vec::new();
+        for row in 0..GRID_SIZE {
+            for col in 0..GRID_SIZE {
+                let (old, new) = (&before[row][col], &grid[row][col]);
+                if old.multiplier != new.multiplier || old.has_gem != new.has_gem {
+                    changes.push(CellChange {
+                        position: Position::new(row, col),
+                        multiplier: new.multiplier,
+                        has_gem: new.has_gem,
+                    });
+                }
+            }
+        }
+        changes
+    }
+}
+
+/// New bonus state of a cell after a reroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellChange {
+    pub position: Position,
+    pub multiplier: Option<Multiplier>,
+    pub has_gem: bool,
+}
+
+impl CellChange {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "row": self.position.row,
+            "col": self.position.col,
+            "multiplier": self.multiplier.map(|m| m.as_str()),
+            "has_gem": self.has_gem
+        })
+    }
+}
+
+/// New letter on a cell after `Game::refresh_cells`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LetterChange {
+    pub position: Position,
+    pub letter: char,
+    pub digraph: Option<char>,
+    pub value: u8,
+}
+
+impl LetterChange {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "row": self.position.row,
+            "col": self.position.col,
+            "letter": std::iter::once(self.letter).chain(self.digraph).collect::<String>(),
+            "value": self.value
+        })
+    }
+}
+
+/// When players may post to the game chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChatPolicy {
+    /// No restrictions
+    #[default]
+    Anytime,
+    /// Only within `window` of a turn starting
+    BetweenTurns { window: chrono::Duration },
+    /// Anyone but the player whose turn it is
+    MutedOnTurn,
+}
+
+impl ChatPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Anytime => "anytime",
+            Self::BetweenTurns { .. } => "between_turns",
+            Self::MutedOnTurn => "muted_on_turn",
+        }
+    }
+}
+
+/// What happens when a game ends with the lead tied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    /// Tied players share first place
+    #[default]
+    Shared,
+    /// The tied players play extra rounds, one at a time, until the tie
+    /// breaks or `max_rounds` overtime rounds have been played
+    Overtime { max_rounds: u8 },
+}
+
+/// When spectators may join a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpectatorJoin {
+    /// At any point, including before the game starts
+    #[default]
+    Anytime,
+    /// Only once the game has started
+    OnceStarted,
+}
+
+impl SpectatorJoin {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Anytime => "anytime",
+            Self::OnceStarted => "once_started",
+        }
+    }
+}
+
+/// Who plays a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    #[default]
+    Multiplayer,
+    /// One player practicing alone. The game ends after `max_rounds` turns,
+    /// or once `time_limit` has passed (pauses excluded). Solo games aren't
+    /// indexed as the player's game in `GameManager`.
+    Solo { time_limit: Option<chrono::Duration> },
+    /// Everyone plays for one team score. The game ends once the team
+    /// reaches `target`, after `max_rounds` turns, or once `time_limit` has
+    /// passed. There's no elimination or overtime, and the team wins
+    /// together only if it reached the target (or had none).
+    Coop {
+        target: Option<i32>,
+        time_limit: Option<chrono::Duration>,
+    },
+    /// No turns: everyone plays the same board at once through
+    /// `Game::submit_blitz_word` until `window` closes, then words found by
+    /// several players are settled by `duplicates`.
+    Blitz {
+        window: chrono::Duration,
+        duplicates: DuplicateRule,
+    },
+}
+
+impl GameMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Multiplayer => "multiplayer",
+            Self::Solo { .. } => "solo",
+            Self::Coop { .. } => "coop",
+            Self::Blitz { .. } => "blitz",
+        }
+    }
+
+    pub fn is_solo(&self) -> bool {
+        matches!(self, Self::Solo { .. })
+    }
+
+    pub fn is_coop(&self) -> bool {
+        matches!(self, Self::Coop { .. })
+    }
+
+    pub fn is_blitz(&self) -> bool {
+        matches!(self, Self::Blitz { .. })
+    }
+
+    /// How long the game may run, pauses excluded.
+    pub fn time_limit(&self) -> Option<chrono::Duration> {
+        match self {
+            Self::Multiplayer => None,
+            Self::Solo { time_limit } | Self::Coop { time_limit, .. } => *time_limit,
+            Self::Blitz { window, .. } => Some(*window),
+        }
+    }
+}
+
+/// How the turn order of a new game is decided.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TurnOrderStrategy {
+    /// Order players joined the lobby
+    #[default]
+    JoinOrder,
+    /// Shuffled with a recorded seed, so the order can be reproduced
+    Random { seed: u64 },
+    /// Lowest rating first (unrated players count as lowest), ties by join order
+    ByRating,
+    /// Explicit order; must list every player exactly once
+    Custom(Vec<i64>),
+}
+
+impl TurnOrderStrategy {
+    /// Random order with a seed drawn from `rng`.
+    pub fn random_from(rng: &mut RngProvider) -> Self {
+        Self::Random {
+            seed: rng.next_seed(),
+        }
+    }
+
+    /// Order players, given in join order, and assign their `turn_order`.
+    pub fn apply(&self, mut players: Vec<GamePlayer>) -> Result<Vec<GamePlayer>, GameError> {
+        match self {
+            Self::JoinOrder => {}
+            Self::Random { seed } => SeededRng::new(*seed).shuffle(&mut players),
+            Self::ByRating => players.sort_by_key(|p| p.rating),
+            Self::Custom(order) => {
+                let mut ids: Vec<i64> = players.iter().map(|p| p.player_id).collect();
+                let mut expected = order.clone();
+                ids.sort_unstable();
+                expected.sort_unstable();
+                if ids != expected {
+                    return Err(GameError::InvalidTurnOrder);
+                }
+                players.sort_by_key(|p| order.iter().position(|id| *id == p.player_id));
+            }
+        }
+
+        for (index, player) in players.iter_mut().enumerate() {
+            player.turn_order = index as u8;
+        }
+        Ok(players)
+    }
+}
+
+/// Per-player limits on how often words may be submitted.
+///
+/// Mostly relevant to simultaneous play, where nothing else stops a player
+/// from flooding the game with guesses.
+#[derive(Debug, Clone, Default)]
+pub struct SubmissionLimits {
+    /// Minimum time between two submissions by the same player
+    pub min_interval: Option<chrono::Duration>,
+    /// Maximum submissions per player per round
+    pub max_per_round: Option<u32>,
+}
+
+/// Gem economy and spawn rules.
+#[derive(Debug, Clone)]
+pub struct GemConfig {
+    /// Gems placed when the game starts
+    pub initial_gems: usize,
+    /// Gems added at the start of each new round
+    pub respawn_per_round: usize,
+    /// Maximum gems on the board at once
+    pub max_on_board: usize,
+    /// Placement weight for multiplier cells, relative to `PLAIN_CELL_GEM_WEIGHT`
+    /// (0 = never place gems on multipliers)
+    pub multiplier_weight: u32,
+}
+
+impl Default for GemConfig {
+    fn default() -> Self {
+        Self {
+            initial_gems: 0,
+            respawn_per_round: 0,
+            max_on_board: 5,
+            multiplier_weight: 25,
+        }
+    }
+}
+
+impl GemConfig {
+    /// Place up to `count` gems on cells without one, respecting `max_on_board`.
+    /// Returns the positions that received a gem.
+    pub fn place_gems(&self, grid: &mut Grid, count: usize, rng: &mut SeededRng) -> Vec<Position> {
+        let on_board = grid.iter().flatten().filter(|c| c.has_gem).count();
+        let count = count.min(self.max_on_board.saturating_sub(on_board));
+
+        let mut candidates: Vec<Position> = Vec::new();
+        let mut weights: Vec<u32> = Vec::new();
+        for (row, cells) in grid.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                if cell.has_gem || cell.blocked {
+                    continue;
+                }
+                candidates.push(Position::new(row, col));
+                weights.push(if cell.multiplier.is_some() {
+                    self.multiplier_weight
+                } else {
+                    PLAIN_CELL_GEM_WEIGHT
+                });
+            }
+        }
+
+        let mut placed = Vec::new();
+        while placed.len() < count {
+            let Some(i) = rng.choose_weighted(&weights) else {
+                break;
+            };
+            let pos = candidates[i];
+            grid[pos.row][pos.col].has_gem = true;
+            weights[i] = 0;
+            placed.push(pos);
+        }
+        placed
+    }
+}
+
 /// Game state machine states.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum GameStatus {
@@ -20,6 +615,8 @@ pub enum GameStatus {
     Starting,
     /// Game in progress
     InProgress,
+    /// Game frozen mid-play (e.g. too many players dropped); resumes where it left off
+    Paused,
     /// Game completed normally
     Finished,
     /// Game cancelled (player left, etc)
@@ -32,6 +629,7 @@ impl GameStatus {
             Self::Idle => "idle",
             Self::Starting => "starting",
             Self::InProgress => "in_progress",
+            Self::Paused => "paused",
             Self::Finished => "finished",
             Self::Cancelled => "cancelled",
         }
@@ -42,12 +640,67 @@ impl GameStatus {
         matches!(self, Self::InProgress)
     }
 
+    /// Check if game has started and not ended (in progress or paused).
+    pub fn is_started(&self) -> bool {
+        matches!(self, Self::InProgress | Self::Paused)
+    }
+
     /// Check if game is terminal (cannot change).
     pub fn is_terminal(&self) -> bool {
         matches!(self, Self::Finished | Self::Cancelled)
     }
 }
 
+/// Why a game was cancelled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CancelReason {
+    /// Aborted by the host or initiator before play began
+    AbortedBeforeStart { by: i64 },
+    /// The lobby host left
+    HostLeft,
+    /// Too few players were left to continue
+    NotEnoughPlayers,
+    /// The players voted to stop
+    Vote,
+    /// Cancelled by an admin
+    AdminAction { by: i64 },
+    /// The server is shutting down
+    ServerShutdown,
+    /// Never started within `GameConfig::max_duration`
+    TimedOut,
+    /// Free-form reason
+    Custom(String),
+}
+
+impl CancelReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AbortedBeforeStart { .. } => "aborted_before_start",
+            Self::HostLeft => "host_left",
+            Self::NotEnoughPlayers => "not_enough_players",
+            Self::Vote => "vote",
+            Self::AdminAction { .. } => "admin_action",
+            Self::ServerShutdown => "server_shutdown",
+            Self::TimedOut => "timed_out",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::AbortedBeforeStart { by } | Self::AdminAction { by } => serde_json::json!({
+                "type": self.as_str(),
+                "by": by
+            }),
+            Self::Custom(message) => serde_json::json!({
+                "type": self.as_str(),
+                "message": message
+            }),
+            _ => serde_json::json!({ "type": self.as_str() }),
+        }
+    }
+}
+
 /// Tile multiplier types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Multiplier {
@@ -70,18 +723,24 @@ impl Multiplier {
 #[derive(Debug, Clone)]
 pub struct GridCell {
     pub letter: char,
+    /// Second letter of a digraph tile, e.g. Spanish "LL" (see `LetterSet`)
+    pub digraph: Option<char>,
     pub value: u8,
     pub multiplier: Option<Multiplier>,
     pub has_gem: bool,
+    /// Obstacle: can't be used in a path
+    pub blocked: bool,
 }
 
 impl GridCell {
     pub fn new(letter: char) -> Self {
         Self {
             letter: letter.to_ascii_uppercase(),
-            value: letter_value(letter),
+            digraph: None,
+            value: LetterValues::default().get(letter),
             multiplier: None,
             has_gem: false,
+            blocked: false,
         }
     }
 
@@ -95,9 +754,19 @@ impl GridCell {
         self
     }
 
+    pub fn with_blocked(mut self) -> Self {
+        self.blocked = true;
+        self
+    }
+
+    /// The letters the cell spells, in order (two for a digraph tile).
+    pub fn letters(&self) -> impl Iterator<Item = char> {
+        std::iter::once(self.letter).chain(self.digraph)
+    }
+
     pub fn to_json(&self) -> serde_json::Value {
         let mut obj = serde_json::json!({
-            "letter": self.letter.to_string(),
+            "letter": self.letters().collect::<String>(),
             "value": self.value
         });
         if let Some(m) = &self.multiplier {
@@ -106,49 +775,207 @@ impl GridCell {
         if self.has_gem {
             obj["has_gem"] = serde_json::json!(true);
         }
+        if self.blocked {
+            obj["blocked"] = serde_json::json!(true);
+        }
         obj
     }
 }
 
-/// Get point value for a letter.
+/// Get the English point value for a letter (see `LetterSet` for other languages,
+/// `GameConfig::letter_values` for house values).
 pub fn letter_value(letter: char) -> u8 {
-    match letter.to_ascii_uppercase() {
-        'A' | 'E' | 'I' | 'O' | 'U' | 'L' | 'N' | 'S' | 'T' | 'R' => 1,
-        'D' | 'G' => 2,
-        'B' | 'C' | 'M' | 'P' => 3,
-        'F' | 'H' | 'V' | 'W' | 'Y' => 4,
-        'K' => 5,
-        'J' | 'X' => 8,
-        'Q' | 'Z' => 10,
-        _ => 1,
-    }
+    LetterValues::default().get(letter)
 }
 
 /// 5x5 game grid.
 pub type Grid = [[GridCell; GRID_SIZE]; GRID_SIZE];
 
-/// Grid position.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    pub row: usize,
-    pub col: usize,
+/// Extension methods for [`Grid`].
+pub trait GridExt {
+    /// Generate a default board from a seed. The same seed always gives the
+    /// same board, e.g. for daily challenges or reproducing a reported game.
+    fn generate_seeded(seed: u64) -> Self;
+
+    /// Measure how playable the board is using the solver.
+    fn quality(&self, dict: &dyn Dictionary) -> GridQuality;
+
+    /// Encode the board as a compact string for storage or URLs, e.g.
+    /// `"HELLO|WORLD|...#DL:0,3;DW:2,2#G:1,4"`.
+    ///
+    /// Rows are separated by `|`. Digraph cells and cells whose value isn't
+    /// the standard one are bracketed with their value, e.g. `[LL:8]`. The
+    /// rows are followed by `#`-separated lists of multipliers (`DL`, `TL`,
+    /// `DW`), gems (`G`) and blocked cells (`X`); empty lists are left out.
+    fn to_compact(&self) -> String;
+
+    /// Decode a board from [`GridExt::to_compact`].
+    fn parse(compact: &str) -> Result<Self, GameError>
+    where
+        Self: Sized;
 }
 
-impl Position {
-    pub fn new(row: usize, col: usize) -> Self {
-        Self { row, col }
+impl GridExt for Grid {
+    fn generate_seeded(seed: u64) -> Self {
+        GridBuilder::default().build_seeded(seed)
     }
 
-    /// Check if position is valid (within grid bounds).
-    pub fn is_valid(&self) -> bool {
-        self.row < GRID_SIZE && self.col < GRID_SIZE
+    fn quality(&self, dict: &dyn Dictionary) -> GridQuality {
+        GridQuality::from_words(&solver::solve(self, dict, MIN_WORD_LENGTH))
     }
 
-    /// Check if two positions are adjacent (including diagonals).
-    pub fn is_adjacent_to(&self, other: &Position) -> bool {
-        let row_diff = (self.row as i32 - other.row as i32).abs();
-        let col_diff = (self.col as i32 - other.col as i32).abs();
-        row_diff <= 1 && col_diff <= 1 && (row_diff != 0 || col_diff != 0)
+    fn to_compact(&self) -> String {
+        let standard = LetterValues::default();
+        let rows: Vec<String> = self
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|c| {
+                        if c.digraph.is_none() && c.value == standard.get(c.letter) {
+                            c.letter.to_string()
+                        } else {
+                            format!("[{}:{}]", c.letters().collect::<String>(), c.value)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let cells = || {
+            (0..GRID_SIZE).flat_map(|row| (0..GRID_SIZE).map(move |col| Position::new(row, col)))
+        };
+        let list = |tag: fn(&GridCell) -> Option<&'static str>| {
+            cells()
+                .filter_map(|p| {
+                    let tag = tag(&self[p.row][p.col])?;
+                    Some(format!("{}:{},{}", tag, p.row, p.col))
+                })
+                .collect::<Vec<_>>()
+                .join(";")
+        };
+        let sections = [
+            list(|c| c.multiplier.map(compact_multiplier)),
+            list(|c| c.has_gem.then_some("G")),
+            list(|c| c.blocked.then_some("X")),
+        ];
+
+        let mut compact = rows.join("|");
+        for section in sections.iter().filter(|s| !s.is_empty()) {
+            compact.push('#');
+            compact.push_str(section);
+        }
+        compact
+    }
+
+    fn parse(compact: &str) -> Result<Self, GameError> {
+        let mut sections = compact.split('#');
+        let rows: Vec<&str> = sections.next().unwrap_or_default().split('|').collect();
+        if rows.len() != GRID_SIZE {
+            return Err(GameError::InvalidGrid);
+        }
+        let mut cells: Vec<GridCell> = Vec::with_capacity(GRID_SIZE * GRID_SIZE);
+        for row in &rows {
+            let parsed = parse_compact_row(row).ok_or(GameError::InvalidGrid)?;
+            if parsed.len() != GRID_SIZE {
+                return Err(GameError::InvalidGrid);
+            }
+            cells.extend(parsed);
+        }
+        let mut cells = cells.into_iter();
+        let mut grid: Grid = std::array::from_fn(|_| {
+            std::array::from_fn(|_| cells.next().unwrap_or_else(|| GridCell::new('A')))
+        });
+
+        for entry in sections.flat_map(|s| s.split(';')) {
+            let (tag, pos) = entry.split_once(':').ok_or(GameError::InvalidGrid)?;
+            let (row, col) = pos.split_once(',').ok_or(GameError::InvalidGrid)?;
+            let pos = Position::new(
+                row.parse().map_err(|_| GameError::InvalidGrid)?,
+                col.parse().map_err(|_| GameError::InvalidGrid)?,
+            );
+            if !pos.is_valid() {
+                return Err(GameError::InvalidGrid);
+            }
+            let cell = &mut grid[pos.row][pos.col];
+            match tag {
+                "DL" => cell.multiplier = Some(Multiplier::DoubleLetter),
+                "TL" => cell.multiplier = Some(Multiplier::TripleLetter),
+                "DW" => cell.multiplier = Some(Multiplier::DoubleWord),
+                "G" => cell.has_gem = true,
+                "X" => cell.blocked = true,
+                _ => return Err(GameError::InvalidGrid),
+            }
+        }
+        Ok(grid)
+    }
+}
+
+fn compact_multiplier(multiplier: Multiplier) -> &'static str {
+    match multiplier {
+        Multiplier::DoubleLetter => "DL",
+        Multiplier::TripleLetter => "TL",
+        Multiplier::DoubleWord => "DW",
+    }
+}
+
+/// Parse one row of [`GridExt::to_compact`] letters.
+fn parse_compact_row(row: &str) -> Option<Vec<GridCell>> {
+    let mut cells = Vec::new();
+    let mut chars = row.chars();
+    while let Some(c) = chars.next() {
+        if c != '[' {
+            if !c.is_alphabetic() {
+                return None;
+            }
+            cells.push(GridCell::new(c));
+            continue;
+        }
+        let token: String = chars.by_ref().take_while(|c| *c != ']').collect();
+        let (letters, value) = token.split_once(':')?;
+        let mut letters = letters.chars();
+        let mut cell = GridCell::new(letters.next()?);
+        cell.digraph = letters.next().map(|c| c.to_ascii_uppercase());
+        if letters.next().is_some() {
+            return None;
+        }
+        cell.value = value.parse().ok()?;
+        cells.push(cell);
+    }
+    Some(cells)
+}
+
+/// Grid position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Check if position is valid (within grid bounds).
+    pub fn is_valid(&self) -> bool {
+        self.row < GRID_SIZE && self.col < GRID_SIZE
+    }
+
+    /// Check if two positions are adjacent (including diagonals).
+    pub fn is_adjacent_to(&self, other: &Position) -> bool {
+        let row_diff = (self.row as i32 - other.row as i32).abs();
+        let col_diff = (self.col as i32 - other.col as i32).abs();
+        row_diff <= 1 && col_diff <= 1 && (row_diff != 0 || col_diff != 0)
+    }
+
+    /// Get all in-bounds adjacent positions (including diagonals).
+    pub fn neighbors(&self) -> impl Iterator<Item = Position> + '_ {
+        let rows = self.row.saturating_sub(1)..=(self.row + 1).min(GRID_SIZE - 1);
+        rows.flat_map(move |row| {
+            let cols = self.col.saturating_sub(1)..=(self.col + 1).min(GRID_SIZE - 1);
+            cols.map(move |col| Position::new(row, col))
+        })
+        .filter(move |p| p != self)
     }
 
     pub fn to_json(&self) -> serde_json::Value {
@@ -163,11 +990,34 @@ pub struct GamePlayer {
     pub user_id: String,
     pub username: String,
     pub avatar_url: Option<String>,
-    pub score: i32,
-    pub gems: i32,
+    /// Skill rating, if the player has one
+    pub rating: Option<i32>,
+    /// Changed only through `add_score`, so the audit trail stays complete
+    score: i32,
+    /// Changed only through `add_gems`
+    gems: i32,
     pub turn_order: u8,
     pub is_connected: bool,
     pub words_played: Vec<String>,
+    pub passes: u32,
+    pub last_submission_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub submissions_this_round: u32,
+    /// Turns in a row that ran out or were skipped (reset by playing or passing)
+    pub missed_turns: u32,
+    /// Turn time bought with gems over the whole game
+    pub extension_seconds: u32,
+    /// Turns finished (played, passed, timed out or skipped by an admin)
+    pub turns_taken: u32,
+    /// Time spent on finished turns, pauses excluded
+    pub turn_time: chrono::Duration,
+    /// Turns that ran out of time
+    pub timeouts: u32,
+    /// Resigned from the game; out of the rotation with their score frozen
+    pub forfeited: bool,
+    /// Moves are picked by a `BotStrategy` (see `Game::play_bot_turn`)
+    pub is_bot: bool,
+    /// Every change to `score` and `gems`, in order
+    score_history: Vec<ScoreDelta>,
 }
 
 impl GamePlayer {
@@ -183,12 +1033,86 @@ impl GamePlayer {
             user_id,
             username,
             avatar_url,
+            rating: None,
             score: 0,
             gems: 0,
             turn_order,
             is_connected: true,
             words_played: Vec::new(),
+            passes: 0,
+            last_submission_at: None,
+            submissions_this_round: 0,
+            missed_turns: 0,
+            extension_seconds: 0,
+            turns_taken: 0,
+            turn_time: chrono::Duration::zero(),
+            timeouts: 0,
+            forfeited: false,
+            is_bot: false,
+            score_history: Vec::new(),
+        }
+    }
+
+    /// Current score.
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+
+    /// Gems held.
+    pub fn gems(&self) -> i32 {
+        self.gems
+    }
+
+    /// Change the player's score, recording why.
+    pub fn add_score(&mut self, source: ScoreSource, amount: i32) {
+        self.score += amount;
+        self.record_delta(source, ScoreField::Score, amount);
+    }
+
+    /// Change the player's gem count, recording why.
+    pub fn add_gems(&mut self, source: ScoreSource, amount: i32) {
+        self.gems += amount;
+        self.record_delta(source, ScoreField::Gems, amount);
+    }
+
+    fn record_delta(&mut self, source: ScoreSource, field: ScoreField, amount: i32) {
+        self.score_history.push(ScoreDelta {
+            source,
+            field,
+            amount,
+            at: chrono::Utc::now(),
+        });
+    }
+
+    /// Get every recorded score and gem change, oldest first.
+    pub fn score_history(&self) -> &[ScoreDelta] {
+        &self.score_history
+    }
+
+    /// Recompute a total from the audit trail.
+    pub fn recomputed(&self, field: ScoreField) -> i32 {
+        self.score_history
+            .iter()
+            .filter(|d| d.field == field)
+            .map(|d| d.amount)
+            .sum()
+    }
+
+    /// Average time per finished turn (None if no turn finished yet).
+    pub fn average_turn_time(&self) -> Option<chrono::Duration> {
+        (self.turns_taken > 0).then(|| self.turn_time / self.turns_taken as i32)
+    }
+
+    /// Check that `score` and `gems` match the audit trail.
+    pub fn verify_score(&self) -> Result<(), GameError> {
+        if self.score != self.recomputed(ScoreField::Score)
+            || self.gems != self.recomputed(ScoreField::Gems)
+        {
+            return Err(GameError::ScoreMismatch {
+                player_id: self.player_id,
+            });
         }
+        Ok(())
     }
 
     pub fn to_json(&self) -> serde_json::Value {
@@ -199,7 +1123,69 @@ impl GamePlayer {
             "score": self.score,
             "gems": self.gems,
             "turn_order": self.turn_order,
-            "is_connected": self.is_connected
+            "is_connected": self.is_connected,
+            "passes": self.passes,
+            "turns_taken": self.turns_taken,
+            "turn_time_ms": self.turn_time.num_milliseconds(),
+            "average_turn_ms": self.average_turn_time().map(|d| d.num_milliseconds()),
+            "timeouts": self.timeouts,
+            "forfeited": self.forfeited,
+            "is_bot": self.is_bot
+        })
+    }
+}
+
+/// Why a score or gem count changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScoreSource {
+    /// Playing a word
+    Word(String),
+    /// A bonus (e.g. long word, gem pickup)
+    Bonus(String),
+    /// Manual correction
+    Adjustment(String),
+    /// Gems spent on something (e.g. extra turn time)
+    Purchase(String),
+}
+
+impl ScoreSource {
+    pub fn to_json(&self) -> serde_json::Value {
+        let (kind, detail) = match self {
+            Self::Word(word) => ("word", word),
+            Self::Bonus(reason) => ("bonus", reason),
+            Self::Adjustment(reason) => ("adjustment", reason),
+            Self::Purchase(item) => ("purchase", item),
+        };
+        serde_json::json!({ "kind": kind, "detail": detail })
+    }
+}
+
+/// Which total a delta applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreField {
+    Score,
+    Gems,
+}
+
+/// One entry in a player's score audit trail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreDelta {
+    pub source: ScoreSource,
+    pub field: ScoreField,
+    pub amount: i32,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ScoreDelta {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "source": self.source.to_json(),
+            "field": match self.field {
+                ScoreField::Score => "score",
+                ScoreField::Gems => "gems",
+            },
+            "amount": self.amount,
+            "at": self.at.to_rfc3339()
         })
     }
 }
@@ -224,7 +1210,7 @@ impl Spectator {
 }
 
 /// Timer vote state.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum TimerVoteState {
     #[default]
     Idle,
@@ -282,6 +1268,304 @@ impl TimerVoteState {
     }
 }
 
+/// Kick vote state.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum KickVoteState {
+    #[default]
+    Idle,
+    VoteInProgress {
+        initiator_id: i64,
+        target_player_id: i64,
+        voters: HashSet<i64>,
+        votes_needed: u32,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    },
+    Cooldown {
+        expires_at: chrono::DateTime<chrono::Utc>,
+    },
+}
+
+impl KickVoteState {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Idle => serde_json::json!({"status": "idle"}),
+            Self::VoteInProgress {
+                target_player_id,
+                voters,
+                votes_needed,
+                expires_at,
+                ..
+            } => {
+                let remaining = (*expires_at - chrono::Utc::now())
+                    .num_seconds()
+                    .max(0);
+                serde_json::json!({
+                    "status": "vote_in_progress",
+                    "target_player_id": target_player_id,
+                    "current_votes": voters.len(),
+                    "votes_needed": votes_needed,
+                    "seconds_remaining": remaining
+                })
+            }
+            Self::Cooldown { expires_at } => {
+                let remaining = (*expires_at - chrono::Utc::now())
+                    .num_seconds()
+                    .max(0);
+                serde_json::json!({
+                    "status": "cooldown",
+                    "seconds_remaining": remaining
+                })
+            }
+        }
+    }
+}
+
+/// Word challenge state.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ChallengeState {
+    #[default]
+    Idle,
+    /// The turn is frozen until the challenge is resolved
+    Pending {
+        challenger_id: i64,
+        /// Who played the word
+        player_id: i64,
+        word: String,
+        /// Time left on the frozen turn (None without a turn limit)
+        turn_remaining: Option<chrono::Duration>,
+    },
+}
+
+impl ChallengeState {
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending { .. })
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Idle => serde_json::json!({"status": "idle"}),
+            Self::Pending {
+                challenger_id,
+                player_id,
+                word,
+                ..
+            } => serde_json::json!({
+                "status": "pending",
+                "challenger_id": challenger_id,
+                "player_id": player_id,
+                "word": word
+            }),
+        }
+    }
+}
+
+/// How a word challenge was settled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengeOutcome {
+    pub challenger_id: i64,
+    /// Who played the word
+    pub player_id: i64,
+    pub word: String,
+    /// The word stood, so the challenger paid the penalty
+    pub valid: bool,
+    /// Points taken from the player (invalid word) or the challenger
+    pub points: i32,
+}
+
+impl ChallengeOutcome {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "challenger_id": self.challenger_id,
+            "player_id": self.player_id,
+            "word": self.word,
+            "valid": self.valid,
+            "points": self.points
+        })
+    }
+}
+
+/// What a player did with their turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TurnAction {
+    /// Player played a word
+    PlayWord {
+        word: String,
+        path: Vec<Position>,
+        points: i32,
+        gems: i32,
+    },
+    /// Player voluntarily passed
+    Pass,
+    /// The turn's deadline passed
+    TimedOut,
+    /// Skipped because the player was disconnected
+    AutoSkipped,
+    /// Player bought extra time for their turn
+    ExtendTurn { seconds: u32, gems: i32 },
+    /// Player spent gems on a power-up
+    UsePowerUp { power_up: PowerUp, gems: i32 },
+    /// An admin skipped the player's turn
+    ForceSkipped { by: i64 },
+    /// An admin changed the player's score
+    ScoreAdjusted { amount: i32, reason: String, by: i64 },
+    /// An admin removed the player from the game
+    Removed { by: i64 },
+    /// The other players voted the player out
+    Kicked { votes: u32 },
+    /// The player (an admin) ended the game early
+    ForceEnded,
+    /// The player resigned
+    Forfeited,
+    /// The player challenged `player_id`'s word
+    Challenged {
+        player_id: i64,
+        word: String,
+        valid: bool,
+    },
+}
+
+impl TurnAction {
+    /// Check if this action ended the player's turn without a word
+    /// (pass, timeout or skip).
+    pub fn is_missed_turn(&self) -> bool {
+        matches!(
+            self,
+            Self::Pass | Self::TimedOut | Self::AutoSkipped | Self::ForceSkipped { .. }
+        )
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::PlayWord {
+                word,
+                path,
+                points,
+                gems,
+            } => serde_json::json!({
+                "type": "play_word",
+                "word": word,
+                "path": path.iter().map(|p| p.to_json()).collect::<Vec<_>>(),
+                "points": points,
+                "gems": gems
+            }),
+            Self::Pass => serde_json::json!({ "type": "pass" }),
+            Self::TimedOut => serde_json::json!({ "type": "timed_out" }),
+            Self::AutoSkipped => serde_json::json!({ "type": "auto_skipped" }),
+            Self::ExtendTurn { seconds, gems } => serde_json::json!({
+                "type": "extend_turn",
+                "seconds": seconds,
+                "gems": gems
+            }),
+            Self::UsePowerUp { power_up, gems } => serde_json::json!({
+                "type": "use_power_up",
+                "power_up": power_up.as_str(),
+                "gems": gems
+            }),
+            Self::ForceSkipped { by } => serde_json::json!({ "type": "force_skipped", "by": by }),
+            Self::ScoreAdjusted { amount, reason, by } => serde_json::json!({
+                "type": "score_adjusted",
+                "amount": amount,
+                "reason": reason,
+                "by": by
+            }),
+            Self::Removed { by } => serde_json::json!({ "type": "removed", "by": by }),
+            Self::Kicked { votes } => serde_json::json!({ "type": "kicked", "votes": votes }),
+            Self::ForceEnded => serde_json::json!({ "type": "force_ended" }),
+            Self::Forfeited => serde_json::json!({ "type": "forfeited" }),
+            Self::Challenged {
+                player_id,
+                word,
+                valid,
+            } => serde_json::json!({
+                "type": "challenged",
+                "player_id": player_id,
+                "word": word,
+                "valid": valid
+            }),
+        }
+    }
+}
+
+/// Outcome of `Game::advance_to_next_connected`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnAdvance {
+    /// Whose turn it is now
+    pub player_id: i64,
+    pub round: u8,
+    /// Disconnected players whose turns were skipped, in order
+    pub skipped: Vec<i64>,
+}
+
+/// Outcome of `Game::submit_word`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordPlayResult {
+    pub score: WordScore,
+    /// Whose turn it is now
+    pub next_player_id: i64,
+    /// Round after the turn advanced
+    pub round: u8,
+    /// Cells given new letters (with `config.refresh_played_cells`)
+    pub refreshed: Vec<LetterChange>,
+}
+
+impl WordPlayResult {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "score": self.score.to_json(),
+            "next_player_id": self.next_player_id,
+            "round": self.round,
+            "refreshed": self.refreshed.iter().map(|c| c.to_json()).collect::<Vec<_>>()
+        })
+    }
+}
+
+/// A single entry in the game's move log.
+#[derive(Debug, Clone)]
+pub struct TurnRecord {
+    pub player_id: i64,
+    pub round: u8,
+    pub action: TurnAction,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TurnRecord {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "player_id": self.player_id,
+            "round": self.round,
+            "action": self.action.to_json(),
+            "at": self.at.to_rfc3339()
+        })
+    }
+}
+
+/// Summary of the moves made during one round.
+#[derive(Debug, Clone)]
+pub struct RoundSummary {
+    pub round: u8,
+    /// Moves made this round, in order
+    pub moves: Vec<TurnRecord>,
+}
+
+impl RoundSummary {
+    /// Player IDs that passed this round.
+    pub fn passed_player_ids(&self) -> Vec<i64> {
+        self.moves
+            .iter()
+            .filter(|m| m.action == TurnAction::Pass)
+            .map(|m| m.player_id)
+            .collect()
+    }
+
+    /// Number of passes this round.
+    pub fn pass_count(&self) -> usize {
+        self.moves
+            .iter()
+            .filter(|m| m.action == TurnAction::Pass)
+            .count()
+    }
+}
+
 /// Game session state.
 #[derive(Debug, Clone)]
 pub struct Game {
@@ -312,44 +1596,265 @@ pub struct Game {
     /// Maximum rounds
     pub max_rounds: u8,
 
+    /// Rule configuration
+    pub config: GameConfig,
+
+    /// Move log, in order
+    moves: Vec<TurnRecord>,
+
     /// Words already used
     pub used_words: HashSet<String>,
 
+    /// Valid words (None accepts any word)
+    dictionary: Option<Arc<dyn Dictionary>>,
+
     /// Spectators
     spectators: HashMap<i64, Spectator>,
 
+    /// Player chat
+    chat: ChatBuffer,
+
+    /// Spectator-only chat, hidden from players until the game ends
+    spectator_chat: ChatBuffer,
+
+    /// Randomness for gem spawns and other in-game rolls
+    rng: SeededRng,
+
     /// Timer vote state
     pub timer_vote: TimerVoteState,
 
+    /// Kick vote state
+    pub kick_vote: KickVoteState,
+
+    /// Word challenge state
+    pub challenge: ChallengeState,
+
+    /// Move index of the last play challenged (each play once)
+    challenged_move: Option<usize>,
+
     /// When game was created
     pub created_at: chrono::DateTime<chrono::Utc>,
 
+    /// When the countdown ends (only while `Starting`)
+    pub starts_at: Option<chrono::DateTime<chrono::Utc>>,
+
     /// When game started (status -> InProgress)
     pub started_at: Option<chrono::DateTime<chrono::Utc>>,
 
+    /// When a timed solo game runs out, pauses excluded
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// When the current turn started
+    pub turn_started_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// When the current turn runs out (None without `config.turn_time_limit`)
+    pub turn_deadline: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Seconds bought for the current turn
+    turn_extension_seconds: u32,
+
     /// When game ended
     pub ended_at: Option<chrono::DateTime<chrono::Utc>>,
-}
 
-impl Game {
-    /// Create a new game.
-    pub fn new(id: String, lobby_id: String, grid: Grid) -> Self {
-        Self {
-            id,
-            lobby_id,
-            status: GameStatus::Idle,
-            grid,
-            players: HashMap::new(),
+    /// Why the game was cancelled (if it was)
+    pub cancel_reason: Option<CancelReason>,
+
+    /// When the game was paused (while `Paused`)
+    pub paused_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Why the game was paused (while `Paused`)
+    pub pause_reason: Option<String>,
+
+    /// Letter weighting the board was generated with (None if supplied by the caller)
+    pub letter_weighting: Option<LetterWeighting>,
+
+    /// Language tag inherited from the lobby's settings
+    pub locale: String,
+
+    /// Recent changes, for clients catching up
+    events: EventBuffer<GameEvent>,
+
+    /// Power-ups used, in order
+    power_ups_used: Vec<PowerUpUse>,
+
+    /// Seconds to take off each player's next turn (from `FreezeOpponentTimer`)
+    timer_penalties: HashMap<i64, u32>,
+
+    /// Words each player has been hinted, in order
+    hints: HashMap<i64, Vec<String>>,
+
+    /// End-of-game statistics (set when the game finishes)
+    stats: Option<GameStats>,
+
+    /// Players knocked out in elimination mode, in order
+    eliminated: Vec<i64>,
+
+    /// Overtime rounds started (see `TieBreak::Overtime`)
+    overtime_rounds: u8,
+
+    /// Players who have claimed each multiplier cell (see `config.multiplier_use`)
+    claimed_multipliers: HashMap<Position, HashSet<i64>>,
+
+    /// Words found so far in blitz mode, scored when the game ends
+    blitz: BlitzState,
+
+    /// Achievements unlocked by each player
+    achievements: AchievementTracker,
+
+    /// Recorded steps for [`Game::export_replay`] (None unless recording)
+    replay: Option<ReplayLog>,
+}
+
+impl Game {
+    /// Create a new game.
+    ///
+    /// The game's RNG is seeded from entropy; games created through
+    /// `AppState` are reseeded from its `rng` with [`Game::with_seed`].
+    pub fn new(id: String, lobby_id: String, grid: Grid) -> Self {
+        Self {
+            id,
+            lobby_id,
+            status: GameStatus::Idle,
+            grid,
+            players: HashMap::new(),
             turn_order: Vec::new(),
             current_turn_index: 0,
             round: 1,
             max_rounds: DEFAULT_MAX_ROUNDS,
+            config: GameConfig::default(),
+            moves: Vec::new(),
             used_words: HashSet::new(),
+            dictionary: None,
             spectators: HashMap::new(),
+            chat: ChatBuffer::default(),
+            spectator_chat: ChatBuffer::default(),
+            rng: SeededRng::from_entropy(),
             timer_vote: TimerVoteState::Idle,
+            kick_vote: KickVoteState::Idle,
+            challenge: ChallengeState::Idle,
+            challenged_move: None,
             created_at: chrono::Utc::now(),
+            starts_at: None,
             started_at: None,
+            ends_at: None,
+            turn_started_at: None,
+            turn_deadline: None,
+            turn_extension_seconds: 0,
             ended_at: None,
+            cancel_reason: None,
+            paused_at: None,
+            pause_reason: None,
+            letter_weighting: None,
+            locale: DEFAULT_LOCALE.to_string(),
+            events: EventBuffer::default(),
+            power_ups_used: Vec::new(),
+            timer_penalties: HashMap::new(),
+            hints: HashMap::new(),
+            stats: None,
+            eliminated: Vec::new(),
+            overtime_rounds: 0,
+            claimed_multipliers: HashMap::new(),
+            blitz: BlitzState::default(),
+            achievements: AchievementTracker::default(),
+            replay: None,
+        }
+    }
+
+    /// Set the rule configuration.
+    ///
+    /// With a letter set in `config.grid`, the board's cells are revalued
+    /// from its tiles; otherwise, with custom `letter_values`, the
+    /// single-letter cells are revalued to match.
+    pub fn with_config(mut self, config: GameConfig) -> Self {
+        if let Some(set) = &config.grid.letters {
+            for cell in self.grid.iter_mut().flatten() {
+                cell.value = set.value(cell.letter, cell.digraph);
+            }
+        } else if config.letter_values != LetterValues::default() {
+            for cell in self.grid.iter_mut().flatten().filter(|c| c.digraph.is_none()) {
+                cell.value = config.letter_values.get(cell.letter);
+            }
+        }
+        self.config = config;
+        self
+    }
+
+    /// Set the locale.
+    pub fn with_locale(mut self, locale: String) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Seed the game's random number generator, for reproducible games.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = SeededRng::new(seed);
+        self
+    }
+
+    /// Check submitted words against a dictionary.
+    pub fn with_dictionary(mut self, dictionary: Arc<dyn Dictionary>) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    /// The dictionary words are checked against, if any.
+    pub fn dictionary(&self) -> Option<&dyn Dictionary> {
+        self.dictionary.as_deref()
+    }
+
+    /// The letter set boards are drawn from (None = English letters).
+    pub fn letter_set(&self) -> Option<&LetterSet> {
+        self.config.grid.letters.as_ref()
+    }
+
+    /// The seed of the game's random number generator.
+    pub fn rng_seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Record every state-changing call from here on, for
+    /// [`Game::export_replay`].
+    ///
+    /// The grid and configuration are captured now and again when the game
+    /// starts, so set them up before the first recorded step.
+    pub fn with_replay_recording(mut self) -> Self {
+        self.replay = Some(ReplayLog::new(&self.grid, &self.config, self.max_rounds));
+        self
+    }
+
+    /// Check if replay steps are being recorded.
+    pub fn is_recording_replay(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// Export the recorded steps (None unless recording).
+    pub fn export_replay(&self) -> Option<Replay> {
+        let log = self.replay.as_ref()?;
+        Some(Replay {
+            game_id: self.id.clone(),
+            lobby_id: self.lobby_id.clone(),
+            seed: self.rng.seed(),
+            grid: log.grid.clone(),
+            config: log.config.clone(),
+            max_rounds: log.max_rounds,
+            locale: self.locale.clone(),
+            dictionary: self.dictionary.clone(),
+            steps: log.steps.clone(),
+        })
+    }
+
+    fn record(&mut self, step: ReplayStep) {
+        if let Some(log) = &mut self.replay {
+            log.steps.push(step);
+        }
+    }
+
+    /// Most players the game takes: one in solo mode, otherwise six.
+    pub fn max_players(&self) -> usize {
+        if self.config.mode.is_solo() {
+            1
+        } else {
+            6
         }
     }
 
@@ -363,33 +1868,212 @@ impl Game {
             return Err(GameError::AlreadyPlayer);
         }
 
-        if self.players.len() >= 6 {
+        if self.players.len() >= self.max_players() {
             return Err(GameError::TooManyPlayers);
         }
 
         let player_id = player.player_id;
+        self.record(ReplayStep::Join(player.clone()));
         self.players.insert(player_id, player);
         self.turn_order.push(player_id);
+        self.events.push(GameEvent::PlayerJoined { player_id });
 
         Ok(())
     }
 
-    /// Start the game.
-    pub fn start(&mut self) -> Result<(), GameError> {
+    /// Set the turn order before the game starts. `order` must list every
+    /// player exactly once.
+    pub fn set_turn_order(&mut self, order: &[i64]) -> Result<(), GameError> {
+        if self.status != GameStatus::Idle {
+            return Err(GameError::GameStarted);
+        }
+        let mut ids = self.turn_order.clone();
+        let mut expected = order.to_vec();
+        ids.sort_unstable();
+        expected.sort_unstable();
+        if ids != expected {
+            return Err(GameError::InvalidTurnOrder);
+        }
+
+        self.record(ReplayStep::SetTurnOrder(order.to_vec()));
+        self.reorder(order.to_vec());
+        Ok(())
+    }
+
+    /// Shuffle the turn order before the game starts. The same seed and
+    /// players always give the same order.
+    pub fn shuffle_turn_order(&mut self, seed: u64) -> Result<(), GameError> {
+        if self.status != GameStatus::Idle {
+            return Err(GameError::GameStarted);
+        }
+        let mut order = self.turn_order.clone();
+        SeededRng::new(seed).shuffle(&mut order);
+
+        self.record(ReplayStep::SetTurnOrder(order.clone()));
+        self.reorder(order);
+        Ok(())
+    }
+
+    fn reorder(&mut self, order: Vec<i64>) {
+        for (index, player_id) in order.iter().enumerate() {
+            if let Some(player) = self.players.get_mut(player_id) {
+                player.turn_order = index as u8;
+            }
+        }
+        self.turn_order = order;
+        self.current_turn_index = 0;
+        self.events.push(GameEvent::TurnOrderChanged {
+            order: self.turn_order.clone(),
+        });
+    }
+
+    /// Count down to the start of the game.
+    ///
+    /// The game is `Starting` until [`Game::tick`] passes `starts_at`, or
+    /// [`Game::cancel_countdown`] puts it back to `Idle`.
+    pub fn begin_countdown(&mut self, duration: chrono::Duration) -> Result<(), GameError> {
         if self.status != GameStatus::Idle {
             return Err(GameError::InvalidStatus);
         }
+        if self.players.is_empty() {
+            return Err(GameError::NotEnoughPlayers);
+        }
+
+        let starts_at = chrono::Utc::now() + duration;
+        self.status = GameStatus::Starting;
+        self.starts_at = Some(starts_at);
+        self.events.push(GameEvent::CountdownStarted { starts_at });
+        Ok(())
+    }
+
+    /// Stop the countdown and go back to `Idle`.
+    pub fn cancel_countdown(&mut self) -> Result<(), GameError> {
+        if self.status != GameStatus::Starting {
+            return Err(GameError::InvalidStatus);
+        }
+        self.status = GameStatus::Idle;
+        self.starts_at = None;
+        self.events.push(GameEvent::CountdownCancelled);
+        Ok(())
+    }
+
+    /// Seconds left in the countdown.
+    pub fn countdown_remaining(&self, now: chrono::DateTime<chrono::Utc>) -> Option<i64> {
+        self.starts_at.map(|at| (at - now).num_seconds().max(0))
+    }
+
+    /// Advance time-based state: finish the countdown, end timed solo games
+    /// and games past `config.max_duration`, and close expired votes.
+    /// Returns true if the game started or ended.
+    ///
+    /// A countdown that can't start the game (everyone left) goes back to
+    /// `Idle`. Overdue turns are left to [`Game::expire_turn`].
+    pub fn tick(&mut self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        if self.is_overdue(now) {
+            self.expire_duration(now);
+            return self.status.is_terminal();
+        }
+        self.update_timer_vote(now);
+        self.update_kick_vote(now);
+        if self.status.is_active() && self.ends_at.is_some_and(|at| at <= now) {
+            self.time_up();
+            return true;
+        }
+        if self.status != GameStatus::Starting || self.starts_at.is_some_and(|at| at > now) {
+            return false;
+        }
+        if self.start().is_ok() {
+            return true;
+        }
+        let _ = self.cancel_countdown();
+        false
+    }
+
+    /// Check if the game has outlived `config.max_duration` without ending.
+    pub fn is_overdue(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        !self.status.is_terminal()
+            && self.config.max_duration.is_some_and(|limit| now - self.created_at >= limit)
+    }
+
+    /// End a game that has outlived `config.max_duration`: a started game
+    /// finishes and its results are returned, one that never started is
+    /// cancelled as `TimedOut`.
+    pub fn expire_duration(&mut self, now: chrono::DateTime<chrono::Utc>) -> Option<GameResult> {
+        if !self.is_overdue(now) {
+            return None;
+        }
+        if self.status.is_started() {
+            return Some(self.time_up());
+        }
+        self.cancel(CancelReason::TimedOut);
+        None
+    }
+
+    /// Finish a started game whose time ran out: `config.max_duration`, a
+    /// solo or co-op time limit or a blitz window. Unlike [`Game::end`], a
+    /// tied lead doesn't go to overtime.
+    pub(crate) fn time_up(&mut self) -> GameResult {
+        let result = self.finish();
+        self.record(ReplayStep::TimeUp);
+        result
+    }
+
+    /// Start the game, cutting short any countdown.
+    ///
+    /// With a dictionary and `config.min_quality`, a board below the minimum
+    /// fails with `GridQualityTooLow` and the game doesn't start.
+    pub fn start(&mut self) -> Result<(), GameError> {
+        if !matches!(self.status, GameStatus::Idle | GameStatus::Starting) {
+            return Err(GameError::InvalidStatus);
+        }
 
         if self.players.is_empty() {
             return Err(GameError::NotEnoughPlayers);
         }
+        if let (Some(dict), Some(_)) = (self.dictionary.as_deref(), &self.config.min_quality) {
+            self.check_grid_quality(dict)?;
+        }
 
+        if let Some(log) = &mut self.replay {
+            log.snapshot(&self.grid, &self.config, self.max_rounds);
+            log.steps.push(ReplayStep::Start);
+        }
         self.status = GameStatus::InProgress;
-        self.started_at = Some(chrono::Utc::now());
+        let now = chrono::Utc::now();
+        self.starts_at = None;
+        self.started_at = Some(now);
+        if let Some(limit) = self.config.mode.time_limit() {
+            self.ends_at = Some(now + limit);
+        }
+        self.begin_turn(now);
+        self.events.push(GameEvent::Started);
+
+        let gems = self.config.gems.clone();
+        let positions = gems.place_gems(&mut self.grid, gems.initial_gems, &mut self.rng);
+        self.record_gems(positions);
 
         Ok(())
     }
 
+    /// Check the board against `config.min_quality`.
+    ///
+    /// Returns the measured quality, or `GridQualityTooLow` so the caller can
+    /// regenerate the board. [`Game::start`] runs this when the game has a
+    /// dictionary.
+    pub fn check_grid_quality(&self, dict: &dyn Dictionary) -> Result<GridQuality, GameError> {
+        let words = solver::solve(&self.grid, dict, self.min_word_length());
+        let quality = GridQuality::from_words(&words);
+        match &self.config.min_quality {
+            Some(min) if !quality.meets(min) => Err(GameError::GridQualityTooLow),
+            _ => Ok(quality),
+        }
+    }
+
+    /// Minimum letters in a playable word for this game.
+    pub fn min_word_length(&self) -> usize {
+        self.config.min_word_length.unwrap_or(MIN_WORD_LENGTH)
+    }
+
     /// Get current player ID.
     pub fn current_player_id(&self) -> Option<i64> {
         self.turn_order.get(self.current_turn_index).copied()
@@ -407,399 +2091,4302 @@ impl Game {
     }
 
     /// Advance to next turn.
+    ///
+    /// Disconnected players are skipped as configured by
+    /// `config.skip_disconnected_after`; see [`Game::advance_to_next_connected`].
     pub fn advance_turn(&mut self) -> (i64, u8) {
+        let advance = self.advance_to_next_connected();
+        (advance.player_id, advance.round)
+    }
+
+    fn next_turn(&mut self) -> (i64, u8) {
+        let advance = self.advance();
+        (advance.player_id, advance.round)
+    }
+
+    /// Advance to the next turn, skipping disconnected players who have
+    /// missed `config.skip_disconnected_after` turns in a row. Each skip is
+    /// recorded in the move log and counts as another missed turn.
+    pub fn advance_to_next_connected(&mut self) -> TurnAdvance {
+        self.record(ReplayStep::AdvanceTurn);
+        self.advance()
+    }
+
+    fn advance(&mut self) -> TurnAdvance {
+        self.end_timer_vote(chrono::Utc::now());
+        if let (Some(started), Some(player_id)) = (self.turn_started_at, self.current_player_id())
+        {
+            if let Some(player) = self.players.get_mut(&player_id) {
+                player.turns_taken += 1;
+                player.turn_time += chrono::Utc::now() - started;
+            }
+        }
+
+        let mut skipped = Vec::new();
+        self.step_turn();
+        while skipped.len() < self.turn_order.len() && !self.should_end() {
+            let Some(player_id) = self.current_player_id() else {
+                break;
+            };
+            let Some(player) = self.players.get_mut(&player_id) else {
+                break;
+            };
+            let skip = self
+                .config
+                .skip_disconnected_after
+                .is_some_and(|after| !player.is_connected && player.missed_turns >= after);
+            if !skip {
+                break;
+            }
+            player.missed_turns += 1;
+            self.moves.push(TurnRecord {
+                player_id,
+                round: self.round,
+                action: TurnAction::AutoSkipped,
+                at: chrono::Utc::now(),
+            });
+            self.events.push(GameEvent::TurnSkipped {
+                player_id,
+                round: self.round,
+            });
+            skipped.push(player_id);
+            self.step_turn();
+        }
+
+        let player_id = self.current_player_id().unwrap_or(0);
+        if self.is_elimination() && self.turn_order.len() <= 1 && self.status.is_started() {
+            self.finish();
+            return TurnAdvance {
+                player_id,
+                round: self.round,
+                skipped,
+            };
+        }
+        self.begin_turn(chrono::Utc::now());
+        self.events.push(GameEvent::TurnAdvanced {
+            player_id,
+            round: self.round,
+        });
+        TurnAdvance {
+            player_id,
+            round: self.round,
+            skipped,
+        }
+    }
+
+    /// Move the turn pointer on, running round-boundary updates.
+    fn step_turn(&mut self) {
         self.current_turn_index = (self.current_turn_index + 1) % self.turn_order.len();
 
         if self.current_turn_index == 0 {
+            for player_id in &self.turn_order {
+                if let (Some(Handicap::RoundBonus(points)), Some(player)) = (
+                    self.config.handicaps.get(player_id),
+                    self.players.get_mut(player_id),
+                ) {
+                    player.add_score(ScoreSource::Bonus("handicap".to_string()), *points);
+                }
+            }
+            let scores: Vec<(i64, i32)> = self
+                .turn_order
+                .iter()
+                .filter_map(|id| self.players.get(id).map(|p| (*id, p.score)))
+                .collect();
+            self.achievements.on_round_end(&scores);
+            if self.is_elimination() {
+                self.eliminate_lowest();
+            }
             self.round += 1;
+            for player in self.players.values_mut() {
+                player.submissions_this_round = 0;
+            }
+            if !self.should_end() {
+                let changes = self
+                    .config
+                    .reroll
+                    .apply(&mut self.grid, &self.config.gems, &mut self.rng);
+                self.record_grid_delta(changes);
+                let gems = self.config.gems.clone();
+                let positions =
+                    gems.place_gems(&mut self.grid, gems.respawn_per_round, &mut self.rng);
+                self.record_gems(positions);
+            }
         }
-
-        (self.current_player_id().unwrap_or(0), self.round)
     }
 
-    /// Check if game should end.
-    pub fn should_end(&self) -> bool {
-        self.round > self.max_rounds
+    /// Check if elimination applies (it never does in solo or co-op games).
+    fn is_elimination(&self) -> bool {
+        self.config.elimination && self.config.mode == GameMode::Multiplayer
     }
 
-    /// End the game.
-    pub fn end(&mut self) -> Result<Vec<(i64, String, i32)>, GameError> {
-        if !self.status.is_active() {
-            return Err(GameError::InvalidStatus);
+    /// Take the lowest scorer out of the rotation and make them a spectator.
+    /// Nobody is eliminated if the lowest score is tied. Eliminated players
+    /// always get a spectator seat, even past `config.max_spectators`.
+    fn eliminate_lowest(&mut self) {
+        if self.turn_order.len() <= 1 {
+            return;
         }
+        let score = |id: &i64| self.players.get(id).map_or(0, |p| p.score);
+        let Some(lowest) = self.turn_order.iter().map(score).min() else {
+            return;
+        };
+        let mut lowest_players = self.turn_order.iter().filter(|id| score(id) == lowest);
+        let (Some(&player_id), None) = (lowest_players.next(), lowest_players.next()) else {
+            return;
+        };
 
-        self.status = GameStatus::Finished;
-        self.ended_at = Some(chrono::Utc::now());
-
-        // Return final scores sorted by score descending
-        let mut scores: Vec<(i64, String, i32)> = self
-            .players
-            .values()
-            .map(|p| (p.player_id, p.user_id.clone(), p.score))
-            .collect();
-        scores.sort_by(|a, b| b.2.cmp(&a.2));
-
-        Ok(scores)
+        // The index is 0 here, so it still points at the next player to go
+        self.turn_order.retain(|id| *id != player_id);
+        self.eliminated.push(player_id);
+        if let Some(p) = self.players.get(&player_id) {
+            if let Entry::Vacant(seat) = self.spectators.entry(player_id) {
+                seat.insert(Spectator {
+                    player_id,
+                    user_id: p.user_id.clone(),
+                    username: p.username.clone(),
+                    avatar_url: p.avatar_url.clone(),
+                });
+                self.events.push(GameEvent::SpectatorJoined { count: self.spectators.len() });
+            }
+        }
+        self.events.push(GameEvent::PlayerEliminated {
+            player_id,
+            round: self.round,
+        });
     }
 
-    /// Cancel the game.
-    pub fn cancel(&mut self, reason: &str) {
-        self.status = GameStatus::Cancelled;
-        self.ended_at = Some(chrono::Utc::now());
-        // Could store reason if needed
-        let _ = reason;
+    /// Players knocked out in elimination mode, in order.
+    pub fn eliminated(&self) -> &[i64] {
+        &self.eliminated
     }
 
-    /// Get a player.
-    pub fn get_player(&self, player_id: i64) -> Option<&GamePlayer> {
-        self.players.get(&player_id)
+    /// Check if the game is being played out in overtime.
+    pub fn in_overtime(&self) -> bool {
+        self.overtime_rounds > 0 && self.status.is_started()
     }
 
-    /// Get a mutable player.
-    pub fn get_player_mut(&mut self, player_id: i64) -> Option<&mut GamePlayer> {
-        self.players.get_mut(&player_id)
+    /// Overtime rounds started so far.
+    pub fn overtime_rounds(&self) -> u8 {
+        self.overtime_rounds
     }
 
-    /// Check if player is in game.
-    pub fn has_player(&self, player_id: i64) -> bool {
-        self.players.contains_key(&player_id)
+    /// Combined score of every player, the team score in co-op games.
+    pub fn team_score(&self) -> i32 {
+        self.players.values().map(|p| p.score).sum()
     }
 
-    /// Get all players.
-    pub fn players(&self) -> impl Iterator<Item = &GamePlayer> {
-        self.players.values()
+    /// Check if a co-op game has reached its target score.
+    pub fn coop_target_reached(&self) -> bool {
+        match self.config.mode {
+            GameMode::Coop {
+                target: Some(target),
+                ..
+            } => self.team_score() >= target,
+            _ => false,
+        }
     }
 
-    /// Get player IDs in turn order.
-    pub fn player_ids_in_order(&self) -> &[i64] {
-        &self.turn_order
+    /// Words found per player and the duplicate rule (None unless blitz).
+    fn blitz_to_json(&self) -> Option<serde_json::Value> {
+        let GameMode::Blitz { duplicates, .. } = self.config.mode else {
+            return None;
+        };
+        let mut json = self.blitz.to_json();
+        json["duplicates"] = duplicates.as_str().into();
+        Some(json)
     }
 
-    /// Player count.
-    pub fn player_count(&self) -> usize {
-        self.players.len()
+    /// Team score and progress towards the target (None unless co-op).
+    fn team_to_json(&self) -> Option<serde_json::Value> {
+        let GameMode::Coop { target, .. } = self.config.mode else {
+            return None;
+        };
+        let score = self.team_score();
+        let progress = target.map(|t| (score as f64 / t.max(1) as f64).clamp(0.0, 1.0));
+        Some(serde_json::json!({
+            "score": score,
+            "target": target,
+            "progress": progress,
+            "target_reached": self.coop_target_reached()
+        }))
     }
 
-    /// Add a spectator.
-    pub fn add_spectator(&mut self, spectator: Spectator) -> Result<(), GameError> {
-        if self.spectators.contains_key(&spectator.player_id) {
-            return Err(GameError::AlreadySpectator);
-        }
+    /// Check if a player was knocked out in elimination mode.
+    pub fn is_eliminated(&self, player_id: i64) -> bool {
+        self.eliminated.contains(&player_id)
+    }
 
-        self.spectators.insert(spectator.player_id, spectator);
-        Ok(())
+    fn begin_turn(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        let penalty = self
+            .current_player_id()
+            .and_then(|id| self.timer_penalties.remove(&id))
+            .map_or(chrono::Duration::zero(), |s| chrono::Duration::seconds(s as i64));
+        self.turn_started_at = Some(now);
+        self.turn_deadline = self
+            .config
+            .turn_time_limit
+            .filter(|_| !self.config.mode.is_blitz())
+            .map(|limit| now + (limit - penalty).max(chrono::Duration::zero()));
+        self.turn_extension_seconds = 0;
     }
 
-    /// Remove a spectator.
-    pub fn remove_spectator(&mut self, player_id: i64) -> Option<Spectator> {
-        self.spectators.remove(&player_id)
+    /// Check if the current turn's deadline has passed.
+    pub fn is_turn_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.turn_deadline.is_some_and(|deadline| now >= deadline)
     }
 
-    /// Get spectators.
-    pub fn spectators(&self) -> impl Iterator<Item = &Spectator> {
-        self.spectators.values()
+    /// Change the turn time limit. A running turn keeps its start time (and
+    /// any time bought for it) and gets a deadline under the new limit.
+    pub fn set_turn_duration(&mut self, limit: Option<chrono::Duration>) {
+        self.record(ReplayStep::SetTurnDuration(limit));
+        self.config.turn_time_limit = limit;
+        if let (true, Some(started)) = (self.status.is_active(), self.turn_started_at) {
+            let extension = chrono::Duration::seconds(self.turn_extension_seconds as i64);
+            self.turn_deadline = limit.map(|limit| started + limit + extension);
+        }
     }
 
-    /// Spectator count.
-    pub fn spectator_count(&self) -> usize {
-        self.spectators.len()
+    /// End the current turn if its deadline has passed at `now`.
+    ///
+    /// The timeout is recorded in the move log (it doesn't count as a pass)
+    /// and the turn advances. Returns the next player and round, or None if
+    /// the turn hasn't expired.
+    pub fn expire_turn(&mut self, now: chrono::DateTime<chrono::Utc>) -> Option<(i64, u8)> {
+        if !self.status.is_active() || !self.is_turn_expired(now) {
+            return None;
+        }
+        let player_id = self.current_player_id()?;
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.missed_turns += 1;
+            player.timeouts += 1;
+        }
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action: TurnAction::TimedOut,
+            at: now,
+        });
+        self.events.push(GameEvent::TurnExpired {
+            player_id,
+            round: self.round,
+        });
+        self.record(ReplayStep::ExpireTurn);
+        Some(self.next_turn())
     }
 
-    /// Check if word has been used.
-    pub fn is_word_used(&self, word: &str) -> bool {
-        self.used_words.contains(&word.to_uppercase())
+    /// Start a vote to put the current player on a timer.
+    ///
+    /// The initiator's vote counts. The vote passes once a majority of the
+    /// other connected players have voted, at which point the current
+    /// player's turn deadline moves to `config.timer_vote.timer_seconds`
+    /// from now (if that is sooner) and the turn expires through
+    /// [`Game::expire_turn`]. The vote is dropped if the turn ends first.
+    pub fn start_timer_vote(&mut self, initiator_id: i64) -> Result<(), GameError> {
+        let now = chrono::Utc::now();
+        self.update_timer_vote(now);
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        if !self.players.contains_key(&initiator_id) {
+            return Err(GameError::NotPlayer);
+        }
+        if self.is_player_turn(initiator_id) {
+            return Err(GameError::CannotVoteOnSelf);
+        }
+        match &self.timer_vote {
+            TimerVoteState::Idle => {}
+            TimerVoteState::VoteInProgress { .. } => return Err(GameError::VoteInProgress),
+            TimerVoteState::TimerActive { .. } => return Err(GameError::TimerAlreadyActive),
+            TimerVoteState::Cooldown { expires_at } => {
+                return Err(GameError::VoteCooldown {
+                    retry_at: *expires_at,
+                })
+            }
+        }
+
+        let target_id = self.current_player_id().ok_or(GameError::NotEnoughPlayers)?;
+        let eligible = self
+            .turn_order
+            .iter()
+            .filter(|id| **id != target_id)
+            .filter(|id| self.players.get(id).is_some_and(|p| p.is_connected))
+            .count();
+        let votes_needed = (eligible / 2 + 1) as u32;
+        let seconds = self.config.timer_vote.vote_seconds as i64;
+        self.timer_vote = TimerVoteState::VoteInProgress {
+            initiator_id,
+            voters: HashSet::from([initiator_id]),
+            votes_needed,
+            expires_at: now + chrono::Duration::seconds(seconds),
+        };
+        self.events.push(GameEvent::TimerVoteStarted {
+            initiator_id,
+            target_player_id: target_id,
+            votes_needed,
+        });
+        self.record(ReplayStep::StartTimerVote { initiator_id });
+        self.check_timer_vote(now);
+        Ok(())
     }
 
-    /// Mark word as used.
-    pub fn use_word(&mut self, word: &str) {
-        self.used_words.insert(word.to_uppercase());
+    /// Vote in the open timer vote. Returns true if the vote passed.
+    pub fn add_timer_vote(&mut self, voter_id: i64) -> Result<bool, GameError> {
+        let now = chrono::Utc::now();
+        self.update_timer_vote(now);
+        if !self.players.contains_key(&voter_id) {
+            return Err(GameError::NotPlayer);
+        }
+        if self.is_player_turn(voter_id) {
+            return Err(GameError::CannotVoteOnSelf);
+        }
+        let voters = match &mut self.timer_vote {
+            TimerVoteState::VoteInProgress { voters, .. } => voters,
+            TimerVoteState::Cooldown { expires_at } => {
+                return Err(GameError::VoteCooldown {
+                    retry_at: *expires_at,
+                })
+            }
+            TimerVoteState::Idle | TimerVoteState::TimerActive { .. } => {
+                return Err(GameError::NoVoteInProgress)
+            }
+        };
+        if !voters.insert(voter_id) {
+            return Err(GameError::AlreadyVoted);
+        }
+        self.record(ReplayStep::AddTimerVote { voter_id });
+        Ok(self.check_timer_vote(now))
     }
 
-    /// Get cell at position.
-    pub fn get_cell(&self, pos: Position) -> Option<&GridCell> {
-        if pos.is_valid() {
-            Some(&self.grid[pos.row][pos.col])
-        } else {
-            None
+    /// Start the timer if the open vote has enough votes.
+    fn check_timer_vote(&mut self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        let TimerVoteState::VoteInProgress {
+            voters,
+            votes_needed,
+            ..
+        } = &self.timer_vote
+        else {
+            return false;
+        };
+        if (voters.len() as u32) < *votes_needed {
+            return false;
         }
+        let Some(target_player_id) = self.current_player_id() else {
+            return false;
+        };
+
+        let seconds = self.config.timer_vote.timer_seconds as i64;
+        let deadline = now + chrono::Duration::seconds(seconds);
+        let deadline = self.turn_deadline.map_or(deadline, |d| d.min(deadline));
+        self.turn_deadline = Some(deadline);
+        self.timer_vote = TimerVoteState::TimerActive {
+            target_player_id,
+            expires_at: deadline,
+        };
+        self.events.push(GameEvent::TimerStarted {
+            target_player_id,
+            deadline,
+        });
+        true
     }
 
-    /// Get mutable cell at position.
-    pub fn get_cell_mut(&mut self, pos: Position) -> Option<&mut GridCell> {
-        if pos.is_valid() {
-            Some(&mut self.grid[pos.row][pos.col])
-        } else {
-            None
+    /// Close votes and cooldowns that have run out.
+    pub fn update_timer_vote(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        match &self.timer_vote {
+            TimerVoteState::VoteInProgress { expires_at, .. }
+            | TimerVoteState::Cooldown { expires_at }
+                if *expires_at <= now =>
+            {
+                self.timer_vote = TimerVoteState::Idle;
+            }
+            _ => {}
         }
     }
 
-    /// Extract word from path.
-    pub fn extract_word(&self, path: &[Position]) -> String {
-        path.iter()
-            .filter_map(|p| self.get_cell(*p))
-            .map(|c| c.letter)
-            .collect()
+    /// The turn ended: drop an open vote, and start the cooldown after a
+    /// timer.
+    fn end_timer_vote(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        self.timer_vote = match self.timer_vote {
+            TimerVoteState::TimerActive { .. } => {
+                let seconds = self.config.timer_vote.cooldown_seconds as i64;
+                TimerVoteState::Cooldown {
+                    expires_at: now + chrono::Duration::seconds(seconds),
+                }
+            }
+            TimerVoteState::VoteInProgress { .. } => TimerVoteState::Idle,
+            ref other => other.clone(),
+        };
     }
 
-    /// Convert grid to JSON.
-    pub fn grid_to_json(&self) -> serde_json::Value {
-        let rows: Vec<serde_json::Value> = self
-            .grid
+    /// Challenge the previous play.
+    ///
+    /// Only the latest word can be challenged, once, and not by the player
+    /// who played it. The turn is frozen (its deadline included) until the
+    /// challenge is resolved. With a dictionary it's resolved straight away
+    /// and the outcome returned; otherwise call [`Game::resolve_challenge`]
+    /// with an outside ruling.
+    pub fn challenge_word(
+        &mut self,
+        challenger_id: i64,
+    ) -> Result<Option<ChallengeOutcome>, GameError> {
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        if self.config.challenges.is_none() || self.config.mode.is_blitz() {
+            return Err(GameError::ChallengeUnavailable);
+        }
+        if !self.turn_order.contains(&challenger_id) {
+            return Err(GameError::NotPlayer);
+        }
+        if self.challenge.is_pending() {
+            return Err(GameError::ChallengeInProgress);
+        }
+        let (index, player_id, word) = self.previous_play().ok_or(GameError::ChallengeUnavailable)?;
+        if player_id == challenger_id || self.challenged_move == Some(index) {
+            return Err(GameError::ChallengeUnavailable);
+        }
+
+        let now = chrono::Utc::now();
+        let turn_remaining = self
+            .turn_deadline
+            .take()
+            .map(|deadline| (deadline - now).max(chrono::Duration::zero()));
+        self.challenged_move = Some(index);
+        self.challenge = ChallengeState::Pending {
+            challenger_id,
+            player_id,
+            word: word.clone(),
+            turn_remaining,
+        };
+        self.events.push(GameEvent::ChallengeStarted {
+            challenger_id,
+            player_id,
+            word: word.clone(),
+        });
+        self.record(ReplayStep::ChallengeWord { challenger_id });
+
+        let ruling = self.dictionary.as_ref().map(|d| d.contains(&word));
+        Ok(ruling.and_then(|valid| self.settle_challenge(valid)))
+    }
+
+    /// Settle the open challenge with an outside ruling on the word.
+    pub fn resolve_challenge(&mut self, valid: bool) -> Result<ChallengeOutcome, GameError> {
+        let outcome = self.settle_challenge(valid).ok_or(GameError::NoChallengeInProgress)?;
+        self.record(ReplayStep::ResolveChallenge(valid));
+        Ok(outcome)
+    }
+
+    /// The latest move that ended a turn, if it was a word: its index in
+    /// the move log, who played it and the word.
+    fn previous_play(&self) -> Option<(usize, i64, String)> {
+        let (index, record) = self.moves.iter().enumerate().rev().find(|(_, m)| {
+            !matches!(
+                m.action,
+                TurnAction::ExtendTurn { .. }
+                    | TurnAction::UsePowerUp { .. }
+                    | TurnAction::ScoreAdjusted { .. }
+                    | TurnAction::Challenged { .. }
+            )
+        })?;
+        match &record.action {
+            TurnAction::PlayWord { word, .. } => Some((index, record.player_id, word.clone())),
+            _ => None,
+        }
+    }
+
+    /// Apply a ruling to the open challenge and unfreeze the turn. An invalid
+    /// word loses its points and gems and can be played again; a valid one
+    /// costs the challenger `ChallengeConfig::penalty`.
+    fn settle_challenge(&mut self, valid: bool) -> Option<ChallengeOutcome> {
+        let ChallengeState::Pending {
+            challenger_id,
+            player_id,
+            word,
+            turn_remaining,
+        } = std::mem::take(&mut self.challenge)
+        else {
+            return None;
+        };
+        let (points, gems) = self
+            .challenged_move
+            .and_then(|i| self.moves.get(i))
+            .map_or((0, 0), |m| match m.action {
+                TurnAction::PlayWord { points, gems, .. } => (points, gems),
+                _ => (0, 0),
+            });
+        let penalty = self.config.challenges.unwrap_or_default().penalty;
+        let source = || ScoreSource::Adjustment("challenge".to_string());
+
+        let points = if valid {
+            if let Some(challenger) = self.players.get_mut(&challenger_id) {
+                challenger.add_score(source(), -penalty);
+            }
+            penalty
+        } else {
+            if let Some(player) = self.players.get_mut(&player_id) {
+                player.add_score(source(), -points);
+                let gems = gems.min(player.gems).max(0);
+                if gems > 0 {
+                    player.add_gems(source(), -gems);
+                }
+                if let Some(i) = player.words_played.iter().rposition(|w| *w == word) {
+                    player.words_played.remove(i);
+                }
+            }
+            self.used_words.remove(&word.to_uppercase());
+            points
+        };
+
+        let now = chrono::Utc::now();
+        self.turn_deadline = turn_remaining.map(|remaining| now + remaining);
+        self.moves.push(TurnRecord {
+            player_id: challenger_id,
+            round: self.round,
+            action: TurnAction::Challenged {
+                player_id,
+                word: word.clone(),
+                valid,
+            },
+            at: now,
+        });
+        let outcome = ChallengeOutcome {
+            challenger_id,
+            player_id,
+            word,
+            valid,
+            points,
+        };
+        self.events.push(GameEvent::ChallengeResolved {
+            outcome: outcome.clone(),
+        });
+        Some(outcome)
+    }
+
+    /// Start a vote to remove `target_id` from the game.
+    ///
+    /// The initiator's vote counts. The vote passes once a majority of the
+    /// connected players other than the target have voted, and the target
+    /// is removed as by [`Game::remove_player`]. Returns the removed player
+    /// if the vote passed straight away. Prefer [`GameManager::start_kick_vote`],
+    /// which also updates its index.
+    pub fn start_kick_vote(
+        &mut self,
+        initiator_id: i64,
+        target_id: i64,
+    ) -> Result<Option<GamePlayer>, GameError> {
+        let now = chrono::Utc::now();
+        self.update_kick_vote(now);
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        if !self.turn_order.contains(&initiator_id) || !self.turn_order.contains(&target_id) {
+            return Err(GameError::NotPlayer);
+        }
+        if initiator_id == target_id {
+            return Err(GameError::CannotVoteOnSelf);
+        }
+        match &self.kick_vote {
+            KickVoteState::Idle => {}
+            KickVoteState::VoteInProgress { .. } => return Err(GameError::VoteInProgress),
+            KickVoteState::Cooldown { expires_at } => {
+                return Err(GameError::VoteCooldown {
+                    retry_at: *expires_at,
+                })
+            }
+        }
+
+        let eligible = self
+            .turn_order
+            .iter()
+            .filter(|id| **id != target_id)
+            .filter(|id| self.players.get(id).is_some_and(|p| p.is_connected))
+            .count();
+        let votes_needed = (eligible / 2 + 1) as u32;
+        let seconds = self.config.kick_vote.vote_seconds as i64;
+        self.kick_vote = KickVoteState::VoteInProgress {
+            initiator_id,
+            target_player_id: target_id,
+            voters: HashSet::from([initiator_id]),
+            votes_needed,
+            expires_at: now + chrono::Duration::seconds(seconds),
+        };
+        self.events.push(GameEvent::KickVoteStarted {
+            initiator_id,
+            target_player_id: target_id,
+            votes_needed,
+        });
+        self.record(ReplayStep::StartKickVote {
+            initiator_id,
+            target_id,
+        });
+        Ok(self.check_kick_vote(now))
+    }
+
+    /// Vote in the open kick vote. Returns the removed player if the vote
+    /// passed.
+    pub fn add_kick_vote(&mut self, voter_id: i64) -> Result<Option<GamePlayer>, GameError> {
+        let now = chrono::Utc::now();
+        self.update_kick_vote(now);
+        if !self.turn_order.contains(&voter_id) {
+            return Err(GameError::NotPlayer);
+        }
+        let (target_player_id, voters) = match &mut self.kick_vote {
+            KickVoteState::VoteInProgress {
+                target_player_id,
+                voters,
+                ..
+            } => (*target_player_id, voters),
+            KickVoteState::Cooldown { expires_at } => {
+                return Err(GameError::VoteCooldown {
+                    retry_at: *expires_at,
+                })
+            }
+            KickVoteState::Idle => return Err(GameError::NoVoteInProgress),
+        };
+        if voter_id == target_player_id {
+            return Err(GameError::CannotVoteOnSelf);
+        }
+        if !voters.insert(voter_id) {
+            return Err(GameError::AlreadyVoted);
+        }
+        self.record(ReplayStep::AddKickVote { voter_id });
+        Ok(self.check_kick_vote(now))
+    }
+
+    /// Remove the target if the open kick vote has enough votes.
+    fn check_kick_vote(&mut self, now: chrono::DateTime<chrono::Utc>) -> Option<GamePlayer> {
+        let KickVoteState::VoteInProgress {
+            target_player_id,
+            voters,
+            votes_needed,
+            ..
+        } = &self.kick_vote
+        else {
+            return None;
+        };
+        let votes = voters.len() as u32;
+        if votes < *votes_needed {
+            return None;
+        }
+
+        let target_player_id = *target_player_id;
+        let seconds = self.config.kick_vote.cooldown_seconds as i64;
+        self.kick_vote = KickVoteState::Cooldown {
+            expires_at: now + chrono::Duration::seconds(seconds),
+        };
+        self.remove(target_player_id, TurnAction::Kicked { votes }).ok()
+    }
+
+    /// Close kick votes and cooldowns that have run out. An expired vote
+    /// starts the cooldown.
+    pub fn update_kick_vote(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        match &self.kick_vote {
+            KickVoteState::VoteInProgress { expires_at, .. } if *expires_at <= now => {
+                let seconds = self.config.kick_vote.cooldown_seconds as i64;
+                self.kick_vote = KickVoteState::Cooldown {
+                    expires_at: *expires_at + chrono::Duration::seconds(seconds),
+                };
+            }
+            KickVoteState::Cooldown { expires_at } if *expires_at <= now => {
+                self.kick_vote = KickVoteState::Idle;
+            }
+            _ => {}
+        }
+    }
+
+    /// Spend gems on a power-up during the player's own turn.
+    ///
+    /// The target must suit the power-up (a tile and an `A`-`Z` letter for
+    /// `SwapTile`, another player for `FreezeOpponentTimer`, nothing for
+    /// `RevealWord`). `RevealWord` needs a dictionary and `FreezeOpponentTimer`
+    /// a turn time limit. Power-ups wait while a word challenge is open.
+    /// Nothing changes unless every check passes.
+    pub fn use_power_up(
+        &mut self,
+        player_id: i64,
+        power_up: PowerUp,
+        target: PowerUpTarget,
+    ) -> Result<PowerUpEffect, GameError> {
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        if self.challenge.is_pending() {
+            return Err(GameError::ChallengeInProgress);
+        }
+        let player = self.players.get(&player_id).ok_or(GameError::NotPlayer)?;
+        if !self.is_player_turn(player_id) {
+            return Err(GameError::NotYourTurn);
+        }
+        let config = self.config.power_ups.ok_or(GameError::PowerUpUnavailable)?;
+        let cost = config.cost(power_up);
+        if player.gems < cost {
+            return Err(GameError::NotEnoughGems);
+        }
+
+        let effect = match (power_up, target.clone()) {
+            (PowerUp::SwapTile, PowerUpTarget::Tile { position, letter })
+                if position.is_valid()
+                    && !self.grid[position.row][position.col].blocked
+                    && letter.is_ascii_alphabetic() =>
+            {
+                PowerUpEffect::TileSwapped {
+                    position,
+                    letter: letter.to_ascii_uppercase(),
+                }
+            }
+            (PowerUp::RevealWord, PowerUpTarget::None) => {
+                let dict = self.dictionary.as_deref().ok_or(GameError::PowerUpUnavailable)?;
+                let found = solver::solve(&self.grid, dict, self.min_word_length())
+                    .into_iter()
+                    .find(|w| !self.is_word_used(&w.word))
+                    .ok_or(GameError::PowerUpUnavailable)?;
+                PowerUpEffect::WordRevealed {
+                    word: found.word,
+                    path: found.path,
+                }
+            }
+            (PowerUp::FreezeOpponentTimer, PowerUpTarget::Player(target_id))
+                if target_id != player_id && self.players.contains_key(&target_id) =>
+            {
+                if self.config.turn_time_limit.is_none() {
+                    return Err(GameError::PowerUpUnavailable);
+                }
+                PowerUpEffect::TimerFrozen {
+                    target_id,
+                    seconds: config.freeze_seconds,
+                }
+            }
+            _ => return Err(GameError::InvalidPowerUpTarget),
+        };
+
+        match &effect {
+            PowerUpEffect::TileSwapped { position, letter } => {
+                let value = match self.letter_set() {
+                    Some(set) => set.value(*letter, None),
+                    None => self.config.letter_values.get(*letter),
+                };
+                let cell = &mut self.grid[position.row][position.col];
+                cell.letter = *letter;
+                cell.digraph = None;
+                cell.value = value;
+            }
+            PowerUpEffect::WordRevealed { .. } => {}
+            PowerUpEffect::TimerFrozen { target_id, seconds } => {
+                *self.timer_penalties.entry(*target_id).or_default() += seconds;
+            }
+        }
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.add_gems(ScoreSource::Purchase(power_up.as_str().to_string()), -cost);
+        }
+        let now = chrono::Utc::now();
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action: TurnAction::UsePowerUp {
+                power_up,
+                gems: cost,
+            },
+            at: now,
+        });
+        self.power_ups_used.push(PowerUpUse {
+            player_id,
+            round: self.round,
+            power_up,
+            gems: cost,
+            effect: effect.clone(),
+            at: now,
+        });
+        self.events.push(GameEvent::PowerUpUsed {
+            player_id,
+            power_up,
+        });
+        self.record(ReplayStep::UsePowerUp {
+            player_id,
+            power_up,
+            target,
+        });
+        Ok(effect)
+    }
+
+    /// Get the power-ups used so far, in order.
+    pub fn power_ups_used(&self) -> &[PowerUpUse] {
+        &self.power_ups_used
+    }
+
+    /// Give a player a partial reveal of an unused word, charging
+    /// `config.hints.cost` gems.
+    ///
+    /// Words the player was already hinted are skipped while others remain.
+    /// Returns None (charging nothing) if the game isn't active, hints are
+    /// disabled, there's no dictionary, the player is out of hints or gems,
+    /// or no unused word is left.
+    pub fn generate_hint(&mut self, player_id: i64) -> Option<Hint> {
+        if !self.status.is_active() {
+            return None;
+        }
+        let config = self.config.hints?;
+        let player = self.players.get(&player_id)?;
+        let hinted = self.hints.get(&player_id).map_or(&[][..], |h| h.as_slice());
+        if config.max_per_player.is_some_and(|max| hinted.len() >= max as usize)
+            || player.gems < config.cost
+        {
+            return None;
+        }
+
+        let unused = self.missed_words();
+        let found = unused
+            .iter()
+            .find(|w| !hinted.contains(&w.word))
+            .or_else(|| unused.first())?;
+        let hint = Hint::new(config.style, &found.word, &found.path);
+        let word = found.word.clone();
+
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.add_gems(ScoreSource::Purchase("hint".to_string()), -config.cost);
+        }
+        self.hints.entry(player_id).or_default().push(word);
+        self.events.push(GameEvent::HintUsed { player_id });
+        self.record(ReplayStep::Hint { player_id });
+        Some(hint)
+    }
+
+    /// Number of hints a player has taken.
+    pub fn hints_used(&self, player_id: i64) -> usize {
+        self.hints.get(&player_id).map_or(0, |h| h.len())
+    }
+
+    /// Spend gems to push back the current turn's deadline.
+    ///
+    /// Only the player whose turn it is may extend it, and only when turns
+    /// have a deadline and `config.turn_extensions` is set. Returns the new
+    /// deadline.
+    pub fn extend_turn(
+        &mut self,
+        player_id: i64,
+        seconds: u32,
+    ) -> Result<chrono::DateTime<chrono::Utc>, GameError> {
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        let player = self.players.get(&player_id).ok_or(GameError::NotPlayer)?;
+        if !self.is_player_turn(player_id) {
+            return Err(GameError::NotYourTurn);
+        }
+        let (Some(deadline), Some(config)) = (self.turn_deadline, self.config.turn_extensions)
+        else {
+            return Err(GameError::TurnExtensionUnavailable);
+        };
+        if seconds == 0 {
+            return Err(GameError::TurnExtensionUnavailable);
+        }
+
+        let (Some(turn_total), Some(game_total), Some(cost)) = (
+            self.turn_extension_seconds.checked_add(seconds),
+            player.extension_seconds.checked_add(seconds),
+            config.cost(seconds),
+        ) else {
+            return Err(GameError::TurnExtensionLimitReached);
+        };
+        let over_turn = config.max_seconds_per_turn.is_some_and(|max| turn_total > max);
+        let over_game = config.max_seconds_per_game.is_some_and(|max| game_total > max);
+        if over_turn || over_game {
+            return Err(GameError::TurnExtensionLimitReached);
+        }
+
+        if player.gems < cost {
+            return Err(GameError::NotEnoughGems);
+        }
+
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.add_gems(ScoreSource::Purchase("turn_extension".to_string()), -cost);
+            player.extension_seconds = game_total;
+        }
+        self.turn_extension_seconds = turn_total;
+        let deadline = deadline + chrono::Duration::seconds(seconds as i64);
+        self.turn_deadline = Some(deadline);
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action: TurnAction::ExtendTurn {
+                seconds,
+                gems: cost,
+            },
+            at: chrono::Utc::now(),
+        });
+        self.events.push(GameEvent::TurnExtended {
+            player_id,
+            deadline,
+        });
+        self.record(ReplayStep::ExtendTurn { player_id, seconds });
+        Ok(deadline)
+    }
+
+    /// Move bonuses to new cells according to `config.reroll`.
+    ///
+    /// Done automatically at each round boundary; cells are drawn from the
+    /// game's RNG and the changed ones are recorded as a `GridDelta` event
+    /// and returned.
+    pub fn reroll_bonuses(&mut self) -> Vec<CellChange> {
+        let changes = self.config.reroll.apply(&mut self.grid, &self.config.gems, &mut self.rng);
+        self.record(ReplayStep::GridChanged(changes.clone()));
+        self.record_grid_delta(changes.clone());
+        changes
+    }
+
+    fn record_grid_delta(&mut self, changes: Vec<CellChange>) {
+        // A multiplier moved onto a cell is a fresh one
+        for change in &changes {
+            self.claimed_multipliers.remove(&change.position);
+        }
+        if !changes.is_empty() {
+            self.events.push(GameEvent::GridDelta {
+                round: self.round,
+                changes,
+            });
+        }
+    }
+
+    fn record_gems(&mut self, positions: Vec<Position>) {
+        if !positions.is_empty() {
+            self.events.push(GameEvent::GemsSpawned { positions });
+        }
+    }
+
+    /// Play a bot's turn with the move `strategy` picks.
+    ///
+    /// The move goes through [`Game::submit_word`] or [`Game::pass_turn`]
+    /// like a human's. A word the game rejects is replaced by a pass, so a
+    /// faulty strategy can't stall the game. Returns the move made.
+    pub fn play_bot_turn(
+        &mut self,
+        player_id: i64,
+        strategy: &dyn BotStrategy,
+    ) -> Result<BotMove, GameError> {
+        let player = self.players.get(&player_id).ok_or(GameError::NotPlayer)?;
+        if !player.is_bot {
+            return Err(GameError::NotBot);
+        }
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        if !self.is_player_turn(player_id) {
+            return Err(GameError::NotYourTurn);
+        }
+
+        if let BotMove::PlayWord(path) = strategy.pick_move(self, player_id) {
+            if self.submit_word(player_id, &path).is_ok() {
+                return Ok(BotMove::PlayWord(path));
+            }
+        }
+        self.pass_turn(player_id)?;
+        Ok(BotMove::Pass)
+    }
+
+    /// Resign from the game.
+    ///
+    /// Unlike disconnecting, a forfeit is final: the player leaves the turn
+    /// rotation for good and their score is frozen, but they stay in the
+    /// standings (ranked below everyone still playing). The game ends once
+    /// at most one player is left in the rotation.
+    pub fn forfeit(&mut self, player_id: i64) -> Result<(), GameError> {
+        if !self.status.is_started() {
+            return Err(GameError::GameNotActive);
+        }
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::NotPlayer)?;
+        if player.forfeited {
+            return Err(GameError::Forfeited);
+        }
+        if !self.turn_order.contains(&player_id) {
+            return Err(GameError::NotPlayer);
+        }
+        player.forfeited = true;
+
+        let was_current = self.leave_rotation(player_id);
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action: TurnAction::Forfeited,
+            at: chrono::Utc::now(),
+        });
+        self.events.push(GameEvent::PlayerForfeited { player_id });
+        self.record(ReplayStep::Forfeit { player_id });
+
+        if self.turn_order.len() <= 1 {
+            if self.status.is_started() {
+                self.finish();
+            }
+        } else if was_current && self.status.is_active() {
+            self.begin_turn(chrono::Utc::now());
+            self.events.push(GameEvent::TurnAdvanced {
+                player_id: self.current_player_id().unwrap_or(0),
+                round: self.round,
+            });
+        }
+        Ok(())
+    }
+
+    /// Pass the current turn without playing a word.
+    ///
+    /// Unlike letting the timer run out, a pass is a deliberate action: it is
+    /// counted against the player and recorded in the move log. If
+    /// `config.end_after_pass_rounds` is reached the game ends.
+    pub fn pass_turn(&mut self, player_id: i64) -> Result<(i64, u8), GameError> {
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        if self.config.mode.is_blitz() {
+            return Err(GameError::WrongMode);
+        }
+        if self.challenge.is_pending() {
+            return Err(GameError::ChallengeInProgress);
+        }
+
+        let player = self.players.get(&player_id).ok_or(GameError::NotPlayer)?;
+        if !self.is_player_turn(player_id) {
+            return Err(GameError::NotYourTurn);
+        }
+        if let Some(limit) = self.config.max_passes_per_player {
+            if player.passes >= limit {
+                return Err(GameError::PassLimitReached);
+            }
+        }
+
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.passes += 1;
+            player.missed_turns = 0;
+        }
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action: TurnAction::Pass,
+            at: chrono::Utc::now(),
+        });
+        self.events.push(GameEvent::Passed {
+            player_id,
+            round: self.round,
+        });
+
+        self.record(ReplayStep::Pass { player_id });
+        let next = self.next_turn();
+        if let Some(rounds) = self.config.end_after_pass_rounds {
+            let passes = rounds as usize * self.turn_order.len();
+            if self.status.is_started() && self.consecutive_passes() >= passes {
+                self.finish();
+            }
+        }
+        Ok(next)
+    }
+
+    /// Number of passes in a row since the last turn that ended any other way.
+    pub fn consecutive_passes(&self) -> usize {
+        self.moves
+            .iter()
+            .rev()
+            .filter(|m| {
+                !matches!(
+                    m.action,
+                    TurnAction::ExtendTurn { .. }
+                        | TurnAction::UsePowerUp { .. }
+                        | TurnAction::ScoreAdjusted { .. }
+                        | TurnAction::Challenged { .. }
+                )
+            })
+            .take_while(|m| m.action == TurnAction::Pass)
+            .count()
+    }
+
+    /// Play a word: the whole turn in one call.
+    ///
+    /// Checks it's the player's turn, validates the path and word, applies
+    /// submission limits, scores the word, collects gems on the path, records
+    /// the move and advances the turn. Nothing changes if any check fails.
+    /// The caller still ends the game once `should_end` is true.
+    pub fn submit_word(
+        &mut self,
+        player_id: i64,
+        path: &[Position],
+    ) -> Result<WordPlayResult, GameError> {
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        if self.config.mode.is_blitz() {
+            return Err(GameError::WrongMode);
+        }
+        if self.challenge.is_pending() {
+            return Err(GameError::ChallengeInProgress);
+        }
+        if !self.players.contains_key(&player_id) {
+            return Err(GameError::NotPlayer);
+        }
+        if !self.is_player_turn(player_id) {
+            return Err(GameError::NotYourTurn);
+        }
+        let word = self.validate_path(path)?;
+        self.check_word(&word)?;
+        self.register_submission(player_id, chrono::Utc::now())?;
+
+        let score = self.score_for(player_id, path);
+        for pos in path {
+            self.grid[pos.row][pos.col].has_gem = false;
+            if self.grid[pos.row][pos.col].multiplier.is_some()
+                && self.config.multiplier_use != MultiplierUse::Unlimited
+            {
+                self.claimed_multipliers.entry(*pos).or_default().insert(player_id);
+            }
+        }
+        self.used_words.insert(word.to_uppercase());
+        let round = self.round;
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::NotPlayer)?;
+        let bonus_points: i32 = score.bonuses.iter().map(|(_, points)| points).sum();
+        player.add_score(ScoreSource::Word(word.clone()), score.points - bonus_points);
+        for (bonus, points) in &score.bonuses {
+            player.add_score(ScoreSource::Bonus(bonus.as_str().to_string()), *points);
+        }
+        if score.gems > 0 {
+            player.add_gems(ScoreSource::Bonus("gems".to_string()), score.gems);
+        }
+        player.words_played.push(word.clone());
+        player.missed_turns = 0;
+
+        self.moves.push(TurnRecord {
+            player_id,
+            round,
+            action: TurnAction::PlayWord {
+                word: word.clone(),
+                path: path.to_vec(),
+                points: score.points,
+                gems: score.gems,
+            },
+            at: chrono::Utc::now(),
+        });
+        self.track_word(player_id, &word, score.gems, round);
+        self.events.push(GameEvent::WordPlayed {
+            player_id,
+            word,
+            path: path.to_vec(),
+            points: score.points,
+            gems: score.gems,
+        });
+
+        let refreshed = if self.config.refresh_played_cells {
+            self.refresh(path)
+        } else {
+            Vec::new()
+        };
+
+        self.record(ReplayStep::PlayWord {
+            player_id,
+            path: path.to_vec(),
+        });
+        let (next_player_id, round) = self.next_turn();
+        Ok(WordPlayResult {
+            score,
+            next_player_id,
+            round,
+            refreshed,
+        })
+    }
+
+    /// Play a word in a blitz game, timestamped now.
+    ///
+    /// Any player may submit until the window closes. The word is scored
+    /// against the board now but only awarded when the game ends, once
+    /// duplicates are settled by the mode's `DuplicateRule`; the returned
+    /// score is before settling. Gems stay on the board for everyone.
+    pub fn submit_blitz_word(
+        &mut self,
+        player_id: i64,
+        path: &[Position],
+    ) -> Result<WordScore, GameError> {
+        self.play_blitz_word(player_id, path, chrono::Utc::now())
+    }
+
+    /// [`Game::submit_blitz_word`] at a recorded time, for replays.
+    pub(crate) fn play_blitz_word(
+        &mut self,
+        player_id: i64,
+        path: &[Position],
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<WordScore, GameError> {
+        if !self.config.mode.is_blitz() {
+            return Err(GameError::WrongMode);
+        }
+        if !self.status.is_active() || self.ends_at.is_some_and(|end| at >= end) {
+            return Err(GameError::GameNotActive);
+        }
+        let player = self.players.get(&player_id).ok_or(GameError::NotPlayer)?;
+        if player.forfeited {
+            return Err(GameError::Forfeited);
+        }
+        let word = self.validate_path(path)?.to_uppercase();
+        self.check_word(&word)?;
+        if self.blitz.has_found(player_id, &word) {
+            return Err(GameError::WordUsed);
+        }
+        self.register_submission(player_id, at)?;
+
+        let score = self.score_for(player_id, path);
+        self.blitz.add(BlitzFind {
+            player_id,
+            word,
+            path: path.to_vec(),
+            points: score.points,
+            gems: score.gems,
+            at,
+        })?;
+        self.record(ReplayStep::PlayBlitzWord {
+            player_id,
+            path: path.to_vec(),
+            at,
+        });
+        self.events.push(GameEvent::BlitzWordFound {
+            player_id,
+            found: self.blitz.found_count(player_id),
+        });
+        Ok(score)
+    }
+
+    /// Give the cells on a path new letters, drawn with the board's letter
+    /// weighting (standard weights if the board was supplied by the caller).
+    ///
+    /// Multipliers and gems stay put. Returns the changed cells, which are
+    /// also sent as a `TilesRefreshed` event.
+    pub fn refresh_cells(&mut self, path: &[Position]) -> Vec<LetterChange> {
+        self.record(ReplayStep::RefreshCells(path.to_vec()));
+        self.refresh(path)
+    }
+
+    fn refresh(&mut self, path: &[Position]) -> Vec<LetterChange> {
+        let weights = self
+            .letter_weighting
+            .as_ref()
+            .map_or_else(LetterWeights::default, |w| w.weights);
+        let mut changes = Vec::new();
+        for pos in path.iter().filter(|p| p.is_valid()) {
+            if self.grid[pos.row][pos.col].blocked {
+                continue;
+            }
+            let tile = match &self.config.grid.letters {
+                Some(set) => set.sample(&mut self.rng).copied(),
+                None => {
+                    let letter = weights.sample(&mut self.rng);
+                    Some(Tile::new(letter, 0, self.config.letter_values.get(letter)))
+                }
+            };
+            let Some(tile) = tile else {
+                continue;
+            };
+            let cell = &mut self.grid[pos.row][pos.col];
+            cell.letter = tile.letter;
+            cell.digraph = tile.digraph;
+            cell.value = tile.value;
+            changes.push(LetterChange {
+                position: *pos,
+                letter: tile.letter,
+                digraph: tile.digraph,
+                value: tile.value,
+            });
+        }
+        if !changes.is_empty() {
+            self.events.push(GameEvent::TilesRefreshed {
+                round: self.round,
+                changes: changes.clone(),
+            });
+        }
+        changes
+    }
+
+    /// Check a player's submission limits and record a submission at `now`.
+    pub fn register_submission(
+        &mut self,
+        player_id: i64,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), GameError> {
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+
+        let limits = &self.config.submissions;
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::NotPlayer)?;
+
+        if let (Some(interval), Some(last)) = (limits.min_interval, player.last_submission_at) {
+            let retry_at = last + interval;
+            if now < retry_at {
+                return Err(GameError::SubmissionCooldown { retry_at });
+            }
+        }
+        if let Some(max) = limits.max_per_round {
+            if player.submissions_this_round >= max {
+                return Err(GameError::SubmissionLimitReached);
+            }
+        }
+
+        player.last_submission_at = Some(now);
+        player.submissions_this_round += 1;
+        Ok(())
+    }
+
+    /// Get the move log.
+    pub fn moves(&self) -> &[TurnRecord] {
+        &self.moves
+    }
+
+    /// Get one player's moves, in order.
+    pub fn moves_for_player(&self, player_id: i64) -> impl Iterator<Item = &TurnRecord> {
+        self.moves.iter().filter(move |m| m.player_id == player_id)
+    }
+
+    /// Summarize the moves made during a round.
+    pub fn round_summary(&self, round: u8) -> RoundSummary {
+        RoundSummary {
+            round,
+            moves: self
+                .moves
+                .iter()
+                .filter(|m| m.round == round)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Freeze a game in progress. Turn deadlines stop running until
+    /// [`Game::resume`].
+    pub fn pause(&mut self, reason: &str) -> Result<(), GameError> {
+        if self.status != GameStatus::InProgress {
+            return Err(GameError::InvalidStatus);
+        }
+        self.status = GameStatus::Paused;
+        self.paused_at = Some(chrono::Utc::now());
+        self.pause_reason = Some(reason.to_string());
+        self.events.push(GameEvent::Paused {
+            reason: reason.to_string(),
+        });
+        self.record(ReplayStep::Pause(reason.to_string()));
+        Ok(())
+    }
+
+    /// Resume a paused game. Turn and timer-vote deadlines move back by the
+    /// time spent paused, so the current player keeps the time they had left.
+    pub fn resume(&mut self) -> Result<(), GameError> {
+        if self.status != GameStatus::Paused {
+            return Err(GameError::InvalidStatus);
+        }
+        let now = chrono::Utc::now();
+        let paused_for = self.paused_at.map_or(chrono::Duration::zero(), |at| now - at);
+
+        for time in [
+            &mut self.turn_started_at,
+            &mut self.turn_deadline,
+            &mut self.ends_at,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *time += paused_for;
+        }
+        match &mut self.timer_vote {
+            TimerVoteState::VoteInProgress { expires_at, .. }
+            | TimerVoteState::TimerActive { expires_at, .. }
+            | TimerVoteState::Cooldown { expires_at } => *expires_at += paused_for,
+            TimerVoteState::Idle => {}
+        }
+        match &mut self.kick_vote {
+            KickVoteState::VoteInProgress { expires_at, .. }
+            | KickVoteState::Cooldown { expires_at } => *expires_at += paused_for,
+            KickVoteState::Idle => {}
+        }
+
+        self.status = GameStatus::InProgress;
+        self.paused_at = None;
+        self.pause_reason = None;
+        self.events.push(GameEvent::Resumed);
+        self.record(ReplayStep::Resume);
+        Ok(())
+    }
+
+    /// Check if game should end.
+    pub fn should_end(&self) -> bool {
+        self.round > self.max_rounds || self.coop_target_reached()
+    }
+
+    /// End the game.
+    ///
+    /// Returns the ranked results; the fuller end-of-game summary is
+    /// available from [`Game::stats`] afterwards. Players whose totals don't
+    /// match their audit trail are flagged in the results rather than
+    /// keeping the game from ending.
+    ///
+    /// With `TieBreak::Overtime`, a tied lead instead starts another round
+    /// among the tied players and fails with `Overtime`; call `end` again
+    /// once [`Game::should_end`] is true.
+    pub fn end(&mut self) -> Result<GameResult, GameError> {
+        if self.begin_overtime() {
+            self.record(ReplayStep::End);
+            return Err(GameError::Overtime);
+        }
+        if !self.status.is_started() {
+            return Err(GameError::InvalidStatus);
+        }
+        let result = self.finish();
+        self.record(ReplayStep::End);
+        Ok(result)
+    }
+
+    /// Start an overtime round if the lead is tied and the tie-break allows
+    /// it. Players not in the tie sit out the rest of the game.
+    fn begin_overtime(&mut self) -> bool {
+        let TieBreak::Overtime { max_rounds } = self.config.tie_break else {
+            return false;
+        };
+        if self.config.mode.is_coop() || self.config.mode.is_blitz() {
+            return false;
+        }
+        if !self.status.is_started() || self.overtime_rounds >= max_rounds {
+            return false;
+        }
+        let score = |id: &i64| self.players.get(id).map_or(i32::MIN, |p| p.score);
+        let Some(lead) = self.turn_order.iter().map(score).max() else {
+            return false;
+        };
+        let tied: Vec<i64> =
+            self.turn_order.iter().copied().filter(|id| score(id) == lead).collect();
+        if tied.len() < 2 {
+            return false;
+        }
+
+        self.overtime_rounds += 1;
+        self.turn_order = tied.clone();
+        self.current_turn_index = 0;
+        self.max_rounds = self.round;
+        self.begin_turn(chrono::Utc::now());
+        self.events.push(GameEvent::OvertimeStarted {
+            round: self.round,
+            player_ids: tied,
+        });
+        true
+    }
+
+    /// Finish a started game. Callers check the status first.
+    fn finish(&mut self) -> GameResult {
+        self.settle_blitz();
+
+        self.status = GameStatus::Finished;
+        self.ended_at = Some(chrono::Utc::now());
+        self.paused_at = None;
+        self.pause_reason = None;
+        if self.config.mode == GameMode::Multiplayer {
+            let winners = self.result().winners;
+            for (player_id, achievement) in self.achievements.on_game_end(&winners, self.round) {
+                self.events.push(GameEvent::AchievementUnlocked {
+                    player_id,
+                    achievement,
+                });
+            }
+        }
+        self.stats = Some(GameStats::from_game(self));
+        self.events.push(GameEvent::Ended);
+
+        self.result()
+    }
+
+    /// Award the words found in a blitz game, earliest first.
+    fn settle_blitz(&mut self) {
+        let GameMode::Blitz { duplicates, .. } = self.config.mode else {
+            return;
+        };
+        for award in self.blitz.resolve(duplicates) {
+            let BlitzFind {
+                player_id,
+                word,
+                path,
+                at,
+                ..
+            } = award.find;
+            let Some(player) = self.players.get_mut(&player_id) else {
+                continue;
+            };
+            player.add_score(ScoreSource::Word(word.clone()), award.points);
+            if award.gems > 0 {
+                player.add_gems(ScoreSource::Bonus("gems".to_string()), award.gems);
+            }
+            player.words_played.push(word.clone());
+            self.used_words.insert(word.clone());
+            self.moves.push(TurnRecord {
+                player_id,
+                round: self.round,
+                action: TurnAction::PlayWord {
+                    word: word.clone(),
+                    path: path.clone(),
+                    points: award.points,
+                    gems: award.gems,
+                },
+                at,
+            });
+            self.track_word(player_id, &word, award.gems, self.round);
+            self.events.push(GameEvent::WordPlayed {
+                player_id,
+                word,
+                path,
+                points: award.points,
+                gems: award.gems,
+            });
+        }
+    }
+
+    /// Run the achievement hooks for a played word.
+    fn track_word(&mut self, player_id: i64, word: &str, gems: i32, round: u8) {
+        for achievement in self.achievements.on_word(player_id, word, gems, round) {
+            self.events.push(GameEvent::AchievementUnlocked {
+                player_id,
+                achievement,
+            });
+        }
+    }
+
+    /// Achievements unlocked so far; final once the game has ended.
+    pub fn achievements(&self) -> &AchievementTracker {
+        &self.achievements
+    }
+
+    /// Statistics computed when the game finished (None before that).
+    pub fn stats(&self) -> Option<&GameStats> {
+        self.stats.as_ref()
+    }
+
+    /// Current standings, final once the game has ended.
+    pub fn result(&self) -> GameResult {
+        GameResult::from_game(self)
+    }
+
+    /// End the game now on behalf of an admin.
+    ///
+    /// Like [`Game::end`], but records who ended it in the move log.
+    pub fn force_end(&mut self, by: i64) -> Result<GameResult, GameError> {
+        if !self.status.is_started() {
+            return Err(GameError::InvalidStatus);
+        }
+        let result = self.finish();
+        self.record(ReplayStep::ForceEnd { by });
+        self.moves.push(TurnRecord {
+            player_id: by,
+            round: self.round,
+            action: TurnAction::ForceEnded,
+            at: chrono::Utc::now(),
+        });
+        Ok(result)
+    }
+
+    /// Skip the current player's turn on behalf of an admin.
+    pub fn force_skip_turn(&mut self, by: i64) -> Result<(i64, u8), GameError> {
+        if !self.status.is_active() {
+            return Err(GameError::GameNotActive);
+        }
+        let player_id = self.current_player_id().ok_or(GameError::NotEnoughPlayers)?;
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action: TurnAction::ForceSkipped { by },
+            at: chrono::Utc::now(),
+        });
+        self.record(ReplayStep::ForceSkip { by });
+        Ok(self.next_turn())
+    }
+
+    /// Change a player's score, e.g. for a rule penalty. Returns the new
+    /// score.
+    ///
+    /// The change goes into the player's score history as an `Adjustment`
+    /// with `reason`.
+    pub fn adjust_score(
+        &mut self,
+        player_id: i64,
+        delta: i32,
+        reason: &str,
+    ) -> Result<i32, GameError> {
+        let score = self.apply_score_adjustment(player_id, delta, reason)?;
+        self.record(ReplayStep::AdjustScore {
+            player_id,
+            amount: delta,
+            reason: reason.to_string(),
+            by: None,
+        });
+        Ok(score)
+    }
+
+    /// Change a player's score on behalf of an admin. Returns the new score.
+    ///
+    /// Like [`Game::adjust_score`], but also records who made the change in
+    /// the move log.
+    pub fn force_adjust_score(
+        &mut self,
+        player_id: i64,
+        amount: i32,
+        reason: &str,
+        by: i64,
+    ) -> Result<i32, GameError> {
+        let score = self.apply_score_adjustment(player_id, amount, reason)?;
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action: TurnAction::ScoreAdjusted {
+                amount,
+                reason: reason.to_string(),
+                by,
+            },
+            at: chrono::Utc::now(),
+        });
+        self.record(ReplayStep::AdjustScore {
+            player_id,
+            amount,
+            reason: reason.to_string(),
+            by: Some(by),
+        });
+        Ok(score)
+    }
+
+    fn apply_score_adjustment(
+        &mut self,
+        player_id: i64,
+        amount: i32,
+        reason: &str,
+    ) -> Result<i32, GameError> {
+        if self.status.is_terminal() {
+            return Err(GameError::InvalidStatus);
+        }
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::NotPlayer)?;
+        if player.forfeited {
+            return Err(GameError::Forfeited);
+        }
+        player.add_score(ScoreSource::Adjustment(reason.to_string()), amount);
+        let score = player.score;
+        self.events.push(GameEvent::ScoreAdjusted { player_id, score });
+        Ok(score)
+    }
+
+    /// Remove a player on behalf of an admin.
+    ///
+    /// If it was their turn, the next player's turn starts. If the game has
+    /// started and fewer than `config.min_players` remain (or nobody does),
+    /// it is cancelled. Prefer [`GameManager::remove_player`], which also
+    /// updates its index.
+    pub fn remove_player(&mut self, player_id: i64, by: i64) -> Result<GamePlayer, GameError> {
+        let player = self.remove(player_id, TurnAction::Removed { by })?;
+        self.record(ReplayStep::RemovePlayer { player_id, by });
+        Ok(player)
+    }
+
+    /// Take a player out of the turn order, keeping the turn pointer on the
+    /// same player and dropping votes about them. Returns true if it was
+    /// their turn.
+    fn leave_rotation(&mut self, player_id: i64) -> bool {
+        let was_current = self.is_player_turn(player_id);
+        if let Some(index) = self.turn_order.iter().position(|id| *id == player_id) {
+            self.turn_order.remove(index);
+            if index < self.current_turn_index {
+                self.current_turn_index -= 1;
+            }
+        }
+        if self.current_turn_index >= self.turn_order.len() {
+            self.current_turn_index = 0;
+        }
+
+        let kick_target = match &self.kick_vote {
+            KickVoteState::VoteInProgress {
+                target_player_id, ..
+            } => Some(*target_player_id),
+            _ => None,
+        };
+        if kick_target == Some(player_id) {
+            self.kick_vote = KickVoteState::Idle;
+        }
+        if was_current {
+            self.end_timer_vote(chrono::Utc::now());
+        }
+        was_current
+    }
+
+    /// Remove a player mid-game, logging `action` against them.
+    fn remove(&mut self, player_id: i64, action: TurnAction) -> Result<GamePlayer, GameError> {
+        if self.status.is_terminal() {
+            return Err(GameError::InvalidStatus);
+        }
+        let player = self
+            .players
+            .remove(&player_id)
+            .ok_or(GameError::NotPlayer)?;
+
+        let was_current = self.leave_rotation(player_id);
+        self.moves.push(TurnRecord {
+            player_id,
+            round: self.round,
+            action,
+            at: chrono::Utc::now(),
+        });
+        self.events.push(GameEvent::PlayerRemoved { player_id });
+
+        let min_players = self.config.min_players.unwrap_or(1).max(1);
+        if self.status.is_started() && self.turn_order.len() < min_players {
+            self.mark_cancelled(CancelReason::NotEnoughPlayers);
+        } else if was_current && self.status.is_active() {
+            self.begin_turn(chrono::Utc::now());
+            self.events.push(GameEvent::TurnAdvanced {
+                player_id: self.current_player_id().unwrap_or(0),
+                round: self.round,
+            });
+        }
+        Ok(player)
+    }
+
+    /// Cancel the game.
+    pub fn cancel(&mut self, reason: CancelReason) {
+        self.mark_cancelled(reason.clone());
+        self.record(ReplayStep::Cancel(reason));
+    }
+
+    fn mark_cancelled(&mut self, reason: CancelReason) {
+        self.status = GameStatus::Cancelled;
+        self.starts_at = None;
+        self.ended_at = Some(chrono::Utc::now());
+        self.paused_at = None;
+        self.pause_reason = None;
+        self.cancel_reason = Some(reason.clone());
+        self.events.push(GameEvent::Cancelled { reason });
+    }
+
+    /// Abort a game that has not started yet.
+    ///
+    /// Only valid while `Idle` or `Starting`; permission checks (host vs.
+    /// player) are left to the caller.
+    pub fn abort(&mut self, initiator_id: i64) -> Result<(), GameError> {
+        match self.status {
+            GameStatus::Idle | GameStatus::Starting => {}
+            GameStatus::InProgress => return Err(GameError::GameStarted),
+            _ => return Err(GameError::InvalidStatus),
+        }
+
+        self.status = GameStatus::Cancelled;
+        self.starts_at = None;
+        self.ended_at = Some(chrono::Utc::now());
+        let reason = CancelReason::AbortedBeforeStart { by: initiator_id };
+        self.cancel_reason = Some(reason.clone());
+        self.events.push(GameEvent::Cancelled { reason });
+        self.record(ReplayStep::Abort { by: initiator_id });
+        Ok(())
+    }
+
+    /// Sequence number of the latest game event.
+    pub fn event_seq(&self) -> u64 {
+        self.events.last_seq()
+    }
+
+    /// Get events after `seq`, or None if the client needs a full snapshot.
+    pub fn events_since(&self, seq: u64) -> Option<Vec<&SequencedEvent<GameEvent>>> {
+        self.events.events_since(seq)
+    }
+
+    /// Get a player.
+    pub fn get_player(&self, player_id: i64) -> Option<&GamePlayer> {
+        self.players.get(&player_id)
+    }
+
+    /// Get a mutable player.
+    pub fn get_player_mut(&mut self, player_id: i64) -> Option<&mut GamePlayer> {
+        self.players.get_mut(&player_id)
+    }
+
+    /// Set a player's connection status.
+    pub fn set_player_connected(
+        &mut self,
+        player_id: i64,
+        connected: bool,
+    ) -> Result<(), GameError> {
+        let player = self.players.get_mut(&player_id).ok_or(GameError::NotPlayer)?;
+        player.is_connected = connected;
+        self.record(ReplayStep::SetConnected {
+            player_id,
+            connected,
+        });
+        Ok(())
+    }
+
+    /// Check if player is in game.
+    pub fn has_player(&self, player_id: i64) -> bool {
+        self.players.contains_key(&player_id)
+    }
+
+    /// Get all players.
+    pub fn players(&self) -> impl Iterator<Item = &GamePlayer> {
+        self.players.values()
+    }
+
+    /// Get player IDs in turn order.
+    pub fn player_ids_in_order(&self) -> &[i64] {
+        &self.turn_order
+    }
+
+    /// Player count.
+    pub fn player_count(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Add a spectator, subject to `config.spectator_join` and
+    /// `config.max_spectators`.
+    pub fn add_spectator(&mut self, spectator: Spectator) -> Result<(), GameError> {
+        if self.spectators.contains_key(&spectator.player_id) {
+            return Err(GameError::AlreadySpectator);
+        }
+        if self.config.spectator_join == SpectatorJoin::OnceStarted
+            && matches!(self.status, GameStatus::Idle | GameStatus::Starting)
+        {
+            return Err(GameError::GameNotActive);
+        }
+        if self.config.max_spectators.is_some_and(|max| self.spectators.len() >= max) {
+            return Err(GameError::TooManySpectators);
+        }
+
+        let player_id = spectator.player_id;
+        self.spectators.insert(player_id, spectator);
+        self.events.push(GameEvent::SpectatorJoined { count: self.spectators.len() });
+        Ok(())
+    }
+
+    /// Remove a spectator.
+    pub fn remove_spectator(&mut self, player_id: i64) -> Option<Spectator> {
+        let spectator = self.spectators.remove(&player_id)?;
+        self.events.push(GameEvent::SpectatorLeft { count: self.spectators.len() });
+        Some(spectator)
+    }
+
+    /// Get spectators.
+    pub fn spectators(&self) -> impl Iterator<Item = &Spectator> {
+        self.spectators.values()
+    }
+
+    /// Spectator count.
+    pub fn spectator_count(&self) -> usize {
+        self.spectators.len()
+    }
+
+    /// Post a message to the game chat, subject to `config.chat`.
+    ///
+    /// The policy only applies while the game is in progress.
+    pub fn post_chat(&mut self, player_id: i64, text: &str) -> Result<&ChatMessage, GameError> {
+        let player = self.players.get(&player_id).ok_or(GameError::NotPlayer)?;
+        let now = chrono::Utc::now();
+
+        if self.status == GameStatus::InProgress {
+            let allowed = match self.config.chat {
+                ChatPolicy::Anytime => true,
+                ChatPolicy::BetweenTurns { window } => {
+                    self.turn_started_at.is_none_or(|at| now - at <= window)
+                }
+                ChatPolicy::MutedOnTurn => !self.is_player_turn(player_id),
+            };
+            if !allowed {
+                return Err(GameError::ChatNotAllowed);
+            }
+        }
+
+        let message = ChatMessage {
+            player_id,
+            user_id: player.user_id.clone(),
+            username: player.username.clone(),
+            text: text.to_string(),
+            sent_at: now,
+        };
+        let posted = self.chat.post(message)?;
+        self.events.push(GameEvent::ChatPosted {
+            player_id,
+            text: posted.text.clone(),
+        });
+        Ok(posted)
+    }
+
+    /// Get the game chat.
+    pub fn chat(&self) -> &ChatBuffer {
+        &self.chat
+    }
+
+    /// Turn a spectator into a player, even once the game has started.
+    ///
+    /// The new player goes last in the turn order and starts with a score
+    /// from `starting_score` (recorded as a `catch_up` adjustment). Returns a
+    /// player-redacted snapshot for the new player to catch up from.
+    pub fn promote_spectator(
+        &mut self,
+        player_id: i64,
+        starting_score: StartingScore,
+    ) -> Result<serde_json::Value, GameError> {
+        if self.status.is_terminal() {
+            return Err(GameError::InvalidStatus);
+        }
+        if self.players.contains_key(&player_id) {
+            return Err(GameError::AlreadyPlayer);
+        }
+        if self.players.len() >= self.max_players() {
+            return Err(GameError::TooManyPlayers);
+        }
+        let spectator = self
+            .spectators
+            .remove(&player_id)
+            .ok_or(GameError::NotSpectator)?;
+
+        let scores: Vec<i32> = self.players.values().map(|p| p.score).collect();
+        let score = match starting_score {
+            StartingScore::Zero => 0,
+            StartingScore::Lowest => scores.iter().copied().min().unwrap_or(0),
+            StartingScore::Average if scores.is_empty() => 0,
+            StartingScore::Average => scores.iter().sum::<i32>() / scores.len() as i32,
+        };
+        let mut player = GamePlayer::new(
+            player_id,
+            spectator.user_id.clone(),
+            spectator.username.clone(),
+            spectator.avatar_url.clone(),
+            self.turn_order.len() as u8,
+        );
+        if score != 0 {
+            player.add_score(ScoreSource::Adjustment("catch_up".to_string()), score);
+        }
+
+        self.players.insert(player_id, player);
+        self.turn_order.push(player_id);
+        self.events.push(GameEvent::SpectatorLeft { count: self.spectators.len() });
+        self.events.push(GameEvent::PlayerJoined { player_id });
+        self.record(ReplayStep::PromoteSpectator {
+            spectator,
+            starting_score,
+        });
+        Ok(self.to_json_for(RedactionProfile::Player))
+    }
+
+    /// Post a message to the spectator chat.
+    pub fn post_spectator_chat(
+        &mut self,
+        player_id: i64,
+        text: &str,
+    ) -> Result<&ChatMessage, GameError> {
+        let spectator = self
+            .spectators
+            .get(&player_id)
+            .ok_or(GameError::NotSpectator)?;
+        let message = ChatMessage {
+            player_id,
+            user_id: spectator.user_id.clone(),
+            username: spectator.username.clone(),
+            text: text.to_string(),
+            sent_at: chrono::Utc::now(),
+        };
+        Ok(self.spectator_chat.post(message)?)
+    }
+
+    /// Get the spectator chat.
+    pub fn spectator_chat(&self) -> &ChatBuffer {
+        &self.spectator_chat
+    }
+
+    /// Check if word has been used.
+    pub fn is_word_used(&self, word: &str) -> bool {
+        self.used_words.contains(&word.to_uppercase())
+    }
+
+    /// Words on the board nobody played, best first (empty without a
+    /// dictionary). For end-of-game screens.
+    pub fn missed_words(&self) -> Vec<FoundWord> {
+        let Some(dict) = self.dictionary.as_deref() else {
+            return Vec::new();
+        };
+        solver::solve(&self.grid, dict, self.min_word_length())
+            .into_iter()
+            .filter(|w| !self.is_word_used(&w.word))
+            .collect()
+    }
+
+    /// Check that a word may be played: long enough, not used yet and, if
+    /// the game has a dictionary, a valid word.
+    pub fn check_word(&self, word: &str) -> Result<(), GameError> {
+        if word.chars().count() < self.min_word_length() {
+            return Err(GameError::PathTooShort);
+        }
+        if self.is_word_used(word) {
+            return Err(GameError::WordUsed);
+        }
+        if self.dictionary.as_ref().is_some_and(|d| !d.contains(word)) {
+            return Err(GameError::NotAWord);
+        }
+        Ok(())
+    }
+
+    /// Mark word as used.
+    pub fn use_word(&mut self, word: &str) {
+        self.record(ReplayStep::UseWord(word.to_string()));
+        self.used_words.insert(word.to_uppercase());
+    }
+
+    /// Get cell at position.
+    pub fn get_cell(&self, pos: Position) -> Option<&GridCell> {
+        if pos.is_valid() {
+            Some(&self.grid[pos.row][pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// Get mutable cell at position.
+    pub fn get_cell_mut(&mut self, pos: Position) -> Option<&mut GridCell> {
+        if pos.is_valid() {
+            Some(&mut self.grid[pos.row][pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// Count gems currently on the board.
+    pub fn gems_on_board(&self) -> usize {
+        self.grid.iter().flatten().filter(|c| c.has_gem).count()
+    }
+
+    /// Check that a path is playable and return the word it spells.
+    ///
+    /// Every position must be on the board and not blocked, each cell
+    /// adjacent to the one before it, no cell used twice, and the path at least
+    /// [`Game::min_word_length`] cells long.
+    pub fn validate_path(&self, path: &[Position]) -> Result<String, GameError> {
+        let path = WordPath::new(path.to_vec(), self.min_word_length())?;
+        if path.iter().any(|p| self.grid[p.row][p.col].blocked) {
+            return Err(GameError::InvalidPath);
+        }
+        Ok(path.word(&self.grid))
+    }
+
+    /// Validate a path and score it against the current board.
+    ///
+    /// Multipliers count even if already claimed; see
+    /// [`Game::is_multiplier_available`].
+    pub fn score_path(&self, path: &[Position]) -> Result<WordScore, GameError> {
+        self.validate_path(path)?;
+        Ok(self.config.scoring.score(&self.grid, path))
+    }
+
+    /// Check if a cell's multiplier would still count for a player's word.
+    pub fn is_multiplier_available(&self, pos: Position, player_id: i64) -> bool {
+        let claimed = self.claimed_multipliers.get(&pos);
+        match self.config.multiplier_use {
+            MultiplierUse::Unlimited => true,
+            MultiplierUse::OncePerGame => claimed.is_none_or(|c| c.is_empty()),
+            MultiplierUse::OncePerPlayer => claimed.is_none_or(|c| !c.contains(&player_id)),
+        }
+    }
+
+    /// Score a path for a player, leaving out multipliers they may no longer
+    /// claim and applying their handicap.
+    fn score_for(&self, player_id: i64, path: &[Position]) -> WordScore {
+        let mut score = if self.config.multiplier_use == MultiplierUse::Unlimited {
+            self.config.scoring.score(&self.grid, path)
+        } else {
+            let mut grid = self.grid.clone();
+            for pos in path.iter().filter(|p| p.is_valid()) {
+                if !self.is_multiplier_available(*pos, player_id) {
+                    grid[pos.row][pos.col].multiplier = None;
+                }
+            }
+            self.config.scoring.score(&grid, path)
+        };
+        if let Some(handicap) = self.config.handicaps.get(&player_id) {
+            handicap.apply(&mut score);
+        }
+        score
+    }
+
+    /// Extract word from path.
+    ///
+    /// Positions off the board are skipped; use [`Game::validate_path`] to
+    /// reject invalid paths instead.
+    pub fn extract_word(&self, path: &[Position]) -> String {
+        path.iter()
+            .filter_map(|p| self.get_cell(*p))
+            .flat_map(|c| c.letters())
+            .collect()
+    }
+
+    /// Convert grid to JSON.
+    pub fn grid_to_json(&self) -> serde_json::Value {
+        let mut rows: Vec<serde_json::Value> = self
+            .grid
+            .iter()
+            .map(|row| {
+                let cells: Vec<serde_json::Value> =
+                    row.iter().map(|c| c.to_json()).collect();
+                serde_json::Value::Array(cells)
+            })
+            .collect();
+        if self.config.multiplier_use == MultiplierUse::OncePerGame {
+            for pos in self.claimed_multipliers.keys() {
+                rows[pos.row][pos.col]["multiplier_used"] = true.into();
+            }
+        }
+        serde_json::Value::Array(rows)
+    }
+
+    /// Convert full game state to JSON snapshot.
+    pub fn to_json(&self) -> serde_json::Value {
+        let players: Vec<serde_json::Value> =
+            self.turn_order
+                .iter()
+                .filter_map(|id| self.players.get(id))
+                .map(|p| p.to_json())
+                .collect();
+
+        let spectators: Vec<serde_json::Value> =
+            self.spectators.values().map(|s| s.to_json()).collect();
+
+        let current_turn = self.current_player().map(|p| p.user_id.as_str());
+
+        serde_json::json!({
+            "game_id": self.id,
+            "lobby_id": self.lobby_id,
+            "status": self.status.as_str(),
+            "grid": self.grid_to_json(),
+            "players": players,
+            "spectators": spectators,
+            "spectator_count": self.spectators.len(),
+            "current_turn": current_turn,
+            "round": self.round,
+            "max_rounds": self.max_rounds,
+            "turn_deadline": self.turn_deadline.map(|d| d.to_rfc3339()),
+            "starts_at": self.starts_at.map(|d| d.to_rfc3339()),
+            "mode": self.config.mode.as_str(),
+            "team": self.team_to_json(),
+            "blitz": self.blitz_to_json(),
+            "achievements": self.achievements.to_json(),
+            "ends_at": self.ends_at.map(|d| d.to_rfc3339()),
+            "countdown_seconds": self.countdown_remaining(chrono::Utc::now()),
+            "pause_reason": self.pause_reason,
+            "cancel_reason": self.cancel_reason.as_ref().map(|r| r.to_json()),
+            "eliminated": self.eliminated,
+            "in_overtime": self.in_overtime(),
+            "overtime_rounds": self.overtime_rounds,
+            "used_words": self.used_words.iter().collect::<Vec<_>>(),
+            "moves": self.moves.iter().map(|m| m.to_json()).collect::<Vec<_>>(),
+            "stats": self.stats.as_ref().map(|s| s.to_json()),
+            "timer_vote": self.timer_vote.to_json(),
+            "kick_vote": self.kick_vote.to_json(),
+            "challenge": self.challenge.to_json(),
+            "chat": self.chat.to_json(),
+            "chat_policy": self.config.chat.as_str(),
+            "letter_weighting": self.letter_weighting.as_ref().map(|w| w.to_json()),
+            "locale": self.locale,
+            "event_seq": self.events.last_seq()
+        })
+    }
+
+    /// Get the redaction profile for a viewer of this game.
+    ///
+    /// The game doesn't know who hosts its lobby; use
+    /// `AppState::game_profile_for` to get `Host` for the lobby host.
+    pub fn profile_for(&self, viewer_id: i64) -> RedactionProfile {
+        if self.players.contains_key(&viewer_id) {
+            RedactionProfile::Player
+        } else if self.spectators.contains_key(&viewer_id) {
+            RedactionProfile::Spectator
+        } else {
+            RedactionProfile::Public
+        }
+    }
+
+    /// Convert game state to JSON as seen by an audience.
+    ///
+    /// Spectator chat is only included for spectators while the game is
+    /// running, and for everyone once it has ended. Only the host and admins
+    /// see who is spectating; everyone else gets `spectator_count`.
+    pub fn to_json_for(&self, profile: RedactionProfile) -> serde_json::Value {
+        let mut json = self.to_json();
+        if !profile.shows_spectator_identities() {
+            redaction::remove_keys(&mut json, &["spectators"]);
+        }
+        if profile.shows_spectator_chat() || self.status.is_terminal() {
+            json["spectator_chat"] = self.spectator_chat.to_json();
+        }
+        if !profile.shows_user_ids() {
+            redaction::remove_keys_in(&mut json, "players", &["user_id"]);
+            redaction::remove_keys_in(&mut json, "spectators", &["user_id"]);
+            redaction::remove_keys_in(&mut json, "chat", &["user_id"]);
+            redaction::remove_keys_in(&mut json, "spectator_chat", &["user_id"]);
+            redaction::remove_keys(&mut json, &["current_turn"]);
+        }
+        json
+    }
+}
+
+/// Game errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    NotFound,
+    InvalidStatus,
+    AlreadyPlayer,
+    NotPlayer,
+    AlreadySpectator,
+    NotSpectator,
+    NotYourTurn,
+    GameStarted,
+    GameNotActive,
+    NotEnoughPlayers,
+    TooManyPlayers,
+    WordUsed,
+    NotAWord,
+    InvalidPath,
+    PathTooShort,
+    PassLimitReached,
+    SubmissionCooldown { retry_at: chrono::DateTime<chrono::Utc> },
+    SubmissionLimitReached,
+    GridQualityTooLow,
+    ScoreMismatch { player_id: i64 },
+    InvalidTurnOrder,
+    ChatNotAllowed,
+    TurnExtensionUnavailable,
+    TurnExtensionLimitReached,
+    NotEnoughGems,
+    PowerUpUnavailable,
+    InvalidPowerUpTarget,
+    VoteInProgress,
+    NoVoteInProgress,
+    AlreadyVoted,
+    CannotVoteOnSelf,
+    VoteCooldown { retry_at: chrono::DateTime<chrono::Utc> },
+    TimerAlreadyActive,
+    Forfeited,
+    NotBot,
+    InvalidGrid,
+    InvalidGridConfig,
+    TooManySpectators,
+    Overtime,
+    WrongMode,
+    ChallengeUnavailable,
+    ChallengeInProgress,
+    NoChallengeInProgress,
+    Chat(ChatError),
+    Manager(GameManagerError),
+}
+
+impl std::fmt::Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "Game not found"),
+            Self::InvalidStatus => write!(f, "Invalid game status for this action"),
+            Self::AlreadyPlayer => write!(f, "Already a player in this game"),
+            Self::NotPlayer => write!(f, "Not a player in this game"),
+            Self::AlreadySpectator => write!(f, "Already spectating this game"),
+            Self::NotSpectator => write!(f, "Not spectating this game"),
+            Self::NotYourTurn => write!(f, "It's not your turn"),
+            Self::GameStarted => write!(f, "Game has already started"),
+            Self::GameNotActive => write!(f, "Game is not active"),
+            Self::NotEnoughPlayers => write!(f, "Not enough players to start"),
+            Self::TooManyPlayers => write!(f, "Too many players"),
+            Self::WordUsed => write!(f, "Word has already been used"),
+            Self::NotAWord => write!(f, "Not a valid word"),
+            Self::InvalidPath => write!(f, "Invalid tile path"),
+            Self::PathTooShort => write!(f, "Path too short"),
+            Self::PassLimitReached => write!(f, "No passes remaining"),
+            Self::SubmissionCooldown { .. } => write!(f, "Submitting too quickly"),
+            Self::SubmissionLimitReached => write!(f, "No submissions left this round"),
+            Self::GridQualityTooLow => write!(f, "Board has too few playable words"),
+            Self::ScoreMismatch { .. } => write!(f, "Score doesn't match its audit trail"),
+            Self::InvalidTurnOrder => write!(f, "Turn order must list every player once"),
+            Self::ChatNotAllowed => write!(f, "Chat is closed right now"),
+            Self::TurnExtensionUnavailable => write!(f, "This turn can't be extended"),
+            Self::TurnExtensionLimitReached => write!(f, "No more turn extensions allowed"),
+            Self::NotEnoughGems => write!(f, "Not enough gems"),
+            Self::PowerUpUnavailable => write!(f, "That power-up can't be used right now"),
+            Self::InvalidPowerUpTarget => write!(f, "Invalid power-up target"),
+            Self::VoteInProgress => write!(f, "A vote is already in progress"),
+            Self::NoVoteInProgress => write!(f, "There is no vote to join"),
+            Self::AlreadyVoted => write!(f, "You've already voted"),
+            Self::CannotVoteOnSelf => write!(f, "You can't vote on yourself"),
+            Self::VoteCooldown { .. } => write!(f, "Too soon for another vote"),
+            Self::TimerAlreadyActive => write!(f, "A timer is already running"),
+            Self::Forfeited => write!(f, "Player has forfeited"),
+            Self::NotBot => write!(f, "Player is not a bot"),
+            Self::InvalidGrid => write!(f, "Invalid board encoding"),
+            Self::InvalidGridConfig => write!(f, "Board settings can't all be satisfied"),
+            Self::TooManySpectators => write!(f, "This game has no room for more spectators"),
+            Self::Overtime => write!(f, "The lead is tied; the game continues in overtime"),
+            Self::WrongMode => write!(f, "Not available in this game mode"),
+            Self::ChallengeUnavailable => write!(f, "There's no play to challenge"),
+            Self::ChallengeInProgress => write!(f, "A word challenge is in progress"),
+            Self::NoChallengeInProgress => write!(f, "No word challenge in progress"),
+            Self::Chat(e) => write!(f, "{}", e),
+            Self::Manager(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl GameError {
+    /// Stable identifier clients use to look up a localized message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound => "game_not_found",
+            Self::InvalidStatus => "game_invalid_status",
+            Self::AlreadyPlayer => "game_already_player",
+            Self::NotPlayer => "game_not_player",
+            Self::AlreadySpectator => "game_already_spectator",
+            Self::NotSpectator => "game_not_spectator",
+            Self::NotYourTurn => "game_not_your_turn",
+            Self::GameStarted => "game_started",
+            Self::GameNotActive => "game_not_active",
+            Self::NotEnoughPlayers => "game_not_enough_players",
+            Self::TooManyPlayers => "game_too_many_players",
+            Self::WordUsed => "game_word_used",
+            Self::NotAWord => "game_not_a_word",
+            Self::InvalidPath => "game_invalid_path",
+            Self::PathTooShort => "game_path_too_short",
+            Self::PassLimitReached => "game_pass_limit_reached",
+            Self::SubmissionCooldown { .. } => "game_submission_cooldown",
+            Self::SubmissionLimitReached => "game_submission_limit_reached",
+            Self::GridQualityTooLow => "game_grid_quality_too_low",
+            Self::ScoreMismatch { .. } => "game_score_mismatch",
+            Self::InvalidTurnOrder => "game_invalid_turn_order",
+            Self::ChatNotAllowed => "game_chat_not_allowed",
+            Self::TurnExtensionUnavailable => "game_turn_extension_unavailable",
+            Self::TurnExtensionLimitReached => "game_turn_extension_limit_reached",
+            Self::NotEnoughGems => "game_not_enough_gems",
+            Self::PowerUpUnavailable => "game_power_up_unavailable",
+            Self::InvalidPowerUpTarget => "game_invalid_power_up_target",
+            Self::VoteInProgress => "game_vote_in_progress",
+            Self::NoVoteInProgress => "game_no_vote_in_progress",
+            Self::AlreadyVoted => "game_already_voted",
+            Self::CannotVoteOnSelf => "game_cannot_vote_on_self",
+            Self::VoteCooldown { .. } => "game_vote_cooldown",
+            Self::TimerAlreadyActive => "game_timer_already_active",
+            Self::Forfeited => "game_forfeited",
+            Self::NotBot => "game_not_bot",
+            Self::InvalidGrid => "game_invalid_grid",
+            Self::InvalidGridConfig => "game_invalid_grid_config",
+            Self::TooManySpectators => "game_too_many_spectators",
+            Self::Overtime => "game_overtime",
+            Self::WrongMode => "game_wrong_mode",
+            Self::ChallengeUnavailable => "game_challenge_unavailable",
+            Self::ChallengeInProgress => "game_challenge_in_progress",
+            Self::NoChallengeInProgress => "game_no_challenge_in_progress",
+            Self::Chat(e) => e.code(),
+            Self::Manager(e) => e.code(),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl From<ChatError> for GameError {
+    fn from(e: ChatError) -> Self {
+        Self::Chat(e)
+    }
+}
+
+impl From<GameManagerError> for GameError {
+    fn from(e: GameManagerError) -> Self {
+        Self::Manager(e)
+    }
+}
+
+/// Game manager errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameManagerError {
+    /// The manager holds its maximum number of games and none could be
+    /// evicted
+    AtCapacity { limit: usize },
+    /// A game with the same ID (or a daily challenge for the same date) is
+    /// already tracked
+    AlreadyExists,
+}
+
+impl std::fmt::Display for GameManagerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AtCapacity { .. } => write!(f, "Too many games are running, try again later"),
+            Self::AlreadyExists => write!(f, "A game with this ID already exists"),
+        }
+    }
+}
+
+impl GameManagerError {
+    /// Stable identifier clients use to look up a localized message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::AtCapacity { .. } => "game_manager_at_capacity",
+            Self::AlreadyExists => "game_manager_already_exists",
+        }
+    }
+}
+
+impl std::error::Error for GameManagerError {}
+
+/// What `GameManager::add` does when the manager is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EvictionPolicy {
+    /// Refuse the new game
+    #[default]
+    Reject,
+    /// Remove (and archive) the terminal game that ended longest ago, and
+    /// refuse only if no game is terminal
+    OldestTerminal,
+}
+
+/// Overview of a game for listings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameSummary {
+    pub game_id: String,
+    pub lobby_id: String,
+    pub status: GameStatus,
+    pub player_count: usize,
+    pub spectator_count: usize,
+    pub round: u8,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl GameSummary {
+    pub fn from_game(game: &Game) -> Self {
+        Self {
+            game_id: game.id.clone(),
+            lobby_id: game.lobby_id.clone(),
+            status: game.status,
+            player_count: game.player_count(),
+            spectator_count: game.spectator_count(),
+            round: game.round,
+            created_at: game.created_at,
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "game_id": self.game_id,
+            "lobby_id": self.lobby_id,
+            "status": self.status.as_str(),
+            "player_count": self.player_count,
+            "spectator_count": self.spectator_count,
+            "round": self.round,
+            "created_at": self.created_at.to_rfc3339()
+        })
+    }
+}
+
+/// Game manager - tracks all active games.
+#[derive(Debug, Default)]
+pub struct GameManager {
+    games: HashMap<String, Game>,
+    /// Player ID to game ID
+    player_index: HashMap<i64, String>,
+    /// Spectator ID to game ID
+    spectator_index: HashMap<i64, String>,
+    /// Player ID to solo game ID, kept apart from `player_index`
+    solo_index: HashMap<i64, String>,
+    /// Lobby ID to game IDs, oldest first
+    lobby_index: HashMap<String, Vec<String>>,
+    /// Summaries of removed terminal games
+    archive: GameArchive,
+    /// Daily challenges by date
+    daily_challenges: HashMap<chrono::NaiveDate, DailyChallenge>,
+    /// Most games held at once (None = unlimited)
+    capacity: Option<usize>,
+    eviction: EvictionPolicy,
+}
+
+impl GameManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold at most `limit` games at once.
+    pub fn with_capacity(mut self, limit: usize) -> Self {
+        self.capacity = Some(limit);
+        self
+    }
+
+    /// Set what happens when adding to a full manager.
+    pub fn with_eviction(mut self, eviction: EvictionPolicy) -> Self {
+        self.eviction = eviction;
+        self
+    }
+
+    /// Add a game.
+    ///
+    /// Fails with `AlreadyExists` if the ID is taken. At capacity, the
+    /// eviction policy may make room by removing a terminal game; otherwise
+    /// this fails with `AtCapacity`.
+    pub fn add(&mut self, game: Game) -> Result<(), GameManagerError> {
+        if self.games.contains_key(&game.id) {
+            return Err(GameManagerError::AlreadyExists);
+        }
+        if let Some(limit) = self.capacity {
+            if self.games.len() >= limit && !self.evict() {
+                return Err(GameManagerError::AtCapacity { limit });
+            }
+        }
+
+        // Index players
+        let index = if game.config.mode.is_solo() {
+            &mut self.solo_index
+        } else {
+            &mut self.player_index
+        };
+        for player_id in game.players.keys() {
+            index.insert(*player_id, game.id.clone());
+        }
+        self.lobby_index
+            .entry(game.lobby_id.clone())
+            .or_default()
+            .push(game.id.clone());
+        self.games.insert(game.id.clone(), game);
+        Ok(())
+    }
+
+    /// Remove a game under the eviction policy. Returns true if one was
+    /// removed.
+    fn evict(&mut self) -> bool {
+        if self.eviction != EvictionPolicy::OldestTerminal {
+            return false;
+        }
+        let oldest = self
+            .games
+            .values()
+            .filter(|g| g.status.is_terminal())
+            .min_by_key(|g| (g.ended_at, g.created_at))
+            .map(|g| g.id.clone());
+        oldest.is_some_and(|id| self.remove(&id).is_some())
+    }
+
+    /// Maximum number of games held at once, if limited.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Get a game.
+    pub fn get(&self, game_id: &str) -> Option<&Game> {
+        self.games.get(game_id)
+    }
+
+    /// Get a mutable game.
+    pub fn get_mut(&mut self, game_id: &str) -> Option<&mut Game> {
+        self.games.get_mut(game_id)
+    }
+
+    /// Get game for a player.
+    pub fn get_for_player(&self, player_id: i64) -> Option<&Game> {
+        self.player_index
+            .get(&player_id)
+            .and_then(|id| self.games.get(id))
+    }
+
+    /// Get mutable game for a player.
+    pub fn get_for_player_mut(&mut self, player_id: i64) -> Option<&mut Game> {
+        let id = self.player_index.get(&player_id)?.clone();
+        self.games.get_mut(&id)
+    }
+
+    /// Get a player's solo game.
+    pub fn get_solo_for_player(&self, player_id: i64) -> Option<&Game> {
+        self.solo_index
+            .get(&player_id)
+            .and_then(|id| self.games.get(id))
+    }
+
+    /// Get a player's mutable solo game.
+    pub fn get_solo_for_player_mut(&mut self, player_id: i64) -> Option<&mut Game> {
+        let id = self.solo_index.get(&player_id)?.clone();
+        self.games.get_mut(&id)
+    }
+
+    /// Get game for a spectator, including players eliminated from the game
+    /// they are in.
+    pub fn get_for_spectator(&self, player_id: i64) -> Option<&Game> {
+        let id = self
+            .spectator_index
+            .get(&player_id)
+            .or_else(|| self.player_index.get(&player_id))?;
+        self.games.get(id).filter(|g| g.spectators.contains_key(&player_id))
+    }
+
+    /// Create a rematch of a finished or cancelled game.
+    ///
+    /// The new game is in the same lobby with the same players (scores, gems
+    /// and words reset), config, rounds, locale and dictionary. Turn order is
+    /// kept, or rotated by one if `config.rotate_rematch_order` is set. The
+    /// RNG is seeded from the finished game's, so seeded games get
+    /// reproducible rematches. Players are indexed to the new game.
+    pub fn create_rematch(
+        &mut self,
+        finished_game_id: &str,
+        new_game_id: String,
+        grid: Grid,
+    ) -> Result<&mut Game, GameError> {
+        let old = self.games.get(finished_game_id).ok_or(GameError::NotFound)?;
+        if !old.status.is_terminal() {
+            return Err(GameError::InvalidStatus);
+        }
+
+        // Eliminated players come back, the last one out first
+        let mut order = old.turn_order.clone();
+        order.extend(old.eliminated.iter().rev());
+        if old.config.rotate_rematch_order && !order.is_empty() {
+            order.rotate_left(1);
+        }
+        let mut game = Game::new(new_game_id.clone(), old.lobby_id.clone(), grid)
+            .with_seed(SeededRng::new(old.rng_seed()).next_u64())
+            .with_config(old.config.clone())
+            .with_locale(old.locale.clone());
+        game.max_rounds = old.max_rounds;
+        game.dictionary = old.dictionary.clone();
+        for (index, player_id) in order.iter().enumerate() {
+            let Some(p) = old.players.get(player_id) else {
+                continue;
+            };
+            let mut player = GamePlayer::new(
+                p.player_id,
+                p.user_id.clone(),
+                p.username.clone(),
+                p.avatar_url.clone(),
+                index as u8,
+            );
+            player.rating = p.rating;
+            player.is_connected = p.is_connected;
+            player.is_bot = p.is_bot;
+            game.add_player(player)?;
+        }
+
+        self.add(game)?;
+        self.games.get_mut(&new_game_id).ok_or(GameError::NotFound)
+    }
+
+    /// Remove a player from their game on behalf of an admin.
+    ///
+    /// See [`Game::remove_player`].
+    pub fn remove_player(&mut self, player_id: i64, by: i64) -> Result<GamePlayer, GameError> {
+        let game = self
+            .get_for_player_mut(player_id)
+            .ok_or(GameError::NotPlayer)?;
+        let player = game.remove_player(player_id, by)?;
+        self.player_index.remove(&player_id);
+        Ok(player)
+    }
+
+    /// Start a kick vote in the initiator's game, unindexing the target if
+    /// it passes straight away.
+    ///
+    /// See [`Game::start_kick_vote`].
+    pub fn start_kick_vote(
+        &mut self,
+        initiator_id: i64,
+        target_id: i64,
+    ) -> Result<Option<GamePlayer>, GameError> {
+        let game = self
+            .get_for_player_mut(initiator_id)
+            .ok_or(GameError::NotPlayer)?;
+        let kicked = game.start_kick_vote(initiator_id, target_id)?;
+        if let Some(player) = &kicked {
+            self.player_index.remove(&player.player_id);
+        }
+        Ok(kicked)
+    }
+
+    /// Vote in the open kick vote of the voter's game, unindexing the
+    /// target if it passes.
+    ///
+    /// See [`Game::add_kick_vote`].
+    pub fn add_kick_vote(&mut self, voter_id: i64) -> Result<Option<GamePlayer>, GameError> {
+        let game = self
+            .get_for_player_mut(voter_id)
+            .ok_or(GameError::NotPlayer)?;
+        let kicked = game.add_kick_vote(voter_id)?;
+        if let Some(player) = &kicked {
+            self.player_index.remove(&player.player_id);
+        }
+        Ok(kicked)
+    }
+
+    /// Turn a spectator of a game into a player and index them as one.
+    ///
+    /// See [`Game::promote_spectator`].
+    pub fn promote_spectator(
+        &mut self,
+        game_id: &str,
+        player_id: i64,
+        starting_score: StartingScore,
+    ) -> Result<serde_json::Value, GameError> {
+        let game = self.games.get_mut(game_id).ok_or(GameError::NotFound)?;
+        let snapshot = game.promote_spectator(player_id, starting_score)?;
+        self.spectator_index.remove(&player_id);
+        self.player_index.insert(player_id, game_id.to_string());
+        Ok(snapshot)
+    }
+
+    /// Remove a game.
+    pub fn remove(&mut self, game_id: &str) -> Option<Game> {
+        let game = self.games.remove(game_id)?;
+
+        // Clean up indexes, leaving entries that already point at another
+        // game (e.g. a rematch)
+        let index = if game.config.mode.is_solo() {
+            &mut self.solo_index
+        } else {
+            &mut self.player_index
+        };
+        for player_id in game.players.keys() {
+            if index.get(player_id).is_some_and(|id| id == game_id) {
+                index.remove(player_id);
+            }
+        }
+        for spectator_id in game.spectators.keys() {
+            if self.spectator_index.get(spectator_id).is_some_and(|id| id == game_id) {
+                self.spectator_index.remove(spectator_id);
+            }
+        }
+        if let Some(ids) = self.lobby_index.get_mut(&game.lobby_id) {
+            ids.retain(|id| id != game_id);
+            if ids.is_empty() {
+                self.lobby_index.remove(&game.lobby_id);
+            }
+        }
+        if game.status.is_terminal() {
+            self.archive.push(ArchivedGame::from_game(&game));
+        }
+        if let Some(challenge) = self
+            .daily_challenges
+            .values_mut()
+            .find(|c| c.has_game(game_id))
+        {
+            challenge.record(&game);
+        }
+
+        Some(game)
+    }
+
+    /// Register the daily challenge for a date.
+    pub fn register_daily_challenge(
+        &mut self,
+        date: chrono::NaiveDate,
+        seed: u64,
+    ) -> Result<&DailyChallenge, GameError> {
+        if self.daily_challenges.contains_key(&date) {
+            return Err(GameManagerError::AlreadyExists.into());
+        }
+        Ok(self
+            .daily_challenges
+            .entry(date)
+            .or_insert_with(|| DailyChallenge::new(date, seed)))
+    }
+
+    /// Get the daily challenge for a date.
+    pub fn daily_challenge(&self, date: chrono::NaiveDate) -> Option<&DailyChallenge> {
+        self.daily_challenges.get(&date)
+    }
+
+    /// Create a game for a date's challenge, on the challenge board and
+    /// seeded with the challenge seed. Players are added as usual.
+    pub fn create_challenge_game(
+        &mut self,
+        date: chrono::NaiveDate,
+        game_id: String,
+        lobby_id: String,
+    ) -> Result<&mut Game, GameError> {
+        let challenge = self
+            .daily_challenges
+            .get(&date)
+            .ok_or(GameError::NotFound)?;
+
+        let game =
+            Game::new(game_id.clone(), lobby_id, challenge.grid()).with_seed(challenge.seed);
+        self.add(game)?;
+        if let Some(challenge) = self.daily_challenges.get_mut(&date) {
+            challenge.add_game(game_id.clone());
+        }
+        self.games.get_mut(&game_id).ok_or(GameError::NotFound)
+    }
+
+    /// Best score per player across a date's challenge games, highest first.
+    ///
+    /// Covers both removed games and finished games not yet cleaned up.
+    pub fn daily_leaderboard(&self, date: chrono::NaiveDate) -> Option<Vec<ChallengeScore>> {
+        let mut challenge = self.daily_challenges.get(&date)?.clone();
+        for game_id in self.daily_challenges[&date].game_ids() {
+            if let Some(game) = self.games.get(game_id) {
+                challenge.record(game);
+            }
+        }
+        Some(challenge.leaderboard())
+    }
+
+    /// Get the archive of removed terminal games.
+    pub fn archive(&self) -> &GameArchive {
+        &self.archive
+    }
+
+    /// Get the summary of a removed terminal game.
+    pub fn get_archived(&self, game_id: &str) -> Option<&ArchivedGame> {
+        self.archive.get(game_id)
+    }
+
+    /// Get up to `n` result summaries for a lobby, most recent first.
+    ///
+    /// Covers both archived games and terminal games not yet cleaned up.
+    pub fn recent_results(&self, lobby_id: &str, n: usize) -> Vec<ArchivedGame> {
+        let mut results: Vec<ArchivedGame> = self
+            .history_for_lobby(lobby_id, usize::MAX)
+            .into_iter()
+            .filter(|g| g.status.is_terminal())
+            .map(ArchivedGame::from_game)
+            .chain(self.archive.for_lobby(lobby_id, n).into_iter().cloned())
+            .collect();
+        results.sort_by_key(|g| std::cmp::Reverse(g.ended_at));
+        results.truncate(n);
+        results
+    }
+
+    /// Get the most recently added game for a lobby.
+    pub fn latest_for_lobby(&self, lobby_id: &str) -> Option<&Game> {
+        self.lobby_index
+            .get(lobby_id)
+            .and_then(|ids| ids.last())
+            .and_then(|id| self.games.get(id))
+    }
+
+    /// Get up to `n` games for a lobby, most recent first.
+    pub fn history_for_lobby(&self, lobby_id: &str, n: usize) -> Vec<&Game> {
+        self.lobby_index
+            .get(lobby_id)
+            .map(|ids| {
+                ids.iter()
+                    .rev()
+                    .filter_map(|id| self.games.get(id))
+                    .take(n)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Tick every game and expire overdue turns. Returns IDs of games that
+    /// started, ended or whose turn advanced.
+    pub fn tick(&mut self, now: chrono::DateTime<chrono::Utc>) -> Vec<String> {
+        self.games
+            .values_mut()
+            .filter_map(|game| {
+                let started = game.tick(now);
+                let expired = game.expire_turn(now).is_some();
+                (started || expired).then(|| game.id.clone())
+            })
+            .collect()
+    }
+
+    /// Move finished games into the archive. Returns their ids, with the
+    /// reason for cancelled ones.
+    pub fn cleanup_finished(&mut self) -> Vec<(String, Option<CancelReason>)> {
+        let finished: Vec<(String, Option<CancelReason>)> = self
+            .games
             .iter()
-            .map(|row| {
-                let cells: Vec<serde_json::Value> =
-                    row.iter().map(|c| c.to_json()).collect();
-                serde_json::Value::Array(cells)
+            .filter(|(_, g)| g.status.is_terminal())
+            .map(|(id, g)| (id.clone(), g.cancel_reason.clone()))
+            .collect();
+
+        for (id, _) in &finished {
+            self.remove(id);
+        }
+
+        finished
+    }
+
+    /// Count active games.
+    pub fn active_count(&self) -> usize {
+        self.games.values().filter(|g| g.status.is_active()).count()
+    }
+
+    /// Total game count.
+    pub fn count(&self) -> usize {
+        self.games.len()
+    }
+
+    /// Iterate over all games.
+    pub fn iter(&self) -> impl Iterator<Item = &Game> {
+        self.games.values()
+    }
+
+    /// Iterate over a lobby's games, oldest first.
+    pub fn games_in_lobby<'a>(&'a self, lobby_id: &str) -> impl Iterator<Item = &'a Game> {
+        self.lobby_index
+            .get(lobby_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.games.get(id))
+    }
+
+    /// Iterate over games with a status.
+    pub fn games_with_status(&self, status: GameStatus) -> impl Iterator<Item = &Game> {
+        self.games.values().filter(move |g| g.status == status)
+    }
+
+    /// Iterate over games that started before `ts`.
+    pub fn games_started_before(
+        &self,
+        ts: chrono::DateTime<chrono::Utc>,
+    ) -> impl Iterator<Item = &Game> {
+        self.games
+            .values()
+            .filter(move |g| g.started_at.is_some_and(|at| at < ts))
+    }
+
+    /// List summaries of all games, oldest first.
+    pub fn summaries(&self, after: Option<&Cursor>, limit: usize) -> Page<GameSummary> {
+        let games = self.games.values().collect();
+        Page::from_items(games, after, limit, SortOrder::Ascending, |g: &&Game| {
+            Cursor::new(g.created_at, g.id.as_str())
+        })
+        .map(GameSummary::from_game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_grid() -> Grid {
+        let default_cell = || GridCell::new('A');
+        [
+            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
+            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
+            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
+            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
+            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
+        ]
+    }
+
+    fn make_player(id: i64, turn_order: u8) -> GamePlayer {
+        GamePlayer::new(
+            id,
+            format!("{}", id * 1000),
+            format!("Player{}", id),
+            None,
+            turn_order,
+        )
+    }
+
+    #[test]
+    fn test_game_new() {
+        let game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        assert_eq!(game.status, GameStatus::Idle);
+        assert_eq!(game.round, 1);
+        assert!(game.players.is_empty());
+    }
+
+    #[test]
+    fn test_end_ranks_by_score() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for (id, score) in [(1, 4), (2, 9), (3, 6)] {
+            let mut player = make_player(id, id as u8 - 1);
+            player.add_score(ScoreSource::Word("AAA".to_string()), score);
+            game.add_player(player).unwrap();
+        }
+        game.start().unwrap();
+
+        let result = game.end().unwrap();
+        let ranked: Vec<(i64, i32)> =
+            result.entries.iter().map(|e| (e.player_id, e.score)).collect();
+        assert_eq!(ranked, vec![(2, 9), (3, 6), (1, 4)]);
+        assert_eq!(result.winners, vec![2]);
+        assert_eq!(game.status, GameStatus::Finished);
+    }
+
+    #[test]
+    fn test_game_add_players() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+
+        assert_eq!(game.player_count(), 2);
+        assert!(game.has_player(1));
+        assert!(game.has_player(2));
+    }
+
+    #[test]
+    fn test_game_start() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+
+        game.start().unwrap();
+
+        assert_eq!(game.status, GameStatus::InProgress);
+        assert!(game.current_player_id().is_some());
+    }
+
+    #[test]
+    fn test_validate_path() {
+        let mut grid = make_grid();
+        grid[0][1] = GridCell::new('C');
+        grid[1][2] = GridCell::new('T');
+        grid[3][3] = GridCell::new('A').with_blocked();
+        let game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid);
+        let p = Position::new;
+
+        assert_eq!(game.validate_path(&[p(0, 1), p(0, 0), p(1, 1), p(1, 2)]).unwrap(), "CAAT");
+        assert_eq!(game.validate_path(&[p(0, 0), p(0, 1)]), Err(GameError::PathTooShort));
+        assert_eq!(game.score_path(&[p(0, 1), p(0, 0), p(1, 1)]).unwrap().points, 5);
+        // Not adjacent, reused cell, off the board, blocked cell
+        for path in [
+            [p(0, 0), p(0, 1), p(2, 2)],
+            [p(0, 0), p(0, 1), p(0, 0)],
+            [p(4, 3), p(4, 4), p(4, 5)],
+            [p(2, 2), p(3, 3), p(4, 4)],
+        ] {
+            assert_eq!(game.validate_path(&path), Err(GameError::InvalidPath));
+        }
+        assert_eq!(game.grid_to_json()[3][3]["blocked"], true);
+    }
+
+    #[test]
+    fn test_game_turns() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+
+        // Player 1's turn
+        assert!(game.is_player_turn(1));
+        assert!(!game.is_player_turn(2));
+
+        // Advance
+        game.advance_turn();
+
+        // Player 2's turn
+        assert!(!game.is_player_turn(1));
+        assert!(game.is_player_turn(2));
+
+        // Advance again - back to player 1, new round
+        let (_, round) = game.advance_turn();
+        assert!(game.is_player_turn(1));
+        assert_eq!(round, 2);
+    }
+
+    #[test]
+    fn test_game_pass_turn() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                max_passes_per_player: Some(1),
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+
+        // Can't pass before the game starts
+        assert_eq!(game.pass_turn(1), Err(GameError::GameNotActive));
+        game.start().unwrap();
+
+        // Only the current player may pass
+        assert_eq!(game.pass_turn(2), Err(GameError::NotYourTurn));
+        assert_eq!(game.pass_turn(1), Ok((2, 1)));
+        assert_eq!(game.pass_turn(2), Ok((1, 2)));
+
+        // Limit reached
+        assert_eq!(game.pass_turn(1), Err(GameError::PassLimitReached));
+
+        assert_eq!(game.get_player(1).unwrap().passes, 1);
+        assert_eq!(game.moves().len(), 2);
+        assert!(game.moves()[0].action.is_missed_turn());
+        let summary = game.round_summary(1);
+        assert_eq!(summary.pass_count(), 2);
+        assert_eq!(summary.passed_player_ids(), vec![1, 2]);
+        assert_eq!(game.round_summary(2).pass_count(), 0);
+    }
+
+    #[test]
+    fn test_pass_rounds_end_game() {
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('C');
+        grid[0][2] = GridCell::new('T');
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_config(GameConfig {
+                end_after_pass_rounds: Some(1),
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+
+        // A word in between breaks the streak
+        game.pass_turn(1).unwrap();
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        game.submit_word(2, &path).unwrap();
+        game.pass_turn(1).unwrap();
+        assert_eq!(game.consecutive_passes(), 1);
+        assert_eq!(game.status, GameStatus::InProgress);
+
+        game.pass_turn(2).unwrap();
+        assert_eq!(game.status, GameStatus::Finished);
+    }
+
+    #[test]
+    fn test_elimination() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                elimination: true,
+                max_spectators: Some(0),
+                ..Default::default()
+            });
+        for (player_id, turn_order) in [(1, 0), (2, 1), (3, 2)] {
+            game.add_player(make_player(player_id, turn_order)).unwrap();
+        }
+        game.start().unwrap();
+        let row = |len: usize| (0..len).map(|col| Position::new(0, col)).collect::<Vec<_>>();
+
+        game.submit_word(1, &row(3)).unwrap();
+        game.submit_word(2, &row(4)).unwrap();
+        game.pass_turn(3).unwrap();
+        assert_eq!(game.eliminated(), &[3]);
+        assert!(game.get_player(3).is_some());
+        assert!(game.is_player_turn(1));
+
+        // Seated as a spectator despite the cap, and announced
+        assert!(game.spectators().any(|s| s.player_id == 3));
+        assert!(game
+            .events_since(0)
+            .unwrap()
+            .iter()
+            .any(|e| e.event == GameEvent::SpectatorJoined { count: 1 }));
+        let mut manager = GameManager::new();
+        manager.add(game).unwrap();
+        assert_eq!(manager.get_for_spectator(3).unwrap().id, "game-1");
+        assert!(manager.get_for_spectator(1).is_none());
+        let game = manager.get_mut("game-1").unwrap();
+
+        // Round 2: 1 leads 8 to 4
+        game.submit_word(1, &row(5)).unwrap();
+        game.pass_turn(2).unwrap();
+        assert_eq!(game.eliminated(), &[3, 2]);
+        assert_eq!(game.status, GameStatus::Finished);
+        assert!(game.stats().is_some());
+    }
+
+    #[test]
+    fn test_start_countdown() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        assert_eq!(
+            game.begin_countdown(chrono::Duration::seconds(5)),
+            Err(GameError::NotEnoughPlayers)
+        );
+        game.add_player(make_player(1, 0)).unwrap();
+        game.begin_countdown(chrono::Duration::seconds(5)).unwrap();
+        assert_eq!(game.status, GameStatus::Starting);
+        let starts_at = game.starts_at.unwrap();
+        assert_eq!(game.countdown_remaining(starts_at - chrono::Duration::seconds(3)), Some(3));
+        assert_eq!(game.to_json()["status"], "starting");
+
+        game.cancel_countdown().unwrap();
+        assert_eq!(game.status, GameStatus::Idle);
+        assert!(game.to_json()["countdown_seconds"].is_null());
+
+        game.begin_countdown(chrono::Duration::seconds(5)).unwrap();
+        let starts_at = game.starts_at.unwrap();
+        let mut manager = GameManager::new();
+        manager.add(game).unwrap();
+        assert!(manager.tick(starts_at - chrono::Duration::seconds(1)).is_empty());
+        assert_eq!(manager.tick(starts_at), vec!["game-1"]);
+        let game = manager.get("game-1").unwrap();
+        assert_eq!(game.status, GameStatus::InProgress);
+        assert_eq!(game.starts_at, None);
+    }
+
+    #[test]
+    fn test_overtime() {
+        let config = GameConfig {
+            tie_break: TieBreak::Overtime { max_rounds: 2 },
+            ..Default::default()
+        };
+        let mut grid = make_grid();
+        for (row, letter) in [(1, 'E'), (2, 'I')] {
+            grid[row] = std::array::from_fn(|_| GridCell::new(letter));
+        }
+        let mut game =
+            Game::new("game-1".to_string(), "lobby-1".to_string(), grid).with_config(config);
+        game.max_rounds = 1;
+        for player_id in 1..=3 {
+            game.add_player(make_player(player_id, player_id as u8)).unwrap();
+        }
+        game.start().unwrap();
+        let row = |r: usize| [Position::new(r, 0), Position::new(r, 1), Position::new(r, 2)];
+
+        game.submit_word(1, &row(0)).unwrap();
+        game.submit_word(2, &row(1)).unwrap();
+        game.pass_turn(3).unwrap();
+        assert!(game.should_end());
+        assert_eq!(game.end().unwrap_err(), GameError::Overtime);
+        assert!(game.in_overtime() && !game.should_end());
+        assert_eq!(game.player_ids_in_order(), &[1, 2]);
+        assert_eq!(game.to_json()["overtime_rounds"], 1);
+
+        // Still tied: a second overtime round, then the cap is reached
+        game.pass_turn(1).unwrap();
+        game.pass_turn(2).unwrap();
+        assert_eq!(game.end().unwrap_err(), GameError::Overtime);
+        game.submit_word(1, &row(2)).unwrap();
+        game.pass_turn(2).unwrap();
+        let result = game.end().unwrap();
+        assert_eq!(result.winners, vec![1]);
+        assert!(!game.in_overtime());
+        assert_eq!(game.overtime_rounds(), 2);
+    }
+
+    #[test]
+    fn test_max_duration() {
+        let config = GameConfig {
+            max_duration: Some(chrono::Duration::hours(2)),
+            ..Default::default()
+        };
+        let game = || {
+            let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+                .with_config(config.clone());
+            game.add_player(make_player(1, 0)).unwrap();
+            game.add_player(make_player(2, 1)).unwrap();
+            game
+        };
+        let mut idle = game();
+        let deadline = idle.created_at + chrono::Duration::hours(2);
+        assert!(!idle.tick(deadline - chrono::Duration::seconds(1)));
+        assert!(idle.tick(deadline));
+        assert_eq!(idle.cancel_reason, Some(CancelReason::TimedOut));
+
+        let mut started = game();
+        started.start().unwrap();
+        started.pause("away").unwrap();
+        let deadline = started.created_at + chrono::Duration::hours(2);
+        let result = started.expire_duration(deadline).unwrap();
+        assert_eq!(result.entries.len(), 2);
+        assert_eq!(started.status, GameStatus::Finished);
+        assert!(started.expire_duration(deadline).is_none());
+    }
+
+    #[test]
+    fn test_game_abort() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+
+        game.abort(1).unwrap();
+        assert_eq!(game.status, GameStatus::Cancelled);
+        assert_eq!(
+            game.cancel_reason,
+            Some(CancelReason::AbortedBeforeStart { by: 1 })
+        );
+
+        // Can't abort once started
+        let mut game = Game::new("game-2".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
+        assert_eq!(game.abort(1), Err(GameError::GameStarted));
+    }
+
+    #[test]
+    fn test_spectator_chat_visibility() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_spectator(Spectator {
+            player_id: 9,
+            user_id: "9000".to_string(),
+            username: "Watcher".to_string(),
+            avatar_url: None,
+        })
+        .unwrap();
+        game.start().unwrap();
+
+        // Players can't post to the spectator channel
+        assert_eq!(
+            game.post_spectator_chat(1, "hi").unwrap_err(),
+            GameError::NotSpectator
+        );
+        game.post_spectator_chat(9, "nice board").unwrap();
+        assert_eq!(
+            game.post_spectator_chat(9, "spam").unwrap_err(),
+            GameError::Chat(ChatError::RateLimited)
+        );
+
+        assert!(game.to_json_for(game.profile_for(1)).get("spectator_chat").is_none());
+        assert_eq!(
+            game.to_json_for(game.profile_for(9))["spectator_chat"].as_array().unwrap().len(),
+            1
+        );
+
+        // Revealed to everyone after the game
+        game.end().unwrap();
+        assert!(game.to_json_for(game.profile_for(1)).get("spectator_chat").is_some());
+        let public = game.to_json_for(RedactionProfile::Public);
+        assert!(public["spectator_chat"][0].get("user_id").is_none());
+        assert_eq!(public["spectator_chat"][0]["text"], "nice board");
+    }
+
+    #[test]
+    fn test_game_chat_policies() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                chat: ChatPolicy::MutedOnTurn,
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.add_player(make_player(3, 2)).unwrap();
+
+        // Anyone may talk before the game starts
+        game.post_chat(1, "glhf").unwrap();
+        game.start().unwrap();
+
+        assert_eq!(game.post_chat(1, "hmm").unwrap_err(), GameError::ChatNotAllowed);
+        game.post_chat(2, "take your time").unwrap();
+        assert_eq!(game.post_chat(9, "hi").unwrap_err(), GameError::NotPlayer);
+
+        game.config.chat = ChatPolicy::BetweenTurns {
+            window: chrono::Duration::seconds(10),
+        };
+        game.turn_started_at = Some(chrono::Utc::now() - chrono::Duration::seconds(30));
+        assert_eq!(game.post_chat(2, "late").unwrap_err(), GameError::ChatNotAllowed);
+        game.advance_turn();
+        game.post_chat(3, "nice").unwrap();
+
+        assert_eq!(game.chat().len(), 3);
+        let events = game.events_since(0).unwrap();
+        assert!(events.iter().any(|e| e.event
+            == GameEvent::ChatPosted {
+                player_id: 3,
+                text: "nice".to_string()
+            }));
+        assert!(game.to_json_for(RedactionProfile::Public)["chat"][0].get("user_id").is_none());
+    }
+
+    #[test]
+    fn test_remove_player_turn_pointer() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                min_players: Some(2),
+                ..Default::default()
+            });
+        for (id, order) in [(1, 0), (2, 1), (3, 2)] {
+            game.add_player(make_player(id, order)).unwrap();
+        }
+        game.start().unwrap();
+        game.advance_turn();
+        game.advance_turn();
+
+        // Removing an earlier player keeps the turn with player 3
+        game.remove_player(1, 99).unwrap();
+        assert_eq!(game.current_player_id(), Some(3));
+        assert_eq!(game.status, GameStatus::InProgress);
+
+        // Dropping below the minimum cancels the game
+        game.remove_player(3, 99).unwrap();
+        assert_eq!(game.status, GameStatus::Cancelled);
+        assert_eq!(game.cancel_reason, Some(CancelReason::NotEnoughPlayers));
+        assert_eq!(game.to_json()["cancel_reason"]["type"], "not_enough_players");
+        assert_eq!(game.player_ids_in_order(), &[2]);
+        assert_eq!(game.remove_player(3, 99).unwrap_err(), GameError::InvalidStatus);
+
+        let mut manager = GameManager::new();
+        manager.add(game).unwrap();
+        assert_eq!(
+            manager.cleanup_finished(),
+            vec![("game-1".to_string(), Some(CancelReason::NotEnoughPlayers))]
+        );
+    }
+
+    #[test]
+    fn test_play_bot_turn() {
+        struct BadBot;
+        impl BotStrategy for BadBot {
+            fn pick_move(&self, _game: &Game, _player_id: i64) -> BotMove {
+                BotMove::PlayWord(vec![Position::new(0, 0)])
+            }
+        }
+
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        let mut bot = make_player(1, 0);
+        bot.is_bot = true;
+        game.add_player(bot).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+
+        assert_eq!(game.play_bot_turn(2, &BadBot), Err(GameError::NotBot));
+        // An invalid word becomes a pass
+        assert_eq!(game.play_bot_turn(1, &BadBot), Ok(BotMove::Pass));
+        assert_eq!(game.moves().last().unwrap().action, TurnAction::Pass);
+        assert_eq!(game.play_bot_turn(1, &BadBot), Err(GameError::NotYourTurn));
+    }
+
+    #[test]
+    fn test_forfeit() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for (id, order) in [(1, 0), (2, 1), (3, 2)] {
+            game.add_player(make_player(id, order)).unwrap();
+        }
+        assert_eq!(game.forfeit(1), Err(GameError::GameNotActive));
+        game.start().unwrap();
+        game.force_adjust_score(1, 10, "bonus", 99).unwrap();
+
+        // The turn moves on and the score is frozen
+        game.forfeit(1).unwrap();
+        assert!(game.is_player_turn(2));
+        assert_eq!(game.player_ids_in_order(), &[2, 3]);
+        assert_eq!(game.forfeit(1), Err(GameError::Forfeited));
+        assert_eq!(game.force_adjust_score(1, 5, "bonus", 99), Err(GameError::Forfeited));
+        assert_eq!(game.moves().last().unwrap().action, TurnAction::Forfeited);
+
+        // One player left ends the game, with the forfeit ranked last
+        game.forfeit(3).unwrap();
+        assert_eq!(game.status, GameStatus::Finished);
+        let result = game.result();
+        assert_eq!(result.winners, vec![2]);
+        assert!(result.entry(1).unwrap().forfeited);
+        let ranked: Vec<i64> = result.entries.iter().map(|e| e.player_id).collect();
+        assert_eq!(ranked, vec![2, 1, 3]);
+        assert!(game.stats().unwrap().player(1).unwrap().forfeited);
+    }
+
+    #[test]
+    fn test_adjust_score() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_replay_recording();
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
+
+        // Rule penalties go through the audit trail without a move
+        assert_eq!(game.adjust_score(1, -5, "invalid challenge"), Ok(-5));
+        let delta = game.get_player(1).unwrap().score_history().last().unwrap();
+        assert_eq!(delta.source, ScoreSource::Adjustment("invalid challenge".to_string()));
+        assert_eq!(delta.amount, -5);
+        assert!(game.moves().is_empty());
+        assert!(game.get_player(1).unwrap().verify_score().is_ok());
+        assert_eq!(game.adjust_score(2, 1, "nobody"), Err(GameError::NotPlayer));
+
+        let replay = game.export_replay().unwrap();
+        assert!(replay.to_json()["steps"][2]["by"].is_null());
+        assert_eq!(replay.reconstruct().get_player(1).unwrap().score, -5);
+    }
+
+    #[test]
+    fn test_admin_game_controls() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for (id, order) in [(1, 0), (2, 1), (3, 2)] {
+            game.add_player(make_player(id, order)).unwrap();
+        }
+        game.start().unwrap();
+
+        assert_eq!(game.force_skip_turn(99).unwrap(), (2, 1));
+        assert_eq!(game.force_adjust_score(3, 7, "dispute", 99).unwrap(), 7);
+
+        // Removing the current player hands the turn on
+        game.remove_player(2, 99).unwrap();
+        assert_eq!(game.current_player_id(), Some(3));
+        assert_eq!(game.player_ids_in_order(), &[1, 3]);
+
+        let actions: Vec<&TurnAction> = game.moves().iter().map(|m| &m.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                &TurnAction::ForceSkipped { by: 99 },
+                &TurnAction::ScoreAdjusted {
+                    amount: 7,
+                    reason: "dispute".to_string(),
+                    by: 99
+                },
+                &TurnAction::Removed { by: 99 },
+            ]
+        );
+
+        let result = game.force_end(99).unwrap();
+        assert_eq!(result.winners, vec![3]);
+        assert_eq!(result.entries[0].user_id, "3000");
+        assert_eq!(result.entries[0].score, 7);
+        assert_eq!(game.moves().last().unwrap().action, TurnAction::ForceEnded);
+    }
+
+    #[test]
+    fn test_pause_resume() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                turn_time_limit: Some(chrono::Duration::seconds(60)),
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        assert_eq!(game.pause("early"), Err(GameError::InvalidStatus));
+        game.start().unwrap();
+        let deadline = game.turn_deadline.unwrap();
+
+        game.pause("players dropped").unwrap();
+        assert_eq!(game.status, GameStatus::Paused);
+        assert_eq!(game.pause("again"), Err(GameError::InvalidStatus));
+        assert_eq!(game.submit_word(1, &[]), Err(GameError::GameNotActive));
+        assert_eq!(game.expire_turn(deadline), None);
+
+        // Pretend the pause lasted 45 seconds
+        game.paused_at = Some(game.paused_at.unwrap() - chrono::Duration::seconds(45));
+        game.resume().unwrap();
+        assert_eq!(game.status, GameStatus::InProgress);
+        assert!(game.turn_deadline.unwrap() >= deadline + chrono::Duration::seconds(45));
+        assert_eq!(game.resume(), Err(GameError::InvalidStatus));
+
+        // A paused game can still be ended
+        game.pause("admin").unwrap();
+        assert!(game.end().is_ok());
+        assert_eq!(game.pause_reason, None);
+    }
+
+    #[test]
+    fn test_skip_disconnected_players() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                turn_time_limit: Some(chrono::Duration::seconds(30)),
+                skip_disconnected_after: Some(1),
+                ..Default::default()
+            });
+        for (id, order) in [(1, 0), (2, 1), (3, 2)] {
+            game.add_player(make_player(id, order)).unwrap();
+        }
+        game.start().unwrap();
+        game.get_player_mut(2).unwrap().is_connected = false;
+
+        // First missed turn isn't skipped
+        assert_eq!(game.advance_to_next_connected().skipped, Vec::<i64>::new());
+        let later = chrono::Utc::now() + chrono::Duration::minutes(5);
+        assert_eq!(game.expire_turn(later), Some((3, 1)));
+        assert_eq!(game.get_player(2).unwrap().missed_turns, 1);
+        assert_eq!(game.get_player(2).unwrap().timeouts, 1);
+        let json = game.get_player(2).unwrap().to_json();
+        assert_eq!((json["timeouts"].clone(), json["turns_taken"].clone()), (1.into(), 1.into()));
+
+        game.advance_turn();
+        let advance = game.advance_to_next_connected();
+        assert_eq!((advance.player_id, advance.skipped), (3, vec![2]));
+        assert_eq!(game.get_player(2).unwrap().missed_turns, 2);
+
+        // Reconnected players get their turn back
+        game.get_player_mut(2).unwrap().is_connected = true;
+        game.advance_turn();
+        assert_eq!(game.advance_turn(), (2, 3));
+    }
+
+    #[test]
+    fn test_turn_timer() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+        assert_eq!(game.turn_deadline, None);
+
+        game.set_turn_duration(Some(chrono::Duration::seconds(30)));
+        let started = game.turn_started_at.unwrap();
+        assert_eq!(game.turn_deadline, Some(started + chrono::Duration::seconds(30)));
+
+        let mut manager = GameManager::new();
+        manager.add(game).unwrap();
+        assert!(manager.tick(started + chrono::Duration::seconds(29)).is_empty());
+        assert_eq!(manager.tick(started + chrono::Duration::seconds(30)), vec!["game-1"]);
+
+        let game = manager.get("game-1").unwrap();
+        assert!(game.is_player_turn(2));
+        assert_eq!(game.moves().last().unwrap().action, TurnAction::TimedOut);
+        assert_eq!(game.get_player(1).unwrap().passes, 0);
+    }
+
+    #[test]
+    fn test_timer_vote() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for (id, order) in [(1, 0), (2, 1), (3, 2), (4, 3)] {
+            game.add_player(make_player(id, order)).unwrap();
+        }
+        assert_eq!(game.start_timer_vote(2), Err(GameError::GameNotActive));
+        game.start().unwrap();
+
+        assert_eq!(game.start_timer_vote(1), Err(GameError::CannotVoteOnSelf));
+        assert_eq!(game.add_timer_vote(2), Err(GameError::NoVoteInProgress));
+        game.start_timer_vote(2).unwrap();
+        assert_eq!(game.start_timer_vote(3), Err(GameError::VoteInProgress));
+        assert_eq!(game.add_timer_vote(2), Err(GameError::AlreadyVoted));
+        assert_eq!(game.add_timer_vote(1), Err(GameError::CannotVoteOnSelf));
+        assert_eq!(game.turn_deadline, None);
+
+        // Two of the three other players is a majority
+        assert_eq!(game.add_timer_vote(3), Ok(true));
+        let TimerVoteState::TimerActive {
+            target_player_id,
+            expires_at,
+        } = game.timer_vote.clone()
+        else {
+            panic!("expected an active timer");
+        };
+        assert_eq!(target_player_id, 1);
+        assert_eq!(game.turn_deadline, Some(expires_at));
+        assert_eq!(game.add_timer_vote(4), Err(GameError::NoVoteInProgress));
+
+        game.pass_turn(1).unwrap();
+        assert!(matches!(game.timer_vote, TimerVoteState::Cooldown { .. }));
+        assert!(matches!(
+            game.start_timer_vote(3),
+            Err(GameError::VoteCooldown { .. })
+        ));
+        game.update_timer_vote(chrono::Utc::now() + chrono::Duration::minutes(5));
+        assert_eq!(game.timer_vote, TimerVoteState::Idle);
+
+        // The vote lapses if the target's turn ends first
+        game.start_timer_vote(3).unwrap();
+        game.pass_turn(2).unwrap();
+        assert_eq!(game.timer_vote, TimerVoteState::Idle);
+    }
+
+    #[test]
+    fn test_kick_vote() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for (id, order) in [(1, 0), (2, 1), (3, 2), (4, 3)] {
+            game.add_player(make_player(id, order)).unwrap();
+        }
+        game.start().unwrap();
+        let mut manager = GameManager::new();
+        manager.add(game).unwrap();
+
+        let err = |result: Result<Option<GamePlayer>, GameError>| result.err();
+        assert_eq!(err(manager.start_kick_vote(2, 2)), Some(GameError::CannotVoteOnSelf));
+        assert_eq!(err(manager.start_kick_vote(2, 9)), Some(GameError::NotPlayer));
+        assert!(manager.start_kick_vote(2, 1).unwrap().is_none());
+        assert_eq!(err(manager.start_kick_vote(3, 4)), Some(GameError::VoteInProgress));
+        assert_eq!(err(manager.add_kick_vote(2)), Some(GameError::AlreadyVoted));
+        assert_eq!(err(manager.add_kick_vote(1)), Some(GameError::CannotVoteOnSelf));
+        let json = manager.get("game-1").unwrap().to_json();
+        assert_eq!(json["kick_vote"]["target_player_id"], 1);
+        assert_eq!(json["kick_vote"]["votes_needed"], 2);
+
+        // Two of the three other players is a majority
+        let kicked = manager.add_kick_vote(3).unwrap().unwrap();
+        assert_eq!(kicked.player_id, 1);
+        assert!(manager.get_for_player(1).is_none());
+        let game = manager.get("game-1").unwrap();
+        assert!(game.is_player_turn(2));
+        assert_eq!(game.moves().last().unwrap().action, TurnAction::Kicked { votes: 2 });
+        assert!(matches!(
+            manager.start_kick_vote(3, 4),
+            Err(GameError::VoteCooldown { .. })
+        ));
+
+        let game = manager.get_mut("game-1").unwrap();
+        game.update_kick_vote(chrono::Utc::now() + chrono::Duration::minutes(5));
+        assert_eq!(game.kick_vote, KickVoteState::Idle);
+    }
+
+    #[test]
+    fn test_use_power_up() {
+        use crate::state::dictionary::Trie;
+
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('C').with_multiplier(Multiplier::DoubleLetter);
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_dictionary(Arc::new(Trie::from_words(["cab", "aaa"])))
+            .with_config(GameConfig {
+                turn_time_limit: Some(chrono::Duration::seconds(60)),
+                power_ups: Some(PowerUpConfig::default()),
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+        game.get_player_mut(1)
+            .unwrap()
+            .add_gems(ScoreSource::Bonus("test".to_string()), 6);
+
+        let swap = PowerUpTarget::Tile {
+            position: Position::new(0, 2),
+            letter: 'b',
+        };
+        assert_eq!(
+            game.use_power_up(1, PowerUp::SwapTile, PowerUpTarget::Player(2)),
+            Err(GameError::InvalidPowerUpTarget)
+        );
+        game.use_power_up(1, PowerUp::SwapTile, swap).unwrap();
+        assert_eq!(game.grid[0][2].letter, 'B');
+        assert_eq!(game.grid[0][0].multiplier, Some(Multiplier::DoubleLetter));
+
+        let freeze = PowerUpTarget::Player(2);
+        game.use_power_up(1, PowerUp::FreezeOpponentTimer, freeze).unwrap();
+        assert_eq!(
+            game.use_power_up(1, PowerUp::RevealWord, PowerUpTarget::None),
+            Err(GameError::NotEnoughGems)
+        );
+        assert_eq!(game.get_player(1).unwrap().gems, 1);
+        assert_eq!(game.power_ups_used().len(), 2);
+
+        // The frozen player's next turn is shorter
+        game.advance_turn();
+        let turn = game.turn_deadline.unwrap() - game.turn_started_at.unwrap();
+        assert_eq!(turn, chrono::Duration::seconds(45));
+
+        game.get_player_mut(2)
+            .unwrap()
+            .add_gems(ScoreSource::Bonus("test".to_string()), 4);
+        assert_eq!(
+            game.use_power_up(2, PowerUp::RevealWord, PowerUpTarget::None),
+            Ok(PowerUpEffect::WordRevealed {
+                word: "CAB".to_string(),
+                path: vec![Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)],
             })
-            .collect();
-        serde_json::Value::Array(rows)
+        );
     }
 
-    /// Convert full game state to JSON snapshot.
-    pub fn to_json(&self) -> serde_json::Value {
-        let players: Vec<serde_json::Value> =
-            self.turn_order
-                .iter()
-                .filter_map(|id| self.players.get(id))
-                .map(|p| p.to_json())
-                .collect();
+    #[test]
+    fn test_extend_turn() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                turn_time_limit: Some(chrono::Duration::seconds(60)),
+                turn_extensions: Some(TurnExtensionConfig {
+                    seconds_per_gem: 10,
+                    max_seconds_per_turn: Some(30),
+                    max_seconds_per_game: Some(40),
+                }),
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+        game.get_player_mut(1)
+            .unwrap()
+            .add_gems(ScoreSource::Bonus("test".to_string()), 4);
 
-        let spectators: Vec<serde_json::Value> =
-            self.spectators.values().map(|s| s.to_json()).collect();
+        let deadline = game.turn_deadline.unwrap();
+        assert_eq!(game.extend_turn(2, 10), Err(GameError::NotYourTurn));
+        assert_eq!(
+            game.extend_turn(1, 15).unwrap(),
+            deadline + chrono::Duration::seconds(15)
+        );
+        assert_eq!(game.get_player(1).unwrap().gems, 2);
+        assert_eq!(game.extend_turn(1, 20), Err(GameError::TurnExtensionLimitReached));
+        assert_eq!(
+            game.moves().last().unwrap().action,
+            TurnAction::ExtendTurn {
+                seconds: 15,
+                gems: 2
+            }
+        );
 
-        let current_turn = self.current_player().map(|p| p.user_id.as_str());
+        // Per-turn budget resets, the per-game one doesn't
+        game.advance_turn();
+        game.advance_turn();
+        assert_eq!(game.extend_turn(1, 30), Err(GameError::TurnExtensionLimitReached));
+        assert_eq!(game.extend_turn(1, 25), Err(GameError::NotEnoughGems));
+        game.extend_turn(1, 20).unwrap();
+        assert_eq!(game.get_player(1).unwrap().gems, 0);
+        game.get_player(1).unwrap().verify_score().unwrap();
 
-        serde_json::json!({
-            "game_id": self.id,
-            "lobby_id": self.lobby_id,
-            "status": self.status.as_str(),
-            "grid": self.grid_to_json(),
-            "players": players,
-            "spectators": spectators,
-            "current_turn": current_turn,
-            "round": self.round,
-            "max_rounds": self.max_rounds,
-            "used_words": self.used_words.iter().collect::<Vec<_>>(),
-            "timer_vote": self.timer_vote.to_json()
-        })
+        // Huge requests are refused rather than overflowing
+        game.config.turn_extensions = Some(TurnExtensionConfig {
+            seconds_per_gem: 1,
+            max_seconds_per_turn: None,
+            max_seconds_per_game: None,
+        });
+        assert_eq!(game.extend_turn(1, u32::MAX), Err(GameError::TurnExtensionLimitReached));
+        assert_eq!(game.get_player(1).unwrap().gems, 0);
     }
-}
 
-/// Game errors.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum GameError {
-    InvalidStatus,
-    AlreadyPlayer,
-    NotPlayer,
-    AlreadySpectator,
-    NotSpectator,
-    NotYourTurn,
-    GameStarted,
-    GameNotActive,
-    NotEnoughPlayers,
-    TooManyPlayers,
-    WordUsed,
-    InvalidPath,
-    PathTooShort,
-}
+    #[test]
+    fn test_reroll_bonuses() {
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('A').with_multiplier(Multiplier::DoubleWord);
+        grid[0][1] = GridCell::new('A').with_multiplier(Multiplier::TripleLetter);
+        grid[4][4] = GridCell::new('A').with_gem();
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_seed(5)
+            .with_config(GameConfig {
+                reroll: RerollConfig {
+                    multipliers: true,
+                    gems: true,
+                },
+                ..Default::default()
+            });
 
-impl std::fmt::Display for GameError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::InvalidStatus => write!(f, "Invalid game status for this action"),
-            Self::AlreadyPlayer => write!(f, "Already a player in this game"),
-            Self::NotPlayer => write!(f, "Not a player in this game"),
-            Self::AlreadySpectator => write!(f, "Already spectating this game"),
-            Self::NotSpectator => write!(f, "Not spectating this game"),
-            Self::NotYourTurn => write!(f, "It's not your turn"),
-            Self::GameStarted => write!(f, "Game has already started"),
-            Self::GameNotActive => write!(f, "Game is not active"),
-            Self::NotEnoughPlayers => write!(f, "Not enough players to start"),
-            Self::TooManyPlayers => write!(f, "Too many players"),
-            Self::WordUsed => write!(f, "Word has already been used"),
-            Self::InvalidPath => write!(f, "Invalid tile path"),
-            Self::PathTooShort => write!(f, "Path too short"),
+        let changes = game.reroll_bonuses();
+        let cells = || game.grid.iter().flatten();
+        assert_eq!(cells().filter(|c| c.multiplier.is_some()).count(), 2);
+        assert_eq!(cells().filter(|c| c.has_gem).count(), 1);
+        for change in &changes {
+            let cell = &game.grid[change.position.row][change.position.col];
+            assert_eq!(cell.multiplier, change.multiplier);
+            assert_eq!(cell.has_gem, change.has_gem);
         }
+        assert!(matches!(
+            game.events_since(0).unwrap().last().map(|e| &e.event),
+            Some(GameEvent::GridDelta { .. })
+        ));
+
+        // Disabled by default
+        let mut game = Game::new("game-2".to_string(), "lobby-1".to_string(), make_grid());
+        assert!(game.reroll_bonuses().is_empty());
+        assert_eq!(game.event_seq(), 0);
     }
-}
 
-impl std::error::Error for GameError {}
+    #[test]
+    fn test_turn_order_strategies() {
+        let players = || {
+            let mut players: Vec<GamePlayer> = (1..=4).map(|id| make_player(id, 0)).collect();
+            players[0].rating = Some(1500);
+            players[2].rating = Some(900);
+            players
+        };
+        let ids = |players: Vec<GamePlayer>| -> Vec<i64> {
+            players.iter().map(|p| p.player_id).collect()
+        };
 
-/// Game manager - tracks all active games.
-#[derive(Debug, Default)]
-pub struct GameManager {
-    games: HashMap<String, Game>,
-    /// Player ID to game ID
-    player_index: HashMap<i64, String>,
-    /// Spectator ID to game ID
-    spectator_index: HashMap<i64, String>,
-}
+        assert_eq!(ids(TurnOrderStrategy::JoinOrder.apply(players()).unwrap()), vec![1, 2, 3, 4]);
+        assert_eq!(ids(TurnOrderStrategy::ByRating.apply(players()).unwrap()), vec![2, 4, 3, 1]);
+
+        let random = TurnOrderStrategy::Random { seed: 7 };
+        let first = ids(random.apply(players()).unwrap());
+        assert_eq!(ids(random.apply(players()).unwrap()), first);
+
+        let custom = TurnOrderStrategy::Custom(vec![3, 1, 4, 2]);
+        let ordered = custom.apply(players()).unwrap();
+        assert_eq!(ordered[0].player_id, 3);
+        assert_eq!(ordered[3].turn_order, 3);
+        assert_eq!(
+            TurnOrderStrategy::Custom(vec![1, 2]).apply(players()).unwrap_err(),
+            GameError::InvalidTurnOrder
+        );
+    }
+
+    #[test]
+    fn test_set_and_shuffle_turn_order() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for player_id in 1..=3 {
+            game.add_player(make_player(player_id, 0)).unwrap();
+        }
+
+        game.set_turn_order(&[3, 1, 2]).unwrap();
+        assert_eq!(game.player_ids_in_order(), &[3, 1, 2]);
+        assert_eq!(game.get_player(3).unwrap().turn_order, 0);
+        for invalid in [&[3, 1][..], &[3, 1, 1], &[3, 1, 9]] {
+            assert_eq!(game.set_turn_order(invalid), Err(GameError::InvalidTurnOrder));
+        }
+
+        game.shuffle_turn_order(11).unwrap();
+        let shuffled = game.player_ids_in_order().to_vec();
+        let mut again = Game::new("game-2".to_string(), "lobby-1".to_string(), make_grid());
+        for player_id in [3, 1, 2] {
+            again.add_player(make_player(player_id, 0)).unwrap();
+        }
+        again.shuffle_turn_order(11).unwrap();
+        assert_eq!(again.player_ids_in_order(), shuffled.as_slice());
+
+        game.start().unwrap();
+        assert_eq!(game.current_player_id(), Some(shuffled[0]));
+        assert_eq!(game.set_turn_order(&shuffled), Err(GameError::GameStarted));
+        assert_eq!(game.shuffle_turn_order(1), Err(GameError::GameStarted));
+    }
+
+    #[test]
+    fn test_handicaps() {
+        use crate::state::scoring::ScoringBonus;
+
+        let mut config = GameConfig::default();
+        config.handicaps.insert(1, Handicap::Multiplier { percent: 200 });
+        config.handicaps.insert(2, Handicap::RoundBonus(3));
+        let mut game =
+            Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid()).with_config(config);
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        let played = game.submit_word(1, &path).unwrap();
+        assert_eq!(played.score.points, 6);
+        assert_eq!(played.score.bonuses, vec![(ScoringBonus::Handicap, 3)]);
+        game.pass_turn(2).unwrap();
+        assert_eq!(game.get_player(2).unwrap().score, 3);
+
+        let result = game.end().unwrap();
+        assert_eq!(result.entry(1).unwrap().handicap_points, 3);
+        assert_eq!(result.entry(2).unwrap().handicap_points, 3);
+        assert!(game.get_player(1).unwrap().verify_score().is_ok());
+    }
+
+    #[test]
+    fn test_score_audit_trail() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
+
+        let player = game.get_player_mut(1).unwrap();
+        player.add_score(ScoreSource::Word("CAT".to_string()), 5);
+        player.add_gems(ScoreSource::Bonus("gem".to_string()), 1);
+        player.add_score(ScoreSource::Adjustment("typo".to_string()), -2);
+        assert_eq!(player.score, 3);
+        assert_eq!(player.score_history().len(), 3);
+        assert_eq!(player.recomputed(ScoreField::Score), 3);
+
+        // Untracked edits are flagged at the end without blocking it
+        player.score += 10;
+        assert_eq!(player.verify_score(), Err(GameError::ScoreMismatch { player_id: 1 }));
+        let result = game.end().unwrap();
+        assert!(game.status.is_terminal());
+        assert_eq!(result.entries[0].score, 13);
+        assert!(!result.entries[0].score_verified);
+        assert_eq!(result.to_json()["entries"][0]["score_verified"], false);
+    }
+
+    #[test]
+    fn test_spectator_limits() {
+        let spectator = |player_id: i64| Spectator {
+            player_id,
+            user_id: player_id.to_string(),
+            username: format!("Watcher{}", player_id),
+            avatar_url: None,
+        };
+        let config = GameConfig {
+            max_spectators: Some(1),
+            spectator_join: SpectatorJoin::OnceStarted,
+            ..Default::default()
+        };
+        let mut game =
+            Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid()).with_config(config);
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        assert_eq!(game.add_spectator(spectator(8)), Err(GameError::GameNotActive));
+
+        game.start().unwrap();
+        game.add_spectator(spectator(8)).unwrap();
+        assert_eq!(game.add_spectator(spectator(9)), Err(GameError::TooManySpectators));
+        game.remove_spectator(8);
+        game.add_spectator(spectator(9)).unwrap();
+    }
+
+    #[test]
+    fn test_game_events() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.add_player(make_player(2, 1)).unwrap();
+        game.start().unwrap();
+        let seq = game.event_seq();
+
+        game.pass_turn(1).unwrap();
+        let events: Vec<&GameEvent> =
+            game.events_since(seq).unwrap().into_iter().map(|e| &e.event).collect();
+        assert_eq!(
+            events,
+            vec![
+                &GameEvent::Passed {
+                    player_id: 1,
+                    round: 1
+                },
+                &GameEvent::TurnAdvanced {
+                    player_id: 2,
+                    round: 1
+                },
+            ]
+        );
+        assert_eq!(game.to_json()["event_seq"], game.event_seq());
 
-impl GameManager {
-    pub fn new() -> Self {
-        Self::default()
+        game.add_spectator(Spectator {
+            player_id: 9,
+            user_id: "9000".to_string(),
+            username: "Watcher".to_string(),
+            avatar_url: None,
+        })
+        .unwrap();
+        game.remove_spectator(9);
+        let last = &game.events_since(game.event_seq() - 1).unwrap()[0].event;
+        assert_eq!(last, &GameEvent::SpectatorLeft { count: 0 });
     }
 
-    /// Add a game.
-    pub fn add(&mut self, game: Game) {
-        // Index players
-        for player_id in game.players.keys() {
-            self.player_index.insert(*player_id, game.id.clone());
+    #[test]
+    fn test_game_event_log_catch_up() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for (player_id, turn_order) in [(1, 0), (2, 1), (3, 2)] {
+            game.add_player(make_player(player_id, turn_order)).unwrap();
         }
-        self.games.insert(game.id.clone(), game);
-    }
-
-    /// Get a game.
-    pub fn get(&self, game_id: &str) -> Option<&Game> {
-        self.games.get(game_id)
-    }
+        game.start().unwrap();
+        // A client drops here and reconnects after the game is over
+        let seen = game.event_seq();
 
-    /// Get a mutable game.
-    pub fn get_mut(&mut self, game_id: &str) -> Option<&mut Game> {
-        self.games.get_mut(game_id)
-    }
+        let row = (0..3).map(|col| Position::new(0, col)).collect::<Vec<_>>();
+        game.submit_word(1, &row).unwrap();
+        game.start_timer_vote(3).unwrap();
+        game.end().unwrap();
 
-    /// Get game for a player.
-    pub fn get_for_player(&self, player_id: i64) -> Option<&Game> {
-        self.player_index
-            .get(&player_id)
-            .and_then(|id| self.games.get(id))
-    }
+        let missed = game.events_since(seen).unwrap();
+        let seqs: Vec<u64> = missed.iter().map(|e| e.seq).collect();
+        let expected: Vec<u64> = (seen + 1..=game.event_seq()).collect();
+        assert_eq!(seqs, expected);
 
-    /// Get mutable game for a player.
-    pub fn get_for_player_mut(&mut self, player_id: i64) -> Option<&mut Game> {
-        let id = self.player_index.get(&player_id)?.clone();
-        self.games.get_mut(&id)
-    }
+        let kinds: Vec<&str> = missed.iter().filter_map(|e| match e.event {
+            GameEvent::WordPlayed { .. } => Some("word"),
+            GameEvent::TurnAdvanced { .. } => Some("turn"),
+            GameEvent::TimerVoteStarted { .. } => Some("vote"),
+            GameEvent::Ended => Some("ended"),
+            _ => None,
+        }).collect();
+        assert_eq!(kinds, vec!["word", "turn", "vote", "ended"]);
 
-    /// Get game for a spectator.
-    pub fn get_for_spectator(&self, player_id: i64) -> Option<&Game> {
-        self.spectator_index
-            .get(&player_id)
-            .and_then(|id| self.games.get(id))
+        // Up to date clients get nothing; clients from the future need a snapshot
+        assert!(game.events_since(game.event_seq()).unwrap().is_empty());
+        assert!(game.events_since(game.event_seq() + 1).is_none());
     }
 
-    /// Remove a game.
-    pub fn remove(&mut self, game_id: &str) -> Option<Game> {
-        let game = self.games.remove(game_id)?;
+    #[test]
+    fn test_public_snapshot_hides_user_ids() {
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
 
-        // Clean up indexes
-        for player_id in game.players.keys() {
-            self.player_index.remove(player_id);
-        }
-        for spectator_id in game.spectators.keys() {
-            self.spectator_index.remove(spectator_id);
-        }
+        assert_eq!(game.profile_for(2), RedactionProfile::Public);
+        game.add_spectator(Spectator {
+            player_id: 9,
+            user_id: "9000".to_string(),
+            username: "Watcher".to_string(),
+            avatar_url: None,
+        })
+        .unwrap();
+        let json = game.to_json_for(RedactionProfile::Public);
+        assert!(json["players"][0].get("user_id").is_none());
+        assert_eq!(json["spectator_count"], 1);
 
-        Some(game)
+        // Spectator identities are for the host and admins only
+        assert!(game.to_json_for(RedactionProfile::Player).get("spectators").is_none());
+        assert_eq!(
+            game.to_json_for(RedactionProfile::Host)["spectators"][0]["username"],
+            "Watcher"
+        );
+        assert!(json.get("current_turn").is_none());
+        assert!(game.to_json_for(RedactionProfile::Player)["players"][0]
+            .get("user_id")
+            .is_some());
     }
 
-    /// Clean up finished games.
-    pub fn cleanup_finished(&mut self) -> Vec<String> {
-        let finished: Vec<String> = self
-            .games
-            .iter()
-            .filter(|(_, g)| g.status.is_terminal())
-            .map(|(id, _)| id.clone())
-            .collect();
+    #[test]
+    fn test_gem_spawning() {
+        let config = GameConfig {
+            gems: GemConfig {
+                initial_gems: 2,
+                respawn_per_round: 2,
+                max_on_board: 3,
+                multiplier_weight: 0,
+            },
+            ..Default::default()
+        };
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('A').with_multiplier(Multiplier::DoubleWord);
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_config(config)
+            .with_seed(1);
+        game.add_player(make_player(1, 0)).unwrap();
 
-        for id in &finished {
-            self.remove(id);
-        }
+        game.start().unwrap();
+        assert_eq!(game.gems_on_board(), 2);
 
-        finished
-    }
+        // New round respawns, capped by max_on_board
+        game.advance_turn();
+        assert_eq!(game.round, 2);
+        assert_eq!(game.gems_on_board(), 3);
 
-    /// Count active games.
-    pub fn active_count(&self) -> usize {
-        self.games.values().filter(|g| g.status.is_active()).count()
+        // Multiplier cells are never chosen with zero weight
+        assert!(!game.grid[0][0].has_gem);
     }
 
-    /// Total game count.
-    pub fn count(&self) -> usize {
-        self.games.len()
+    #[test]
+    fn test_gem_placement_is_seeded() {
+        let config = GemConfig {
+            initial_gems: 3,
+            ..Default::default()
+        };
+        let mut a = make_grid();
+        let mut b = make_grid();
+        let placed_a = config.place_gems(&mut a, 3, &mut SeededRng::new(9));
+        let placed_b = config.place_gems(&mut b, 3, &mut SeededRng::new(9));
+        assert_eq!(placed_a, placed_b);
+        assert_eq!(placed_a.len(), 3);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_refresh_played_cells() {
+        let mut grid = make_grid();
+        grid[0][1] = GridCell::new('A').with_multiplier(Multiplier::DoubleWord);
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_seed(3)
+            .with_config(GameConfig {
+                refresh_played_cells: true,
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
 
-    fn make_grid() -> Grid {
-        let default_cell = || GridCell::new('A');
-        [
-            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
-            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
-            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
-            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
-            [default_cell(), default_cell(), default_cell(), default_cell(), default_cell()],
-        ]
+        let result = game.submit_word(1, &path).unwrap();
+        assert_eq!(result.refreshed.len(), 3);
+        for change in &result.refreshed {
+            let cell = game.get_cell(change.position).unwrap();
+            let value = LetterValues::default().get(change.letter);
+            assert_eq!((cell.letter, cell.value), (change.letter, value));
+        }
+        assert_eq!(game.grid[0][1].multiplier, Some(Multiplier::DoubleWord));
+        assert!(game
+            .events_since(0)
+            .unwrap()
+            .iter()
+            .any(|e| matches!(e.event, GameEvent::TilesRefreshed { .. })));
     }
 
-    fn make_player(id: i64, turn_order: u8) -> GamePlayer {
-        GamePlayer::new(
-            id,
-            format!("{}", id * 1000),
-            format!("Player{}", id),
-            None,
-            turn_order,
-        )
+    #[test]
+    fn test_custom_letter_values() {
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('Q');
+        let config = GameConfig {
+            letter_values: LetterValues::default().with_value('Q', 4),
+            ..Default::default()
+        };
+        let game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid.clone())
+            .with_config(config);
+        assert_eq!(game.grid[0][0].value, 4);
+        assert_eq!(game.grid[0][1].value, LetterValues::default().get(game.grid[0][1].letter));
+
+        // A letter set's values win over the English table
+        let config = GameConfig {
+            grid: GridConfig {
+                letters: Some(LetterSet::new("xx", vec![Tile::new('Q', 1, 7)])),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let game = Game::new("game-2".to_string(), "lobby-1".to_string(), grid).with_config(config);
+        assert_eq!(game.grid[0][0].value, 7);
+        assert!(game.grid.iter().flatten().skip(1).all(|c| c.value == 1));
     }
 
     #[test]
-    fn test_game_new() {
-        let game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
-        assert_eq!(game.status, GameStatus::Idle);
-        assert_eq!(game.round, 1);
-        assert!(game.players.is_empty());
+    fn test_letter_set_tiles() {
+        let mut grid = make_grid();
+        grid[0][0] = Tile::digraph(['L', 'L'], 1, 8).cell();
+        let only_ch = LetterSet::new("es", vec![Tile::digraph(['C', 'H'], 1, 5)]);
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_seed(3)
+            .with_config(GameConfig {
+                refresh_played_cells: true,
+                grid: GridConfig {
+                    letters: Some(only_ch),
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        let word = game.extract_word(&path);
+        assert!(word.starts_with("LL") && word.len() == 4);
+
+        let result = game.submit_word(1, &path).unwrap();
+        assert_eq!(result.score.word, word);
+        let change = result.refreshed[0];
+        assert_eq!((change.letter, change.digraph, change.value), ('C', Some('H'), 5));
+        assert_eq!(change.to_json()["letter"], "CH");
+        assert_eq!(game.grid[0][0].letters().collect::<String>(), "CH");
     }
 
     #[test]
-    fn test_game_add_players() {
-        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
-
-        game.add_player(make_player(1, 0)).unwrap();
-        game.add_player(make_player(2, 1)).unwrap();
+    fn test_multiplier_use() {
+        let row = |len: usize| (0..len).map(|col| Position::new(0, col)).collect::<Vec<_>>();
+        let points = |multiplier_use| {
+            let mut grid = make_grid();
+            grid[0][1] = GridCell::new('A').with_multiplier(Multiplier::DoubleWord);
+            let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+                .with_config(GameConfig {
+                    multiplier_use,
+                    ..Default::default()
+                });
+            game.add_player(make_player(1, 0)).unwrap();
+            game.add_player(make_player(2, 1)).unwrap();
+            game.start().unwrap();
+            [(1, 3), (2, 4), (1, 5)]
+                .map(|(player_id, len)| game.submit_word(player_id, &row(len)).unwrap())
+                .map(|result| result.score.points)
+        };
 
-        assert_eq!(game.player_count(), 2);
-        assert!(game.has_player(1));
-        assert!(game.has_player(2));
+        assert_eq!(points(MultiplierUse::Unlimited), [6, 8, 10]);
+        assert_eq!(points(MultiplierUse::OncePerGame), [6, 4, 5]);
+        assert_eq!(points(MultiplierUse::OncePerPlayer), [6, 8, 5]);
     }
 
     #[test]
-    fn test_game_start() {
-        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+    fn test_submit_word() {
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('C').with_gem();
+        grid[0][1] = GridCell::new('A').with_multiplier(Multiplier::DoubleWord);
+        grid[0][2] = GridCell::new('T');
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid);
         game.add_player(make_player(1, 0)).unwrap();
         game.add_player(make_player(2, 1)).unwrap();
-
         game.start().unwrap();
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
 
-        assert_eq!(game.status, GameStatus::InProgress);
-        assert!(game.current_player_id().is_some());
+        assert_eq!(game.submit_word(2, &path), Err(GameError::NotYourTurn));
+        assert_eq!(game.submit_word(1, &path[..2]), Err(GameError::PathTooShort));
+
+        let result = game.submit_word(1, &path).unwrap();
+        assert_eq!((result.score.points, result.score.gems), (10, 1));
+        assert_eq!(result.next_player_id, 2);
+        let player = game.get_player(1).unwrap();
+        assert_eq!((player.score, player.gems), (10, 1));
+        assert_eq!(player.words_played, vec!["CAT".to_string()]);
+        assert!(player.verify_score().is_ok());
+        assert!(!game.grid[0][0].has_gem);
+        assert!(matches!(
+            game.moves().last().unwrap().action,
+            TurnAction::PlayWord { points: 10, .. }
+        ));
+        let json = game.to_json();
+        assert_eq!(json["moves"][0]["action"]["word"], "CAT");
+        assert_eq!(json["moves"][0]["action"]["path"][2]["col"], 2);
+        assert_eq!(game.moves_for_player(1).count(), 1);
+
+        // Rejected plays leave the turn alone
+        assert_eq!(game.submit_word(2, &path), Err(GameError::WordUsed));
+        assert!(game.is_player_turn(2));
     }
 
     #[test]
-    fn test_game_turns() {
-        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+    fn test_submission_limits() {
+        let config = GameConfig {
+            submissions: SubmissionLimits {
+                min_interval: Some(chrono::Duration::seconds(2)),
+                max_per_round: Some(2),
+            },
+            ..Default::default()
+        };
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(config);
         game.add_player(make_player(1, 0)).unwrap();
-        game.add_player(make_player(2, 1)).unwrap();
         game.start().unwrap();
 
-        // Player 1's turn
-        assert!(game.is_player_turn(1));
-        assert!(!game.is_player_turn(2));
+        let now = chrono::Utc::now();
+        game.register_submission(1, now).unwrap();
+        assert_eq!(
+            game.register_submission(1, now + chrono::Duration::seconds(1)),
+            Err(GameError::SubmissionCooldown {
+                retry_at: now + chrono::Duration::seconds(2)
+            })
+        );
+        game.register_submission(1, now + chrono::Duration::seconds(2))
+            .unwrap();
+        assert_eq!(
+            game.register_submission(1, now + chrono::Duration::seconds(10)),
+            Err(GameError::SubmissionLimitReached)
+        );
 
-        // Advance
+        // Counter resets with the round
         game.advance_turn();
-
-        // Player 2's turn
-        assert!(!game.is_player_turn(1));
-        assert!(game.is_player_turn(2));
-
-        // Advance again - back to player 1, new round
-        let (_, round) = game.advance_turn();
-        assert!(game.is_player_turn(1));
-        assert_eq!(round, 2);
+        game.register_submission(1, now + chrono::Duration::seconds(10))
+            .unwrap();
     }
 
     #[test]
@@ -832,6 +6419,174 @@ mod tests {
         assert!(!p.is_adjacent_to(&Position::new(4, 4))); // Too far
     }
 
+    #[test]
+    fn test_position_neighbors() {
+        assert_eq!(Position::new(2, 2).neighbors().count(), 8);
+        assert_eq!(Position::new(0, 0).neighbors().count(), 3);
+        assert_eq!(Position::new(4, 2).neighbors().count(), 5);
+        assert!(Position::new(0, 0)
+            .neighbors()
+            .all(|p| p.is_adjacent_to(&Position::new(0, 0))));
+    }
+
+    #[test]
+    fn test_generate_seeded() {
+        let letters = |grid: Grid| grid.iter().flatten().map(|c| c.letter).collect::<String>();
+        assert_eq!(letters(Grid::generate_seeded(7)), letters(Grid::generate_seeded(7)));
+        assert_ne!(letters(Grid::generate_seeded(7)), letters(Grid::generate_seeded(8)));
+    }
+
+    #[test]
+    fn test_compact_grid() {
+        let mut grid = make_grid();
+        grid[0][3] = GridCell::new('Q').with_multiplier(Multiplier::DoubleLetter);
+        grid[0][3].value = 4;
+        grid[1][4] = GridCell::new('E').with_gem();
+        grid[2][2] = Tile::digraph(['L', 'L'], 1, 8).cell().with_multiplier(Multiplier::DoubleWord);
+        grid[4][0] = GridCell::new('X').with_blocked();
+
+        let compact = grid.to_compact();
+        assert!(compact.contains("[Q:4]") && compact.contains("[LL:8]"));
+        assert!(compact.ends_with("#DL:0,3;DW:2,2#G:1,4#X:4,0"));
+        let parsed = Grid::parse(&compact).unwrap();
+        assert_eq!(parsed.to_compact(), compact);
+        assert_eq!(parsed[2][2].digraph, Some('L'));
+
+        let plain = Grid::parse("ABCDE|FGHIJ|KLMNO|PQRST|UVWXY").unwrap();
+        assert_eq!(plain[3][1].value, 10);
+        let invalid = [
+            "ABCDE|FGHIJ",
+            "ABCDE|FGHIJ|KLMNO|PQRST|UVWX1",
+            "ABCDE|FGHIJ|KLMNO|PQRST|UVWXY#DL:5,0",
+        ];
+        for invalid in invalid {
+            assert_eq!(Grid::parse(invalid).unwrap_err(), GameError::InvalidGrid);
+        }
+    }
+
+    #[test]
+    fn test_check_word_dictionary() {
+        use crate::state::dictionary::Trie;
+
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        assert_eq!(game.check_word("zzz"), Ok(()));
+
+        game = game.with_dictionary(Arc::new(Trie::from_words(["cat"])));
+        assert_eq!(game.check_word("cat"), Ok(()));
+        assert_eq!(game.check_word("zzz"), Err(GameError::NotAWord));
+        game.use_word("CAT");
+        assert_eq!(game.check_word("cat"), Err(GameError::WordUsed));
+    }
+
+    #[test]
+    fn test_generate_hint() {
+        use crate::state::dictionary::WordList;
+        use crate::state::hint::HintConfig;
+
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('C');
+        grid[1][0] = GridCell::new('T');
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_dictionary(Arc::new(WordList::from_words(["cat", "act"])))
+            .with_config(GameConfig {
+                hints: Some(HintConfig {
+                    cost: 1,
+                    max_per_player: Some(2),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
+        assert_eq!(game.generate_hint(1), None);
+
+        game.get_player_mut(1)
+            .unwrap()
+            .add_gems(ScoreSource::Bonus("test".to_string()), 5);
+        let first = game.generate_hint(1).unwrap();
+        let second = game.generate_hint(1).unwrap();
+        // A different word each time while there are others left
+        assert_ne!(first, second);
+        assert_eq!(game.hints_used(1), 2);
+        assert_eq!(game.get_player(1).unwrap().gems, 3);
+
+        // Capped at max_per_player
+        assert_eq!(game.generate_hint(1), None);
+        assert_eq!(game.get_player(1).unwrap().gems, 3);
+    }
+
+    #[test]
+    fn test_min_word_length() {
+        let game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                min_word_length: Some(4),
+                ..Default::default()
+            });
+        let row = |len: usize| (0..len).map(|col| Position::new(0, col)).collect::<Vec<_>>();
+
+        assert_eq!(game.validate_path(&row(3)), Err(GameError::PathTooShort));
+        assert_eq!(game.validate_path(&row(4)), Ok("AAAA".to_string()));
+        assert_eq!(game.check_word("cat"), Err(GameError::PathTooShort));
+        assert_eq!(game.check_word("cats"), Ok(()));
+    }
+
+    #[test]
+    fn test_missed_words() {
+        use crate::state::dictionary::WordList;
+
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('C');
+        grid[1][0] = GridCell::new('T');
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid);
+        assert!(game.missed_words().is_empty());
+
+        game = game.with_dictionary(Arc::new(WordList::from_words(["cat", "act"])));
+        game.use_word("cat");
+        let missed: Vec<String> = game.missed_words().into_iter().map(|w| w.word).collect();
+        assert_eq!(missed, vec!["ACT"]);
+    }
+
+    #[test]
+    fn test_grid_quality_check() {
+        use crate::state::dictionary::WordList;
+
+        let mut grid = make_grid();
+        grid[0][0] = GridCell::new('C');
+        grid[1][0] = GridCell::new('T');
+        let dict = WordList::from_words(["cat", "act", "tact"]);
+
+        let game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid.clone())
+            .with_config(GameConfig {
+                min_quality: Some(GridQuality {
+                    word_count: 2,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        assert_eq!(game.check_grid_quality(&dict).unwrap().word_count, 2);
+
+        let game = Game::new("game-2".to_string(), "lobby-1".to_string(), grid)
+            .with_config(GameConfig {
+                min_quality: Some(GridQuality {
+                    max_word_length: 4,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        assert_eq!(
+            game.check_grid_quality(&dict),
+            Err(GameError::GridQualityTooLow)
+        );
+
+        // Starting with a dictionary enforces the minimum
+        let mut game = game.with_dictionary(Arc::new(dict));
+        game.add_player(make_player(1, 0)).unwrap();
+        assert_eq!(game.start(), Err(GameError::GridQualityTooLow));
+        assert_eq!(game.status, GameStatus::Idle);
+        game.config.min_quality = None;
+        game.start().unwrap();
+    }
+
     #[test]
     fn test_letter_values() {
         assert_eq!(letter_value('A'), 1);
@@ -842,4 +6597,351 @@ mod tests {
         assert_eq!(letter_value('X'), 8);
         assert_eq!(letter_value('Z'), 10);
     }
+
+    #[test]
+    fn test_create_rematch() {
+        let mut manager = GameManager::new();
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                rotate_rematch_order: true,
+                ..Default::default()
+            });
+        for (id, order) in [(1, 0), (2, 1), (3, 2)] {
+            game.add_player(make_player(id, order)).unwrap();
+        }
+        game.start().unwrap();
+        game.force_adjust_score(1, 12, "test", 99).unwrap();
+        manager.add(game).unwrap();
+
+        let rematch = manager.create_rematch("game-1", "game-2".to_string(), make_grid());
+        assert_eq!(rematch.unwrap_err(), GameError::InvalidStatus);
+        manager.get_mut("game-1").unwrap().end().unwrap();
+
+        let rematch = manager
+            .create_rematch("game-1", "game-2".to_string(), make_grid())
+            .unwrap();
+        assert_eq!(rematch.lobby_id, "lobby-1");
+        assert_eq!(rematch.status, GameStatus::Idle);
+        assert_eq!(rematch.player_ids_in_order(), &[2, 3, 1]);
+        assert_eq!(rematch.get_player(1).unwrap().score, 0);
+        assert_eq!(manager.get_for_player(1).unwrap().id, "game-2");
+
+        let again = manager.create_rematch("game-1", "game-2".to_string(), make_grid());
+        assert_eq!(again.unwrap_err(), GameError::Manager(GameManagerError::AlreadyExists));
+        let missing = manager.create_rematch("nope", "game-3".to_string(), make_grid());
+        assert_eq!(missing.unwrap_err(), GameError::NotFound);
+
+        // Cleaning up the finished game keeps players indexed to the rematch
+        manager.cleanup_finished();
+        assert!(manager.get("game-1").is_none());
+        assert_eq!(manager.get_for_player(1).unwrap().id, "game-2");
+    }
+
+    #[test]
+    fn test_daily_challenge_games() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let mut manager = GameManager::new();
+        manager.register_daily_challenge(date, 42).unwrap();
+        assert!(manager.register_daily_challenge(date, 43).is_err());
+
+        for (game_id, player_id) in [("g1", 1), ("g2", 2)] {
+            let game = manager
+                .create_challenge_game(date, game_id.to_string(), "lobby-1".to_string())
+                .unwrap();
+            game.add_player(make_player(player_id, 0)).unwrap();
+            game.start().unwrap();
+            game.force_adjust_score(player_id, player_id as i32 * 10, "test", 99).unwrap();
+            game.end().unwrap();
+        }
+        let grid = |id: &str| manager.get(id).unwrap().to_json()["grid"].clone();
+        assert_eq!(grid("g1"), grid("g2"));
+        let taken = manager.create_challenge_game(date, "g1".to_string(), "lobby-1".to_string());
+        assert_eq!(taken.unwrap_err(), GameError::Manager(GameManagerError::AlreadyExists));
+
+        // Removed games still count
+        manager.cleanup_finished();
+        let board = manager.daily_leaderboard(date).unwrap();
+        assert_eq!(board.iter().map(|s| s.player_id).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(manager.daily_challenge(date).unwrap().game_ids().len(), 2);
+    }
+
+    #[test]
+    fn test_solo_game() {
+        let mut game = Game::new("solo-1".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(GameConfig {
+                mode: GameMode::Solo {
+                    time_limit: Some(chrono::Duration::minutes(3)),
+                },
+                elimination: true,
+                ..Default::default()
+            });
+        game.add_player(make_player(1, 0)).unwrap();
+        assert_eq!(game.add_player(make_player(2, 1)), Err(GameError::TooManyPlayers));
+        game.start().unwrap();
+        let ends_at = game.ends_at.unwrap();
+
+        // Elimination doesn't apply to a lone player
+        game.pass_turn(1).unwrap();
+        assert_eq!(game.status, GameStatus::InProgress);
+
+        // Solo games don't take over the player's multiplayer game
+        let mut manager = GameManager::new();
+        let mut multiplayer = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        multiplayer.add_player(make_player(1, 0)).unwrap();
+        manager.add(multiplayer).unwrap();
+        manager.add(game).unwrap();
+        assert_eq!(manager.get_for_player(1).unwrap().id, "game-1");
+        assert_eq!(manager.get_solo_for_player(1).unwrap().id, "solo-1");
+
+        assert_eq!(manager.tick(ends_at), vec!["solo-1"]);
+        assert_eq!(manager.get("solo-1").unwrap().status, GameStatus::Finished);
+        manager.cleanup_finished();
+        assert!(manager.get_solo_for_player(1).is_none());
+        assert_eq!(manager.get_for_player(1).unwrap().id, "game-1");
+    }
+
+    #[test]
+    fn test_coop_game() {
+        let mut grid = make_grid();
+        grid[1] = std::array::from_fn(|_| GridCell::new('E'));
+        let coop = |target| GameConfig {
+            mode: GameMode::Coop {
+                target,
+                time_limit: None,
+            },
+            tie_break: TieBreak::Overtime { max_rounds: 1 },
+            ..Default::default()
+        };
+        let row = |r: usize| [Position::new(r, 0), Position::new(r, 1), Position::new(r, 2)];
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_config(coop(Some(6)));
+        for player_id in 1..=2 {
+            game.add_player(make_player(player_id, player_id as u8)).unwrap();
+        }
+        game.start().unwrap();
+
+        let first = game.submit_word(1, &row(0)).unwrap().score.points;
+        assert_eq!(game.team_score(), first);
+        assert_eq!(game.to_json()["team"]["target"], 6);
+        assert!(!game.should_end());
+        game.submit_word(2, &row(1)).unwrap();
+        assert!(game.coop_target_reached() && game.should_end());
+        assert_eq!(game.to_json()["team"]["progress"], 1.0);
+
+        // Teammates win together, with no overtime on a tie
+        let result = game.end().unwrap();
+        assert_eq!(result.winners, vec![1, 2]);
+        assert_eq!(result.team_score, Some(game.team_score()));
+
+        // Missing the target is a shared loss
+        let mut game = Game::new("game-2".to_string(), "lobby-1".to_string(), make_grid())
+            .with_config(coop(Some(100)));
+        game.max_rounds = 1;
+        game.add_player(make_player(1, 0)).unwrap();
+        game.start().unwrap();
+        game.pass_turn(1).unwrap();
+        assert!(game.end().unwrap().winners.is_empty());
+
+        let multiplayer = Game::new("game-3".to_string(), "lobby-1".to_string(), make_grid());
+        assert!(multiplayer.to_json()["team"].is_null());
+    }
+
+    #[test]
+    fn test_blitz_game() {
+        let mut grid = make_grid();
+        grid[1] = std::array::from_fn(|_| GridCell::new('E'));
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_config(GameConfig {
+                mode: GameMode::Blitz {
+                    window: chrono::Duration::minutes(2),
+                    duplicates: DuplicateRule::EarliestWins,
+                },
+                ..Default::default()
+            });
+        for player_id in 1..=2 {
+            game.add_player(make_player(player_id, player_id as u8)).unwrap();
+        }
+        game.start().unwrap();
+        let ends_at = game.ends_at.unwrap();
+        let at = |secs| game.started_at.unwrap() + chrono::Duration::seconds(secs);
+        let (early, late, closed) = (at(10), at(20), ends_at);
+        let row = |r: usize| [Position::new(r, 0), Position::new(r, 1), Position::new(r, 2)];
+
+        // No turns: player 2 can play first, and both can find the same word
+        assert_eq!(game.submit_word(1, &row(0)).unwrap_err(), GameError::WrongMode);
+        let aaa = game.play_blitz_word(2, &row(0), late).unwrap().points;
+        game.play_blitz_word(1, &row(0), early).unwrap();
+        assert_eq!(game.submit_blitz_word(1, &row(0)), Err(GameError::WordUsed));
+        game.submit_blitz_word(2, &row(1)).unwrap();
+        assert_eq!(game.play_blitz_word(1, &row(1), closed), Err(GameError::GameNotActive));
+        assert_eq!(game.to_json()["blitz"]["found"]["2"], 2);
+        assert_eq!(game.get_player(1).unwrap().score, 0);
+
+        // The window closing ends the game and settles duplicates
+        assert!(game.tick(ends_at));
+        assert_eq!(game.status, GameStatus::Finished);
+        assert_eq!(game.get_player(1).unwrap().score, aaa);
+        // Settled in find order; EEE was stamped before the recorded `late`
+        assert_eq!(game.get_player(2).unwrap().words_played, vec!["EEE", "AAA"]);
+        assert!(game.get_player(1).unwrap().verify_score().is_ok());
+    }
+
+    #[test]
+    fn test_achievements() {
+        use crate::state::achievements::Achievement;
+
+        let mut grid = make_grid();
+        grid[0] = std::array::from_fn(|_| GridCell::new('E').with_gem());
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid);
+        game.max_rounds = 2;
+        for player_id in 1..=2 {
+            game.add_player(make_player(player_id, player_id as u8)).unwrap();
+        }
+        game.start().unwrap();
+        let row = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        game.submit_word(1, &row).unwrap();
+        assert!(game.achievements().has(1, Achievement::GemHaul));
+        let events = game.events_since(0).unwrap();
+        assert!(events.iter().any(|e| matches!(
+            e.event,
+            GameEvent::AchievementUnlocked {
+                player_id: 1,
+                achievement: Achievement::GemHaul,
+            }
+        )));
+
+        // Player 2 trails by 30 after round 1, then wins
+        game.force_adjust_score(1, 27, "test", 9).unwrap();
+        game.pass_turn(2).unwrap();
+        game.force_adjust_score(2, 50, "test", 9).unwrap();
+        game.pass_turn(1).unwrap();
+        game.pass_turn(2).unwrap();
+        let result = game.end().unwrap();
+        assert_eq!(result.winners, vec![2]);
+        assert_eq!(result.entry(2).unwrap().achievements, vec![Achievement::Comeback]);
+        assert_eq!(result.entry(1).unwrap().achievements, vec![Achievement::GemHaul]);
+    }
+
+    #[test]
+    fn test_word_challenge() {
+        let mut grid = make_grid();
+        grid[1] = std::array::from_fn(|_| GridCell::new('E'));
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_config(GameConfig {
+                challenges: Some(ChallengeConfig { penalty: 4 }),
+                turn_time_limit: Some(chrono::Duration::seconds(60)),
+                power_ups: Some(PowerUpConfig::default()),
+                ..Default::default()
+            });
+        for player_id in 1..=2 {
+            game.add_player(make_player(player_id, player_id as u8)).unwrap();
+        }
+        game.start().unwrap();
+        let row = |r: usize| [Position::new(r, 0), Position::new(r, 1), Position::new(r, 2)];
+        assert_eq!(game.challenge_word(2), Err(GameError::ChallengeUnavailable));
+
+        // Ruled invalid: points come off and the word is free again
+        let points = game.submit_word(1, &row(0)).unwrap().score.points;
+        assert_eq!(game.challenge_word(1), Err(GameError::ChallengeUnavailable));
+        assert_eq!(game.challenge_word(2), Ok(None));
+        assert!(game.turn_deadline.is_none());
+        assert_eq!(game.submit_word(2, &row(1)), Err(GameError::ChallengeInProgress));
+        // The challenged path can't be rewritten before the ruling
+        let swap = PowerUpTarget::Tile {
+            position: Position::new(0, 0),
+            letter: 'Z',
+        };
+        assert_eq!(
+            game.use_power_up(2, PowerUp::SwapTile, swap),
+            Err(GameError::ChallengeInProgress)
+        );
+        let outcome = game.resolve_challenge(false).unwrap();
+        assert_eq!((outcome.valid, outcome.points), (false, points));
+        assert_eq!(game.get_player(1).unwrap().score, 0);
+        assert!(!game.is_word_used("AAA") && game.turn_deadline.is_some());
+        assert_eq!(game.challenge_word(2), Err(GameError::ChallengeUnavailable));
+        assert_eq!(game.resolve_challenge(true), Err(GameError::NoChallengeInProgress));
+
+        // Ruled valid: the challenger pays
+        game.submit_word(2, &row(1)).unwrap();
+        game.challenge_word(1).unwrap();
+        assert_eq!(game.to_json()["challenge"]["word"], "EEE");
+        game.resolve_challenge(true).unwrap();
+        assert_eq!(game.get_player(1).unwrap().score, -4);
+        assert!(game.get_player(1).unwrap().verify_score().is_ok());
+        game.submit_word(1, &row(0)).unwrap();
+    }
+
+    #[test]
+    fn test_manager_queries() {
+        let mut manager = GameManager::new();
+        for (game_id, lobby_id) in [("g1", "lobby-1"), ("g2", "lobby-2"), ("g3", "lobby-1")] {
+            let mut game = Game::new(game_id.to_string(), lobby_id.to_string(), make_grid());
+            game.add_player(make_player(game_id[1..].parse().unwrap(), 0)).unwrap();
+            manager.add(game).unwrap();
+        }
+        manager.get_mut("g2").unwrap().start().unwrap();
+
+        let ids = |games: Vec<&Game>| {
+            let mut ids: Vec<String> = games.iter().map(|g| g.id.clone()).collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids(manager.games_in_lobby("lobby-1").collect()), vec!["g1", "g3"]);
+        assert_eq!(manager.games_in_lobby("nowhere").count(), 0);
+        assert_eq!(ids(manager.games_with_status(GameStatus::Idle).collect()), vec!["g1", "g3"]);
+        let later = chrono::Utc::now() + chrono::Duration::seconds(1);
+        assert_eq!(ids(manager.games_started_before(later).collect()), vec!["g2"]);
+        assert_eq!(manager.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_manager_capacity() {
+        let game = |id: &str| Game::new(id.to_string(), "lobby-1".to_string(), make_grid());
+        let mut manager = GameManager::new().with_capacity(2);
+        manager.add(game("g1")).unwrap();
+        manager.add(game("g2")).unwrap();
+        manager.get_mut("g1").unwrap().cancel(CancelReason::HostLeft);
+        assert_eq!(
+            manager.add(game("g3")),
+            Err(GameManagerError::AtCapacity { limit: 2 })
+        );
+
+        // Evicting makes room by archiving the terminal game
+        let mut manager = manager.with_eviction(EvictionPolicy::OldestTerminal);
+        manager.add(game("g3")).unwrap();
+        assert!(manager.get("g1").is_none());
+        assert_eq!(manager.archive().len(), 1);
+        assert_eq!(manager.get_archived("g1").unwrap().lobby_id, "lobby-1");
+        assert!(manager.add(game("g4")).is_err());
+    }
+
+    #[test]
+    fn test_manager_lobby_history() {
+        let mut manager = GameManager::new();
+        for i in 1..=3 {
+            let game = Game::new(format!("game-{}", i), "lobby-1".to_string(), make_grid());
+            manager.add(game).unwrap();
+        }
+        let other = Game::new("other".to_string(), "lobby-2".to_string(), make_grid());
+        manager.add(other).unwrap();
+
+        // Re-adding an ID neither replaces the game nor duplicates its entry
+        let again = Game::new("game-2".to_string(), "lobby-2".to_string(), make_grid());
+        assert_eq!(manager.add(again), Err(GameManagerError::AlreadyExists));
+        assert_eq!(manager.get("game-2").unwrap().lobby_id, "lobby-1");
+        assert_eq!(manager.history_for_lobby("lobby-1", 10).len(), 3);
+
+        assert_eq!(manager.latest_for_lobby("lobby-1").unwrap().id, "game-3");
+        let history: Vec<&str> = manager
+            .history_for_lobby("lobby-1", 2)
+            .iter()
+            .map(|g| g.id.as_str())
+            .collect();
+        assert_eq!(history, vec!["game-3", "game-2"]);
+
+        manager.remove("game-3");
+        assert_eq!(manager.latest_for_lobby("lobby-1").unwrap().id, "game-2");
+        assert!(manager.latest_for_lobby("lobby-3").is_none());
+    }
 }
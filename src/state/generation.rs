@@ -0,0 +1,712 @@
+//! Grid generation.
+//!
+//! Letters are drawn independently from a weighted alphabet. With adaptive
+//! difficulty enabled the weights are biased by the players' average rating:
+//! beginners get more vowels and fewer awkward letters, experts the opposite.
+//! `GridBuilder` adds bonus cells and a vowel floor on top of the letters;
+//! `PlacementRules` keep those bonus cells apart or off the rim of the board.
+//! Unplayable boards can be rejected at generation time with
+//! [`GridBuilder::try_build_playable`], which measures each board with the
+//! solver.
+
+use super::dictionary::Dictionary;
+use super::game::{GameError, Grid, GridCell, Multiplier, Position, GRID_SIZE};
+use super::letters::LetterSet;
+use super::rng::SeededRng;
+use super::solver::{self, GridQuality};
+
+/// Approximate English letter frequencies (per mille), A to Z.
+pub const STANDARD_LETTER_WEIGHTS: [u32; 26] = [
+    82, 15, 28, 43, 127, 22, 20, 61, 70, 2, 8, 40, 24, 67, 75, 19, 1, 60, 63, 91, 28, 10, 24, 2,
+    20, 1,
+];
+
+/// Default minimum number of vowels on a built board.
+pub const DEFAULT_MIN_VOWELS: usize = 5;
+
+/// Boards [`GridBuilder::try_build_playable`] generates before giving up.
+pub const DEFAULT_PLAYABLE_ATTEMPTS: usize = 20;
+
+/// Average rating below which players count as beginners.
+pub const BEGINNER_RATING_THRESHOLD: i32 = 1000;
+
+/// Average rating at or above which players count as experts.
+pub const EXPERT_RATING_THRESHOLD: i32 = 1600;
+
+const VOWELS: [char; 5] = ['A', 'E', 'I', 'O', 'U'];
+const RARE_LETTERS: [char; 4] = ['J', 'Q', 'X', 'Z'];
+
+/// Relative weight of each letter, A to Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LetterWeights(pub [u32; 26]);
+
+impl Default for LetterWeights {
+    fn default() -> Self {
+        Self(STANDARD_LETTER_WEIGHTS)
+    }
+}
+
+impl LetterWeights {
+    /// Weight of a letter (0 for non-letters).
+    pub fn get(&self, letter: char) -> u32 {
+        letter_index(letter).map_or(0, |i| self.0[i])
+    }
+
+    /// Scale the weights of `letters` by `numerator / denominator`. Letters
+    /// weighted 0 stay excluded; others keep a weight of at least 1.
+    pub fn scale(mut self, letters: &[char], numerator: u32, denominator: u32) -> Self {
+        for index in letters.iter().filter_map(|c| letter_index(*c)) {
+            let weight = self.0[index];
+            if weight == 0 {
+                continue;
+            }
+            let scaled = weight as u64 * numerator as u64 / denominator.max(1) as u64;
+            self.0[index] = scaled.clamp(1, u32::MAX as u64) as u32;
+        }
+        self
+    }
+
+    /// Draw a random letter.
+    pub fn sample(&self, rng: &mut SeededRng) -> char {
+        let index = rng.choose_weighted(&self.0).unwrap_or(0);
+        (b'A' + index as u8) as char
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let weights: serde_json::Map<String, serde_json::Value> = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (((b'A' + i as u8) as char).to_string(), (*w).into()))
+            .collect();
+        serde_json::Value::Object(weights)
+    }
+}
+
+fn letter_index(letter: char) -> Option<usize> {
+    let upper = letter.to_ascii_uppercase();
+    upper
+        .is_ascii_uppercase()
+        .then(|| (upper as u8 - b'A') as usize)
+}
+
+/// Difficulty bucket derived from average player rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DifficultyTier {
+    Beginner,
+    Standard,
+    Expert,
+}
+
+impl DifficultyTier {
+    /// Tier for an average rating (unrated games are `Standard`).
+    pub fn for_rating(average_rating: Option<i32>) -> Self {
+        match average_rating {
+            Some(r) if r < BEGINNER_RATING_THRESHOLD => Self::Beginner,
+            Some(r) if r >= EXPERT_RATING_THRESHOLD => Self::Expert,
+            _ => Self::Standard,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Beginner => "beginner",
+            Self::Standard => "standard",
+            Self::Expert => "expert",
+        }
+    }
+
+    /// Bias base weights for this tier.
+    pub fn adjust(&self, weights: LetterWeights) -> LetterWeights {
+        match self {
+            Self::Beginner => weights.scale(&VOWELS, 3, 2).scale(&RARE_LETTERS, 1, 4),
+            Self::Standard => weights,
+            Self::Expert => weights.scale(&RARE_LETTERS, 2, 1),
+        }
+    }
+}
+
+/// How boards are generated for a game.
+#[derive(Debug, Clone, Default)]
+pub struct GridConfig {
+    /// Base letter weights
+    pub letter_weights: LetterWeights,
+    /// Bias the weights by the players' average rating
+    pub adaptive_difficulty: bool,
+    /// Draw tiles from this set instead, e.g. for Spanish boards. The letter
+    /// weights and adaptive difficulty only apply to English boards.
+    pub letters: Option<LetterSet>,
+    /// Multiplier and gem cells to add to generated boards
+    pub premiums: Option<PremiumLayout>,
+}
+
+impl GridConfig {
+    /// Work out the weights to use for players with the given ratings.
+    pub fn resolve(&self, ratings: &[Option<i32>]) -> LetterWeighting {
+        let rated: Vec<i64> = ratings.iter().flatten().map(|r| *r as i64).collect();
+        let average_rating = (!rated.is_empty())
+            .then(|| (rated.iter().sum::<i64>() / rated.len() as i64) as i32);
+
+        let tier = if self.adaptive_difficulty {
+            DifficultyTier::for_rating(average_rating)
+        } else {
+            DifficultyTier::Standard
+        };
+
+        LetterWeighting {
+            average_rating,
+            tier,
+            weights: tier.adjust(self.letter_weights),
+        }
+    }
+}
+
+/// The letter weighting a board was generated with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterWeighting {
+    /// Average rating of rated players (None if nobody was rated)
+    pub average_rating: Option<i32>,
+    pub tier: DifficultyTier,
+    pub weights: LetterWeights,
+}
+
+impl Default for LetterWeighting {
+    /// Standard weights, unrated.
+    fn default() -> Self {
+        Self {
+            average_rating: None,
+            tier: DifficultyTier::Standard,
+            weights: LetterWeights::default(),
+        }
+    }
+}
+
+impl LetterWeighting {
+    /// Generate a board with these weights.
+    pub fn generate(&self, rng: &mut SeededRng) -> Grid {
+        std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new(self.weights.sample(rng))))
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "average_rating": self.average_rating,
+            "tier": self.tier.as_str(),
+            "weights": self.weights.to_json()
+        })
+    }
+}
+
+/// Where premium (multiplier and gem) cells may go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlacementRules {
+    /// No two premium cells touch, diagonals included
+    pub no_adjacent: bool,
+    pub no_corners: bool,
+    /// Keep premiums off the outer ring (corners included)
+    pub no_edges: bool,
+}
+
+impl PlacementRules {
+    pub fn is_unrestricted(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check if a premium may go on a cell.
+    pub fn allows(&self, pos: Position) -> bool {
+        let last = GRID_SIZE - 1;
+        let on_edge = |i: usize| i == 0 || i == last;
+        let corner = on_edge(pos.row) && on_edge(pos.col);
+        let edge = on_edge(pos.row) || on_edge(pos.col);
+        !(self.no_corners && corner || self.no_edges && edge)
+    }
+
+    /// Most premium cells that fit on an empty board under these rules.
+    pub fn capacity(&self) -> usize {
+        let cells: Vec<Position> = all_positions().collect();
+        self.pick(&cells, cells.len()).len()
+    }
+
+    /// Choose up to `count` cells from `candidates`, preferring them in
+    /// order. Returns fewer only if `count` cells can't satisfy the rules.
+    fn pick(&self, candidates: &[Position], count: usize) -> Vec<Position> {
+        let allowed: Vec<Position> =
+            candidates.iter().copied().filter(|p| self.allows(*p)).collect();
+        if !self.no_adjacent {
+            return allowed.into_iter().take(count).collect();
+        }
+        let mut best = Vec::new();
+        pick_apart(&allowed, count, &mut Vec::new(), &mut best);
+        best
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "no_adjacent": self.no_adjacent,
+            "no_corners": self.no_corners,
+            "no_edges": self.no_edges
+        })
+    }
+}
+
+/// Depth-first search for `count` mutually non-adjacent cells, keeping the
+/// largest set seen in `best`.
+fn pick_apart(
+    candidates: &[Position],
+    count: usize,
+    chosen: &mut Vec<Position>,
+    best: &mut Vec<Position>,
+) {
+    if chosen.len() > best.len() {
+        *best = chosen.clone();
+    }
+    if best.len() >= count || chosen.len() + candidates.len() <= best.len() {
+        return;
+    }
+    let Some((first, rest)) = candidates.split_first() else {
+        return;
+    };
+    if chosen.iter().all(|c| c != first && !c.is_adjacent_to(first)) {
+        chosen.push(*first);
+        pick_apart(rest, count, chosen, best);
+        chosen.pop();
+    }
+    pick_apart(rest, count, chosen, best);
+}
+
+/// Premium cells for generated boards: how many of each, and where they
+/// may go.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PremiumLayout {
+    pub multipliers: Vec<Multiplier>,
+    pub gems: usize,
+    pub rules: PlacementRules,
+}
+
+impl PremiumLayout {
+    /// Fail with `InvalidGridConfig` if the premiums can't all be placed on
+    /// one board under the rules.
+    pub fn validate(&self) -> Result<(), GameError> {
+        let needed = if self.rules.is_unrestricted() {
+            self.multipliers.len().max(self.gems)
+        } else {
+            self.multipliers.len() + self.gems
+        };
+        if needed > self.rules.capacity() {
+            return Err(GameError::InvalidGridConfig);
+        }
+        Ok(())
+    }
+
+    /// Put the premiums on random open cells of a board, replacing any it
+    /// already had. Unrestricted layouts may put a gem on a multiplier cell;
+    /// otherwise each premium gets a cell of its own.
+    pub fn apply(&self, grid: &mut Grid, rng: &mut SeededRng) -> Result<(), GameError> {
+        self.validate()?;
+        let mut cells: Vec<Position> =
+            all_positions().filter(|p| !grid[p.row][p.col].blocked).collect();
+        for pos in &cells {
+            grid[pos.row][pos.col].multiplier = None;
+            grid[pos.row][pos.col].has_gem = false;
+        }
+        rng.shuffle(&mut cells);
+        if self.rules.is_unrestricted() {
+            place_premiums(grid, &cells, &self.multipliers, self.gems, rng);
+            return Ok(());
+        }
+
+        let picked = self.rules.pick(&cells, self.multipliers.len() + self.gems);
+        if picked.len() < self.multipliers.len() + self.gems {
+            return Err(GameError::InvalidGridConfig);
+        }
+        let (multipliers, gems) = picked.split_at(self.multipliers.len());
+        for (pos, multiplier) in multipliers.iter().zip(&self.multipliers) {
+            grid[pos.row][pos.col].multiplier = Some(*multiplier);
+        }
+        for pos in gems {
+            grid[pos.row][pos.col].has_gem = true;
+        }
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "multipliers": self.multipliers.iter().map(|m| m.as_str()).collect::<Vec<_>>(),
+            "gems": self.gems,
+            "rules": self.rules.to_json()
+        })
+    }
+}
+
+/// Multipliers on the first cells, then gems on a fresh shuffle of the same
+/// cells (so a gem may share a multiplier's cell).
+fn place_premiums(
+    grid: &mut Grid,
+    cells: &[Position],
+    multipliers: &[Multiplier],
+    gems: usize,
+    rng: &mut SeededRng,
+) {
+    for (pos, multiplier) in cells.iter().zip(multipliers) {
+        grid[pos.row][pos.col].multiplier = Some(*multiplier);
+    }
+    let mut cells = cells.to_vec();
+    rng.shuffle(&mut cells);
+    for pos in cells.iter().take(gems) {
+        grid[pos.row][pos.col].has_gem = true;
+    }
+}
+
+/// Builds complete boards: letters drawn with a `LetterWeighting`, a minimum
+/// vowel count, and multiplier and gem cells on random positions, optionally
+/// constrained by `PlacementRules`.
+#[derive(Debug, Clone)]
+pub struct GridBuilder {
+    weighting: LetterWeighting,
+    min_vowels: usize,
+    multipliers: Vec<Multiplier>,
+    gems: usize,
+    blocked: usize,
+    placement: PlacementRules,
+}
+
+impl Default for GridBuilder {
+    /// Standard letter weights, one double-letter and one double-word cell,
+    /// no gems.
+    fn default() -> Self {
+        Self {
+            weighting: LetterWeighting::default(),
+            min_vowels: DEFAULT_MIN_VOWELS,
+            multipliers: vec![Multiplier::DoubleLetter, Multiplier::DoubleWord],
+            gems: 0,
+            blocked: 0,
+            placement: PlacementRules::default(),
+        }
+    }
+}
+
+impl GridBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_weights(mut self, weights: LetterWeights) -> Self {
+        self.weighting.weights = weights;
+        self
+    }
+
+    /// Draw letters with a weighting resolved by [`GridConfig::resolve`].
+    pub fn with_weighting(mut self, weighting: LetterWeighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
+    /// Guarantee at least this many vowels (capped at the board size).
+    pub fn with_min_vowels(mut self, min_vowels: usize) -> Self {
+        self.min_vowels = min_vowels;
+        self
+    }
+
+    /// Place these multipliers, one per cell (extras beyond the board size are dropped).
+    pub fn with_multipliers(mut self, multipliers: Vec<Multiplier>) -> Self {
+        self.multipliers = multipliers;
+        self
+    }
+
+    pub fn with_gems(mut self, gems: usize) -> Self {
+        self.gems = gems;
+        self
+    }
+
+    /// Block this many cells, for obstacle variants. Blocked cells never get
+    /// bonuses and don't count towards the vowel floor.
+    pub fn with_blocked(mut self, blocked: usize) -> Self {
+        self.blocked = blocked;
+        self
+    }
+
+    /// Take the multipliers, gems and placement rules from a layout.
+    pub fn with_premiums(mut self, layout: PremiumLayout) -> Self {
+        self.multipliers = layout.multipliers;
+        self.gems = layout.gems;
+        self.placement = layout.rules;
+        self
+    }
+
+    /// The weighting letters are drawn with.
+    pub fn weighting(&self) -> &LetterWeighting {
+        &self.weighting
+    }
+
+    /// Constrain where multipliers and gems go. With any rule set, every
+    /// premium gets a cell of its own and cells are blocked around them.
+    pub fn with_placement(mut self, rules: PlacementRules) -> Self {
+        self.placement = rules;
+        self
+    }
+
+    fn premiums(&self) -> PremiumLayout {
+        PremiumLayout {
+            multipliers: self.multipliers.clone(),
+            gems: self.gems,
+            rules: self.placement,
+        }
+    }
+
+    /// Fail with `InvalidGridConfig` if the premiums can't all be placed, or
+    /// leave too few cells to block.
+    pub fn validate(&self) -> Result<(), GameError> {
+        let premiums = self.premiums();
+        premiums.validate()?;
+        if !self.placement.is_unrestricted()
+            && premiums.multipliers.len() + premiums.gems + self.blocked > GRID_SIZE * GRID_SIZE
+        {
+            return Err(GameError::InvalidGridConfig);
+        }
+        Ok(())
+    }
+
+    /// Generate a board, failing loudly on settings [`GridBuilder::build`]
+    /// could only satisfy in part.
+    pub fn try_build(&self, rng: &mut SeededRng) -> Result<Grid, GameError> {
+        self.validate()?;
+        Ok(self.build(rng))
+    }
+
+    /// Generate a board with at least `min` quality under `dict`.
+    ///
+    /// Boards are solved as they are built and rejected until one qualifies;
+    /// `GridQualityTooLow` if none of `attempts` boards does.
+    pub fn try_build_playable(
+        &self,
+        rng: &mut SeededRng,
+        dict: &dyn Dictionary,
+        min_word_length: usize,
+        min: &GridQuality,
+        attempts: usize,
+    ) -> Result<Grid, GameError> {
+        self.validate()?;
+        for _ in 0..attempts {
+            let grid = self.build(rng);
+            let words = solver::solve(&grid, dict, min_word_length);
+            if GridQuality::from_words(&words).meets(min) {
+                return Ok(grid);
+            }
+        }
+        Err(GameError::GridQualityTooLow)
+    }
+
+    /// Generate a board from a seed; the same seed always gives the same board.
+    pub fn build_seeded(&self, seed: u64) -> Grid {
+        self.build(&mut SeededRng::new(seed))
+    }
+
+    /// Generate a board. Extra bonuses that don't fit are dropped; see
+    /// [`GridBuilder::try_build`].
+    pub fn build(&self, rng: &mut SeededRng) -> Grid {
+        let mut grid = self.weighting.generate(rng);
+
+        let vowel_weights = LetterWeights(std::array::from_fn(|i| {
+            let letter = (b'A' + i as u8) as char;
+            if VOWELS.contains(&letter) {
+                self.weighting.weights.0[i].max(1)
+            } else {
+                0
+            }
+        }));
+        let mut cells: Vec<Position> = all_positions().collect();
+        rng.shuffle(&mut cells);
+        let premiums = if self.placement.is_unrestricted() {
+            Vec::new()
+        } else {
+            let picked = self.placement.pick(&cells, self.multipliers.len() + self.gems);
+            // Block around the premiums
+            cells.sort_by_key(|p| picked.contains(p));
+            picked
+        };
+        let blocked = (cells.len() - premiums.len()).min(self.blocked);
+        for pos in cells.drain(..blocked) {
+            grid[pos.row][pos.col].blocked = true;
+        }
+
+        let mut consonants: Vec<Position> = cells
+            .iter()
+            .copied()
+            .filter(|p| !VOWELS.contains(&grid[p.row][p.col].letter))
+            .collect();
+        rng.shuffle(&mut consonants);
+        let vowels = cells.len() - consonants.len();
+        let missing = self.min_vowels.saturating_sub(vowels);
+        for pos in consonants.into_iter().take(missing) {
+            grid[pos.row][pos.col] = GridCell::new(vowel_weights.sample(rng));
+        }
+
+        if self.placement.is_unrestricted() {
+            rng.shuffle(&mut cells);
+            place_premiums(&mut grid, &cells, &self.multipliers, self.gems, rng);
+            return grid;
+        }
+        let (multipliers, gems) = premiums.split_at(self.multipliers.len().min(premiums.len()));
+        for (pos, multiplier) in multipliers.iter().zip(&self.multipliers) {
+            grid[pos.row][pos.col].multiplier = Some(*multiplier);
+        }
+        for pos in gems {
+            grid[pos.row][pos.col].has_gem = true;
+        }
+        grid
+    }
+}
+
+fn all_positions() -> impl Iterator<Item = Position> {
+    (0..GRID_SIZE).flat_map(|row| (0..GRID_SIZE).map(move |col| Position::new(row, col)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::dictionary::WordList;
+
+    #[test]
+    fn test_resolve_by_rating() {
+        let config = GridConfig {
+            adaptive_difficulty: true,
+            ..Default::default()
+        };
+
+        let beginner = config.resolve(&[Some(800), Some(900), None]);
+        assert_eq!(beginner.average_rating, Some(850));
+        assert_eq!(beginner.tier, DifficultyTier::Beginner);
+        assert!(beginner.weights.get('E') > STANDARD_LETTER_WEIGHTS[4]);
+        assert!(beginner.weights.get('J') < STANDARD_LETTER_WEIGHTS[9]);
+
+        assert_eq!(config.resolve(&[None]).tier, DifficultyTier::Standard);
+        assert_eq!(config.resolve(&[Some(2000)]).tier, DifficultyTier::Expert);
+
+        // Disabled: always standard
+        let fixed = GridConfig::default().resolve(&[Some(800)]);
+        assert_eq!(fixed.tier, DifficultyTier::Standard);
+        assert_eq!(fixed.weights, LetterWeights::default());
+
+        // Excluded letters stay excluded; huge weights don't overflow
+        let mut weights = LetterWeights::default();
+        weights.0[0] = 0;
+        weights.0[4] = u32::MAX;
+        let scaled = DifficultyTier::Beginner.adjust(weights);
+        assert_eq!(scaled.get('A'), 0);
+        assert_eq!(scaled.get('E'), u32::MAX);
+    }
+
+    #[test]
+    fn test_generate_uses_weights() {
+        let mut weights = [0; 26];
+        weights[4] = 1; // Only E
+        let weighting = LetterWeighting {
+            average_rating: None,
+            tier: DifficultyTier::Standard,
+            weights: LetterWeights(weights),
+        };
+
+        let grid = weighting.generate(&mut SeededRng::new(1));
+        assert!(grid.iter().flatten().all(|c| c.letter == 'E'));
+    }
+
+    #[test]
+    fn test_builder_vowels_and_bonuses() {
+        let mut weights = [0; 26];
+        weights[25] = 1; // Only Z
+        let builder = GridBuilder::new()
+            .with_weights(LetterWeights(weights))
+            .with_min_vowels(7)
+            .with_multipliers(vec![Multiplier::TripleLetter, Multiplier::DoubleWord])
+            .with_gems(3);
+
+        let grid = builder.build(&mut SeededRng::new(4));
+        let cells = || grid.iter().flatten();
+        assert_eq!(cells().filter(|c| VOWELS.contains(&c.letter)).count(), 7);
+        assert_eq!(cells().filter(|c| c.multiplier.is_some()).count(), 2);
+        assert_eq!(cells().filter(|c| c.has_gem).count(), 3);
+
+        // Reproducible from the seed
+        let again = builder.build(&mut SeededRng::new(4));
+        assert!(cells().zip(again.iter().flatten()).all(|(a, b)| a.letter == b.letter));
+
+        let grid = builder.with_blocked(20).build(&mut SeededRng::new(4));
+        let open: Vec<&GridCell> = grid.iter().flatten().filter(|c| !c.blocked).collect();
+        assert_eq!(open.len(), 5);
+        assert_eq!(open.iter().filter(|c| VOWELS.contains(&c.letter)).count(), 5);
+        assert!(grid.iter().flatten().filter(|c| c.blocked).all(|c| c.multiplier.is_none()));
+    }
+
+    #[test]
+    fn test_placement_rules() {
+        let apart = PlacementRules {
+            no_adjacent: true,
+            no_corners: true,
+            ..Default::default()
+        };
+        assert_eq!(PlacementRules::default().capacity(), 25);
+        assert_eq!(apart.capacity(), 7);
+        let inner = PlacementRules {
+            no_adjacent: true,
+            no_edges: true,
+            ..Default::default()
+        };
+        assert_eq!(inner.capacity(), 4);
+
+        let builder = GridBuilder::new()
+            .with_multipliers(vec![Multiplier::DoubleLetter; 4])
+            .with_gems(3)
+            .with_blocked(10)
+            .with_placement(apart);
+        for seed in 0..5 {
+            let grid = builder.try_build(&mut SeededRng::new(seed)).unwrap();
+            let premiums: Vec<Position> = all_positions()
+                .filter(|p| grid[p.row][p.col].multiplier.is_some() || grid[p.row][p.col].has_gem)
+                .collect();
+            assert_eq!(premiums.len(), 7);
+            assert!(premiums.iter().all(|p| apart.allows(*p) && !grid[p.row][p.col].blocked));
+            for (i, a) in premiums.iter().enumerate() {
+                assert!(premiums[i + 1..].iter().all(|b| !a.is_adjacent_to(b)));
+            }
+        }
+
+        // One premium too many fails loudly
+        let crowded = builder.with_gems(4);
+        let err = crowded.try_build(&mut SeededRng::new(0)).unwrap_err();
+        assert_eq!(err, GameError::InvalidGridConfig);
+        let layout = PremiumLayout {
+            multipliers: Vec::new(),
+            gems: 5,
+            rules: inner,
+        };
+        let mut grid = GridBuilder::new().build_seeded(1);
+        let err = layout.apply(&mut grid, &mut SeededRng::new(1)).unwrap_err();
+        assert_eq!(err, GameError::InvalidGridConfig);
+    }
+
+    #[test]
+    fn test_try_build_playable() {
+        let dict = WordList::from_words(["a", "e", "i", "o", "u"]);
+        let builder = GridBuilder::new();
+
+        // The vowel floor guarantees single-letter words
+        let min = GridQuality {
+            word_count: 1,
+            ..Default::default()
+        };
+        let grid = builder
+            .try_build_playable(&mut SeededRng::new(2), &dict, 1, &min, 1)
+            .unwrap();
+        let letters = |g: &Grid| g.iter().flatten().map(|c| c.letter).collect::<String>();
+        assert_eq!(letters(&grid), letters(&builder.build_seeded(2)));
+
+        // Every board is rejected
+        let unreachable = GridQuality {
+            word_count: 6,
+            ..Default::default()
+        };
+        let err = builder
+            .try_build_playable(&mut SeededRng::new(2), &dict, 1, &unreachable, 3)
+            .unwrap_err();
+        assert_eq!(err, GameError::GridQualityTooLow);
+    }
+}
@@ -0,0 +1,164 @@
+//! Per-guild resource quotas.
+//!
+//! All guilds share one process, so a single large guild must not be able to
+//! starve the others. Usage is counted from the managers on demand (so it can
+//! never drift from the real state) and checked by the `AppState`
+//! orchestration helpers before anything new is added.
+
+use std::collections::HashMap;
+
+/// Kinds of per-guild resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuildResource {
+    Connections,
+    Lobbies,
+    Games,
+}
+
+impl GuildResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Connections => "connections",
+            Self::Lobbies => "lobbies",
+            Self::Games => "games",
+        }
+    }
+}
+
+/// Limits for a single guild. `None` means unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GuildQuota {
+    pub max_connections: Option<usize>,
+    pub max_lobbies: Option<usize>,
+    pub max_games: Option<usize>,
+}
+
+impl GuildQuota {
+    /// Get the limit for a resource.
+    pub fn limit(&self, resource: GuildResource) -> Option<usize> {
+        match resource {
+            GuildResource::Connections => self.max_connections,
+            GuildResource::Lobbies => self.max_lobbies,
+            GuildResource::Games => self.max_games,
+        }
+    }
+}
+
+/// Quota configuration: a default plus per-guild overrides.
+#[derive(Debug, Clone, Default)]
+pub struct GuildQuotas {
+    /// Applied to guilds without an override
+    pub default: GuildQuota,
+    overrides: HashMap<String, GuildQuota>,
+}
+
+impl GuildQuotas {
+    pub fn new(default: GuildQuota) -> Self {
+        Self {
+            default,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Set a guild-specific quota.
+    pub fn set(&mut self, guild_id: String, quota: GuildQuota) {
+        self.overrides.insert(guild_id, quota);
+    }
+
+    /// Remove a guild-specific quota, falling back to the default.
+    pub fn clear(&mut self, guild_id: &str) -> Option<GuildQuota> {
+        self.overrides.remove(guild_id)
+    }
+
+    /// Get the quota that applies to a guild.
+    pub fn for_guild(&self, guild_id: &str) -> &GuildQuota {
+        self.overrides.get(guild_id).unwrap_or(&self.default)
+    }
+}
+
+/// Current resource usage of a guild, alongside its quota.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuildUsage {
+    pub guild_id: String,
+    pub connections: usize,
+    pub lobbies: usize,
+    pub games: usize,
+    pub quota: GuildQuota,
+}
+
+impl GuildUsage {
+    /// Get the current count for a resource.
+    pub fn count(&self, resource: GuildResource) -> usize {
+        match resource {
+            GuildResource::Connections => self.connections,
+            GuildResource::Lobbies => self.lobbies,
+            GuildResource::Games => self.games,
+        }
+    }
+
+    /// Check if one more of `resource` would fit within the quota.
+    pub fn has_room_for(&self, resource: GuildResource) -> bool {
+        self.quota
+            .limit(resource)
+            .is_none_or(|limit| self.count(resource) < limit)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "guild_id": self.guild_id,
+            "connections": self.connections,
+            "lobbies": self.lobbies,
+            "games": self.games,
+            "quota": {
+                "max_connections": self.quota.max_connections,
+                "max_lobbies": self.quota.max_lobbies,
+                "max_games": self.quota.max_games
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quota_overrides() {
+        let mut quotas = GuildQuotas::new(GuildQuota {
+            max_games: Some(2),
+            ..Default::default()
+        });
+        quotas.set(
+            "big".to_string(),
+            GuildQuota {
+                max_games: Some(10),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(quotas.for_guild("small").max_games, Some(2));
+        assert_eq!(quotas.for_guild("big").max_games, Some(10));
+
+        quotas.clear("big");
+        assert_eq!(quotas.for_guild("big").max_games, Some(2));
+    }
+
+    #[test]
+    fn test_usage_has_room() {
+        let usage = GuildUsage {
+            guild_id: "g".to_string(),
+            connections: 3,
+            lobbies: 1,
+            games: 0,
+            quota: GuildQuota {
+                max_connections: Some(3),
+                max_lobbies: None,
+                max_games: Some(1),
+            },
+        };
+
+        assert!(!usage.has_room_for(GuildResource::Connections));
+        assert!(usage.has_room_for(GuildResource::Lobbies));
+        assert!(usage.has_room_for(GuildResource::Games));
+    }
+}
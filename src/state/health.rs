@@ -0,0 +1,102 @@
+//! Health checks.
+//!
+//! `AppState::check_invariants` cross-checks the managers' indexes and
+//! player state machines; `AppState::self_test` runs a scripted session
+//! through a throwaway `AppState` so operators can check real state logic
+//! from a health endpoint.
+
+/// A broken cross-manager invariant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvariantViolation {
+    /// Short identifier of the invariant
+    pub invariant: &'static str,
+    pub detail: String,
+}
+
+impl InvariantViolation {
+    pub fn new(invariant: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            invariant,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "invariant": self.invariant,
+            "detail": self.detail
+        })
+    }
+}
+
+/// Outcome of one scripted self-test step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestStep {
+    pub name: &'static str,
+    /// Error message if the step failed
+    pub error: Option<String>,
+}
+
+impl SelfTestStep {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name,
+            "ok": self.error.is_none(),
+            "error": self.error
+        })
+    }
+}
+
+/// Result of `AppState::self_test`.
+#[derive(Debug, Clone, Default)]
+pub struct SelfTestReport {
+    /// Steps that ran, in order (the script stops at the first failure)
+    pub steps: Vec<SelfTestStep>,
+    /// Invariant violations found along the way
+    pub violations: Vec<InvariantViolation>,
+    pub elapsed: std::time::Duration,
+}
+
+impl SelfTestReport {
+    /// Check if every step succeeded and no invariant was violated.
+    pub fn passed(&self) -> bool {
+        self.steps.iter().all(|s| s.error.is_none()) && self.violations.is_empty()
+    }
+
+    /// Record a step's outcome. Returns true if it succeeded.
+    pub(crate) fn step<E: std::fmt::Display>(
+        &mut self,
+        name: &'static str,
+        result: Result<(), E>,
+    ) -> bool {
+        let error = result.err().map(|e| e.to_string());
+        let ok = error.is_none();
+        self.steps.push(SelfTestStep { name, error });
+        ok
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "passed": self.passed(),
+            "steps": self.steps.iter().map(|s| s.to_json()).collect::<Vec<_>>(),
+            "violations": self.violations.iter().map(|v| v.to_json()).collect::<Vec<_>>(),
+            "elapsed_ms": self.elapsed.as_millis() as u64
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_passed() {
+        let mut report = SelfTestReport::default();
+        assert!(report.step::<String>("ok", Ok(())));
+        assert!(report.passed());
+
+        assert!(!report.step("broken", Err("boom")));
+        assert!(!report.passed());
+        assert_eq!(report.to_json()["steps"][1]["error"], "boom");
+    }
+}
@@ -0,0 +1,94 @@
+//! Word hints.
+//!
+//! `Game::generate_hint` picks the best unused word on the board (skipping
+//! words the player was already hinted) and reveals part of it. Hints are
+//! priced in gems and may be capped per player via `HintConfig` on the
+//! game's config.
+
+use super::game::Position;
+
+/// How much of the word a hint gives away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HintStyle {
+    /// The first letter and the word's length
+    #[default]
+    FirstLetter,
+    /// The first two cells of the word's path
+    FirstCells,
+}
+
+/// Price and limits of hints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HintConfig {
+    /// Gems per hint
+    pub cost: i32,
+    /// Hints each player may take over the game (None = unlimited)
+    pub max_per_player: Option<u32>,
+    pub style: HintStyle,
+}
+
+impl Default for HintConfig {
+    fn default() -> Self {
+        Self {
+            cost: 1,
+            max_per_player: Some(3),
+            style: HintStyle::default(),
+        }
+    }
+}
+
+/// A partial reveal of an unused word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    FirstLetter { letter: char, length: usize },
+    FirstCells { cells: Vec<Position>, length: usize },
+}
+
+impl Hint {
+    /// Build a hint for a word traced along `path`.
+    pub fn new(style: HintStyle, word: &str, path: &[Position]) -> Self {
+        let length = path.len();
+        match style {
+            HintStyle::FirstLetter => Self::FirstLetter {
+                letter: word.chars().next().unwrap_or_default(),
+                length,
+            },
+            HintStyle::FirstCells => Self::FirstCells {
+                cells: path.iter().take(2).copied().collect(),
+                length,
+            },
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::FirstLetter { letter, length } => serde_json::json!({
+                "type": "first_letter",
+                "letter": letter.to_string(),
+                "length": length
+            }),
+            Self::FirstCells { cells, length } => serde_json::json!({
+                "type": "first_cells",
+                "cells": cells.iter().map(|p| p.to_json()).collect::<Vec<_>>(),
+                "length": length
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hint_styles() {
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(1, 1)];
+
+        let hint = Hint::new(HintStyle::FirstLetter, "CAT", &path);
+        assert_eq!(hint, Hint::FirstLetter { letter: 'C', length: 3 });
+
+        let hint = Hint::new(HintStyle::FirstCells, "CAT", &path);
+        assert_eq!(hint.to_json()["cells"][1]["col"], 1);
+        assert_eq!(hint.to_json()["length"], 3);
+    }
+}
@@ -0,0 +1,266 @@
+//! Localized letter sets.
+//!
+//! A `LetterSet` is a language's tile economy: which tiles appear on
+//! generated boards, how often, and what each is worth. Digraph tiles such as
+//! Spanish "LL" take up one cell but spell two letters. Games pick a set
+//! through `GridConfig::letters`; without one, boards use the English letters
+//! valued by `GameConfig::letter_values`.
+
+use super::game::{Grid, GridCell};
+use super::generation::STANDARD_LETTER_WEIGHTS;
+use super::rng::SeededRng;
+
+/// Standard English point values, A to Z.
+pub const STANDARD_LETTER_VALUES: [u8; 26] = [
+    1, 3, 3, 2, 1, 4, 2, 4, 1, 8, 5, 1, 3, 1, 1, 3, 10, 1, 1, 1, 1, 4, 4, 8, 4, 10,
+];
+
+/// Point value of each English letter, A to Z. Anything else is worth 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LetterValues(pub [u8; 26]);
+
+impl Default for LetterValues {
+    fn default() -> Self {
+        Self(STANDARD_LETTER_VALUES)
+    }
+}
+
+impl LetterValues {
+    /// Point value of a letter.
+    pub fn get(&self, letter: char) -> u8 {
+        let upper = letter.to_ascii_uppercase();
+        if upper.is_ascii_uppercase() {
+            self.0[(upper as u8 - b'A') as usize]
+        } else {
+            1
+        }
+    }
+
+    /// Change the value of one letter, e.g. for house rules. Non-letters are
+    /// ignored.
+    pub fn with_value(mut self, letter: char, value: u8) -> Self {
+        let upper = letter.to_ascii_uppercase();
+        if upper.is_ascii_uppercase() {
+            self.0[(upper as u8 - b'A') as usize] = value;
+        }
+        self
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let values: serde_json::Map<String, serde_json::Value> = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (((b'A' + i as u8) as char).to_string(), (*v).into()))
+            .collect();
+        serde_json::Value::Object(values)
+    }
+}
+
+/// One kind of tile in a letter set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub letter: char,
+    /// Second letter of a digraph tile
+    pub digraph: Option<char>,
+    /// Relative frequency on generated boards
+    pub weight: u32,
+    pub value: u8,
+}
+
+impl Tile {
+    pub fn new(letter: char, weight: u32, value: u8) -> Self {
+        Self {
+            letter,
+            digraph: None,
+            weight,
+            value,
+        }
+    }
+
+    pub fn digraph(letters: [char; 2], weight: u32, value: u8) -> Self {
+        Self {
+            letter: letters[0],
+            digraph: Some(letters[1]),
+            weight,
+            value,
+        }
+    }
+
+    /// The letters on the tile, e.g. "LL".
+    pub fn text(&self) -> String {
+        std::iter::once(self.letter).chain(self.digraph).collect()
+    }
+
+    /// A plain cell showing this tile.
+    pub fn cell(&self) -> GridCell {
+        let mut cell = GridCell::new(self.letter);
+        cell.digraph = self.digraph;
+        cell.value = self.value;
+        cell
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "letter": self.text(),
+            "weight": self.weight,
+            "value": self.value
+        })
+    }
+}
+
+/// A language's tiles, their frequencies and point values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterSet {
+    /// Language subtag, e.g. "es"
+    pub language: String,
+    tiles: Vec<Tile>,
+}
+
+impl Default for LetterSet {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl LetterSet {
+    pub fn new(language: &str, tiles: Vec<Tile>) -> Self {
+        Self {
+            language: language.to_string(),
+            tiles,
+        }
+    }
+
+    /// A to Z with the standard weights and values.
+    pub fn english() -> Self {
+        let tiles = STANDARD_LETTER_WEIGHTS
+            .iter()
+            .zip(STANDARD_LETTER_VALUES)
+            .enumerate()
+            .map(|(i, (weight, value))| Tile::new((b'A' + i as u8) as char, *weight, value))
+            .collect();
+        Self::new("en", tiles)
+    }
+
+    /// Spanish tiles, including CH, LL, RR and Ñ.
+    pub fn spanish() -> Self {
+        let singles = [
+            ('A', 12, 1), ('B', 2, 3), ('C', 4, 3), ('D', 5, 2), ('E', 12, 1), ('F', 1, 4),
+            ('G', 2, 2), ('H', 2, 4), ('I', 6, 1), ('J', 1, 8), ('L', 4, 1), ('M', 2, 3),
+            ('N', 5, 1), ('Ñ', 1, 8), ('O', 9, 1), ('P', 2, 3), ('Q', 1, 5), ('R', 5, 1),
+            ('S', 6, 1), ('T', 4, 1), ('U', 5, 1), ('V', 1, 4), ('X', 1, 8), ('Y', 1, 4),
+            ('Z', 1, 10),
+        ];
+        let mut tiles: Vec<Tile> = singles.iter().map(|(l, w, v)| Tile::new(*l, *w, *v)).collect();
+        tiles.push(Tile::digraph(['C', 'H'], 1, 5));
+        tiles.push(Tile::digraph(['L', 'L'], 1, 8));
+        tiles.push(Tile::digraph(['R', 'R'], 1, 8));
+        Self::new("es", tiles)
+    }
+
+    /// German tiles, including Ä, Ö and Ü.
+    pub fn german() -> Self {
+        let singles = [
+            ('A', 5, 1), ('Ä', 1, 6), ('B', 2, 3), ('C', 2, 4), ('D', 4, 1), ('E', 15, 1),
+            ('F', 2, 4), ('G', 3, 2), ('H', 4, 2), ('I', 6, 1), ('J', 1, 6), ('K', 2, 4),
+            ('L', 3, 2), ('M', 4, 3), ('N', 9, 1), ('O', 3, 2), ('Ö', 1, 8), ('P', 1, 4),
+            ('Q', 1, 10), ('R', 6, 1), ('S', 7, 1), ('T', 6, 1), ('U', 6, 1), ('Ü', 1, 6),
+            ('V', 1, 6), ('W', 1, 3), ('X', 1, 8), ('Y', 1, 10), ('Z', 1, 3),
+        ];
+        let tiles = singles.iter().map(|(l, w, v)| Tile::new(*l, *w, *v)).collect();
+        Self::new("de", tiles)
+    }
+
+    /// The built-in set for a locale's language, e.g. "es-MX" -> Spanish.
+    pub fn for_locale(locale: &str) -> Option<Self> {
+        let language = locale.split('-').next().unwrap_or_default();
+        match language.to_ascii_lowercase().as_str() {
+            "en" => Some(Self::english()),
+            "es" => Some(Self::spanish()),
+            "de" => Some(Self::german()),
+            _ => None,
+        }
+    }
+
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    /// Find a tile by its letters.
+    pub fn tile(&self, letter: char, digraph: Option<char>) -> Option<&Tile> {
+        self.tiles
+            .iter()
+            .find(|t| t.letter == letter && t.digraph == digraph)
+    }
+
+    /// Points for a tile (1 for tiles not in the set).
+    pub fn value(&self, letter: char, digraph: Option<char>) -> u8 {
+        self.tile(letter, digraph).map_or(1, |t| t.value)
+    }
+
+    /// Draw a random tile. Returns None if every weight is zero.
+    pub fn sample(&self, rng: &mut SeededRng) -> Option<&Tile> {
+        let weights: Vec<u32> = self.tiles.iter().map(|t| t.weight).collect();
+        rng.choose_weighted(&weights).map(|i| &self.tiles[i])
+    }
+
+    /// Generate a board of tiles drawn independently by weight.
+    pub fn generate(&self, rng: &mut SeededRng) -> Grid {
+        std::array::from_fn(|_| {
+            std::array::from_fn(|_| self.sample(rng).map_or_else(|| GridCell::new('A'), Tile::cell))
+        })
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "language": self.language,
+            "tiles": self.tiles.iter().map(|t| t.to_json()).collect::<Vec<_>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_sets() {
+        let english = LetterSet::default();
+        assert_eq!(english.tiles().len(), 26);
+        assert_eq!(english.value('Q', None), 10);
+
+        let spanish = LetterSet::for_locale("es-MX").unwrap();
+        assert_eq!(spanish.language, "es");
+        assert_eq!(spanish.value('L', Some('L')), 8);
+        assert_eq!(spanish.value('L', None), 1);
+        assert_eq!(spanish.tile('R', Some('R')).unwrap().text(), "RR");
+        assert_eq!(LetterSet::german().value('Ö', None), 8);
+        assert!(LetterSet::for_locale("pt-BR").is_none());
+    }
+
+    #[test]
+    fn test_letter_values() {
+        let values = LetterValues::default();
+        assert_eq!(values.get('A'), 1);
+        assert_eq!(values.get('e'), 1);
+        assert_eq!(values.get('D'), 2);
+        assert_eq!(values.get('B'), 3);
+        assert_eq!(values.get('K'), 5);
+        assert_eq!(values.get('X'), 8);
+        assert_eq!(values.get('Z'), 10);
+        assert_eq!(values.get('-'), 1);
+
+        let house = values.with_value('q', 6);
+        assert_eq!(house.get('Q'), 6);
+        assert_eq!(house.to_json()["Q"], 6);
+    }
+
+    #[test]
+    fn test_generate_digraphs() {
+        let set = LetterSet::new("es", vec![Tile::digraph(['L', 'L'], 1, 8)]);
+        let grid = set.generate(&mut SeededRng::new(3));
+        let cell = &grid[2][2];
+        assert_eq!((cell.letter, cell.digraph, cell.value), ('L', Some('L'), 8));
+        assert_eq!(cell.to_json()["letter"], "LL");
+    }
+}
@@ -3,11 +3,247 @@
 //! A lobby is a persistent container for players that can spawn games.
 //! Players must be in a lobby to play together.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::events::{EventBuffer, LobbyEvent, SequencedEvent};
+use super::game::GameConfig;
+use super::pagination::{Cursor, Page, SortOrder};
+use super::redaction::{self, RedactionProfile};
+use super::rng::SeededRng;
 
 /// Maximum players per lobby.
 pub const MAX_LOBBY_PLAYERS: usize = 6;
 
+/// Default time before a kicked player may rejoin the lobby (seconds).
+pub const DEFAULT_KICK_REJOIN_COOLDOWN_SECS: i64 = 5 * 60;
+
+/// Default time before a player who left during a ready check may rejoin (seconds).
+pub const DEFAULT_READY_LEAVE_REJOIN_COOLDOWN_SECS: i64 = 30;
+
+/// Default time a host offer stays open (seconds).
+pub const DEFAULT_HOST_OFFER_TIMEOUT_SECS: i64 = 60;
+
+/// Default time members have to answer a ready check (seconds).
+pub const DEFAULT_READY_CHECK_TIMEOUT_SECS: i64 = 30;
+
+/// Length of generated custom lobby codes.
+pub const LOBBY_CODE_LENGTH: usize = 6;
+
+/// Characters used in generated lobby codes (no 0/O or 1/I lookalikes).
+const LOBBY_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// Locale of lobbies that haven't chosen one.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Who can find a lobby.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    /// Listed in `LobbyManager::list_public` and offered by `find_joinable`
+    Public,
+    /// Joinable by anyone with the code, but not listed
+    #[default]
+    Unlisted,
+    /// Not listed, and only the host sees the join code
+    Private,
+}
+
+impl Visibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Unlisted => "unlisted",
+            Self::Private => "private",
+        }
+    }
+}
+
+/// Host-configurable lobby settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LobbySettings {
+    /// Language tag (e.g. "en", "pt-BR") telling clients which language pack,
+    /// letter values and error message translations to use. Carried onto
+    /// games created from the lobby.
+    pub locale: String,
+
+    pub visibility: Visibility,
+
+    /// Rounds per game (None = the game default)
+    pub rounds: Option<u8>,
+
+    /// Turn time limit in seconds (None = untimed)
+    pub turn_seconds: Option<u32>,
+
+    /// Minimum letters in a playable word (None = the game default)
+    pub min_word_length: Option<usize>,
+
+    /// Word list to check plays against, resolved by the caller
+    pub dictionary_id: Option<String>,
+}
+
+impl Default for LobbySettings {
+    fn default() -> Self {
+        Self {
+            locale: DEFAULT_LOCALE.to_string(),
+            visibility: Visibility::default(),
+            rounds: None,
+            turn_seconds: None,
+            min_word_length: None,
+            dictionary_id: None,
+        }
+    }
+}
+
+impl LobbySettings {
+    /// Check if a locale looks like a language tag: a 2-3 letter language,
+    /// optionally followed by `-` and alphanumeric subtags.
+    pub fn is_valid_locale(locale: &str) -> bool {
+        let mut parts = locale.split('-');
+        let language = parts.next().unwrap_or_default();
+        (2..=3).contains(&language.len())
+            && language.chars().all(|c| c.is_ascii_alphabetic())
+            && parts.all(|p| {
+                (1..=8).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphanumeric())
+            })
+    }
+
+    /// Check the settings are usable for a game.
+    pub fn validate(&self) -> Result<(), LobbyError> {
+        let valid = Self::is_valid_locale(&self.locale)
+            && self.rounds != Some(0)
+            && self.turn_seconds != Some(0)
+            && self.min_word_length.is_none_or(|len| len >= 2)
+            && self.dictionary_id.as_ref().is_none_or(|id| !id.is_empty());
+        if valid {
+            Ok(())
+        } else {
+            Err(LobbyError::InvalidSettings)
+        }
+    }
+
+    /// Apply the settings on top of a game configuration. Rounds aren't part
+    /// of `GameConfig`; `AppState::create_game_from_lobby` sets them on the
+    /// game.
+    pub fn game_config(&self, base: GameConfig) -> GameConfig {
+        GameConfig {
+            turn_time_limit: self
+                .turn_seconds
+                .map(|s| chrono::Duration::seconds(s as i64))
+                .or(base.turn_time_limit),
+            min_word_length: self.min_word_length.or(base.min_word_length),
+            dictionary_id: self.dictionary_id.clone().or(base.dictionary_id),
+            ..base
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "locale": self.locale,
+            "visibility": self.visibility.as_str(),
+            "rounds": self.rounds,
+            "turn_seconds": self.turn_seconds,
+            "min_word_length": self.min_word_length,
+            "dictionary_id": self.dictionary_id
+        })
+    }
+}
+
+/// A pending offer to hand over the host role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostOffer {
+    pub from: i64,
+    pub to: i64,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A round of confirmations before a game starts. Everyone who was a member
+/// when it started has to confirm with `Lobby::set_ready` before it expires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadyCheck {
+    /// Members who have to confirm
+    pub required: HashSet<i64>,
+    /// Answers so far (true = ready)
+    pub responses: HashMap<i64, bool>,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ReadyCheck {
+    pub fn is_confirmed(&self, player_id: i64) -> bool {
+        self.responses.get(&player_id) == Some(&true)
+    }
+
+    /// Check if every required member confirmed.
+    pub fn passed(&self) -> bool {
+        !self.required.is_empty() && self.required.iter().all(|id| self.is_confirmed(*id))
+    }
+
+    /// Required members who haven't answered yet.
+    pub fn pending(&self) -> impl Iterator<Item = i64> + '_ {
+        self.required
+            .iter()
+            .copied()
+            .filter(|id| !self.responses.contains_key(id))
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let sorted = |mut ids: Vec<i64>| {
+            ids.sort_unstable();
+            ids
+        };
+        let answered = |ready: bool| {
+            sorted(
+                self.responses
+                    .iter()
+                    .filter(|(_, r)| **r == ready)
+                    .map(|(id, _)| *id)
+                    .collect(),
+            )
+        };
+        serde_json::json!({
+            "required": sorted(self.required.iter().copied().collect()),
+            "confirmed": answered(true),
+            "declined": answered(false),
+            "expires_at": self.expires_at.to_rfc3339()
+        })
+    }
+}
+
+/// How long players must wait before rejoining a lobby they left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RejoinPolicy {
+    /// After being kicked
+    pub after_kick: chrono::Duration,
+    /// After leaving while members were readied up
+    pub after_ready_leave: chrono::Duration,
+}
+
+impl Default for RejoinPolicy {
+    fn default() -> Self {
+        Self {
+            after_kick: chrono::Duration::seconds(DEFAULT_KICK_REJOIN_COOLDOWN_SECS),
+            after_ready_leave: chrono::Duration::seconds(DEFAULT_READY_LEAVE_REJOIN_COOLDOWN_SECS),
+        }
+    }
+}
+
+/// Who a lobby ban applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BanTarget {
+    /// Database player ID
+    Player(i64),
+    /// Discord user ID, which also covers any other player account of theirs
+    User(String),
+}
+
+impl BanTarget {
+    /// Check if the ban covers a player.
+    pub fn matches(&self, player_id: i64, user_id: &str) -> bool {
+        match self {
+            Self::Player(banned) => *banned == player_id,
+            Self::User(banned) => banned == user_id,
+        }
+    }
+}
+
 /// Lobby types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[derive(Default)]
@@ -19,6 +255,15 @@ pub enum LobbyType {
     Custom,
 }
 
+impl LobbyType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Channel => "channel",
+            Self::Custom => "custom",
+        }
+    }
+}
+
 
 /// A player's state within a lobby.
 #[derive(Debug, Clone)]
@@ -43,6 +288,9 @@ pub struct LobbyMember {
 
     /// When player joined this lobby
     pub joined_at: chrono::DateTime<chrono::Utc>,
+
+    /// Skill rating, if the player has one
+    pub rating: Option<i32>,
 }
 
 impl LobbyMember {
@@ -60,8 +308,15 @@ impl LobbyMember {
             is_ready: false,
             is_connected: true,
             joined_at: chrono::Utc::now(),
+            rating: None,
         }
     }
+
+    /// Set the player's skill rating.
+    pub fn with_rating(mut self, rating: i32) -> Self {
+        self.rating = Some(rating);
+        self
+    }
 }
 
 /// Lobby state.
@@ -85,17 +340,35 @@ pub struct Lobby {
     /// Members indexed by player_id
     members: HashMap<i64, LobbyMember>,
 
+    /// Players waiting for a free seat, in arrival order
+    waitlist: VecDeque<LobbyMember>,
+
     /// Current host player ID
     pub host_id: Option<i64>,
 
     /// Maximum players allowed
     pub max_players: usize,
 
+    /// Host-configurable settings
+    pub settings: LobbySettings,
+
     /// Active game ID (if any)
     pub active_game_id: Option<String>,
 
+    /// Pending host transfer, waiting for the target to accept
+    host_offer: Option<HostOffer>,
+
+    /// Confirmations being collected before a game
+    ready_check: Option<ReadyCheck>,
+
+    /// Players and users barred from joining
+    bans: HashSet<BanTarget>,
+
     /// When lobby was created
     pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// Recent changes, for clients catching up
+    events: EventBuffer<LobbyEvent>,
 }
 
 impl Lobby {
@@ -109,15 +382,23 @@ impl Lobby {
             channel_id: Some(channel_id),
             guild_id,
             members: HashMap::new(),
+            waitlist: VecDeque::new(),
             host_id: None,
             max_players: MAX_LOBBY_PLAYERS,
+            settings: LobbySettings::default(),
             active_game_id: None,
+            host_offer: None,
+            ready_check: None,
+            bans: HashSet::new(),
             created_at: chrono::Utc::now(),
+            events: EventBuffer::default(),
         }
     }
 
-    /// Create a new custom lobby with code.
+    /// Create a new custom lobby with code. Codes are case-insensitive and
+    /// stored uppercase.
     pub fn new_custom(code: String) -> Self {
+        let code = code.to_uppercase();
         let id = format!("custom-{}", code);
         Self {
             id,
@@ -126,15 +407,86 @@ impl Lobby {
             channel_id: None,
             guild_id: None,
             members: HashMap::new(),
+            waitlist: VecDeque::new(),
             host_id: None,
             max_players: MAX_LOBBY_PLAYERS,
+            settings: LobbySettings::default(),
             active_game_id: None,
+            host_offer: None,
+            ready_check: None,
+            bans: HashSet::new(),
             created_at: chrono::Utc::now(),
+            events: EventBuffer::default(),
         }
     }
 
+    /// Change the lobby's locale. Language subtags are normalized to
+    /// lowercase.
+    pub fn set_locale(&mut self, locale: &str) -> Result<(), LobbyError> {
+        if !LobbySettings::is_valid_locale(locale) {
+            return Err(LobbyError::InvalidSettings);
+        }
+        let mut parts = locale.splitn(2, '-');
+        let mut normalized = parts.next().unwrap_or_default().to_ascii_lowercase();
+        if let Some(rest) = parts.next() {
+            normalized.push('-');
+            normalized.push_str(rest);
+        }
+
+        if self.settings.locale != normalized {
+            self.settings.locale = normalized.clone();
+            self.events.push(LobbyEvent::LocaleChanged { locale: normalized });
+        }
+        Ok(())
+    }
+
+    /// Replace the lobby's settings on behalf of the host. Not allowed while
+    /// a game is running.
+    pub fn update_settings(
+        &mut self,
+        player_id: i64,
+        settings: LobbySettings,
+    ) -> Result<(), LobbyError> {
+        if !self.is_host(player_id) {
+            return Err(LobbyError::NotHost);
+        }
+        if self.has_active_game() {
+            return Err(LobbyError::GameInProgress);
+        }
+        settings.validate()?;
+        self.set_locale(&settings.locale)?;
+        let settings = LobbySettings {
+            locale: self.settings.locale.clone(),
+            ..settings
+        };
+        if self.settings != settings {
+            self.settings = settings;
+            self.events.push(LobbyEvent::SettingsChanged);
+        }
+        Ok(())
+    }
+
     /// Add a member to the lobby.
     pub fn add_member(&mut self, member: LobbyMember) -> Result<(), LobbyError> {
+        self.check_admission(&member)?;
+
+        let player_id = member.player_id;
+        self.members.insert(player_id, member);
+        self.events.push(LobbyEvent::MemberJoined { player_id });
+
+        // First member becomes host (for custom lobbies)
+        if self.host_id.is_none() && self.lobby_type == LobbyType::Custom {
+            self.set_host(Some(player_id));
+        }
+
+        Ok(())
+    }
+
+    /// Check that a player could take a seat right now.
+    fn check_admission(&self, member: &LobbyMember) -> Result<(), LobbyError> {
+        if self.is_banned(member.player_id, &member.user_id) {
+            return Err(LobbyError::Banned);
+        }
         if self.is_full() {
             return Err(LobbyError::Full);
         }
@@ -142,28 +494,44 @@ impl Lobby {
         if self.members.contains_key(&member.player_id) {
             return Err(LobbyError::AlreadyMember);
         }
-
-        // First member becomes host (for custom lobbies)
-        if self.host_id.is_none() && self.lobby_type == LobbyType::Custom {
-            self.host_id = Some(member.player_id);
-        }
-
-        self.members.insert(member.player_id, member);
         Ok(())
     }
 
     /// Remove a member from the lobby.
     pub fn remove_member(&mut self, player_id: i64) -> Option<LobbyMember> {
         let member = self.members.remove(&player_id)?;
+        self.events.push(LobbyEvent::MemberLeft { player_id });
+        if self
+            .host_offer
+            .as_ref()
+            .is_some_and(|o| o.from == player_id || o.to == player_id)
+        {
+            self.withdraw_host_offer();
+        }
+        if let Some(check) = &mut self.ready_check {
+            check.required.remove(&player_id);
+            check.responses.remove(&player_id);
+            self.end_ready_check_if_passed();
+        }
 
         // If host left, assign new host
         if self.host_id == Some(player_id) {
-            self.host_id = self.members.keys().next().copied();
+            self.set_host(self.members.keys().next().copied());
         }
 
         Some(member)
     }
 
+    /// Change the host. A pending host offer made by the old host is
+    /// withdrawn.
+    fn set_host(&mut self, host_id: Option<i64>) {
+        if self.host_id != host_id {
+            self.withdraw_host_offer();
+            self.host_id = host_id;
+            self.events.push(LobbyEvent::HostChanged { host_id });
+        }
+    }
+
     /// Get a member by player ID.
     pub fn get_member(&self, player_id: i64) -> Option<&LobbyMember> {
         self.members.get(&player_id)
@@ -190,17 +558,101 @@ impl Lobby {
             .members
             .get_mut(&player_id)
             .ok_or(LobbyError::NotMember)?;
-        member.is_ready = ready;
+        if member.is_ready != ready {
+            member.is_ready = ready;
+            self.events.push(LobbyEvent::ReadyChanged { player_id, ready });
+        }
+        if let Some(check) = &mut self.ready_check {
+            if check.required.contains(&player_id) {
+                check.responses.insert(player_id, ready);
+                self.end_ready_check_if_passed();
+            }
+        }
         Ok(())
     }
 
+    /// Start a ready check that current members answer with `set_ready`
+    /// before `now + timeout`. Replaces any pending check. Members who
+    /// haven't confirmed when it expires are marked not ready, so readiness
+    /// from long ago can't start a game.
+    pub fn start_ready_check(
+        &mut self,
+        now: chrono::DateTime<chrono::Utc>,
+        timeout: chrono::Duration,
+    ) -> Result<(), LobbyError> {
+        if self.has_active_game() {
+            return Err(LobbyError::GameInProgress);
+        }
+        let mut players: Vec<i64> = self.member_ids().collect();
+        players.sort_unstable();
+        let expires_at = now + timeout;
+        self.ready_check = Some(ReadyCheck {
+            required: players.iter().copied().collect(),
+            responses: HashMap::new(),
+            expires_at,
+        });
+        self.events.push(LobbyEvent::ReadyCheckStarted {
+            players,
+            expires_at,
+        });
+        Ok(())
+    }
+
+    /// Get the pending ready check.
+    pub fn ready_check(&self) -> Option<&ReadyCheck> {
+        self.ready_check.as_ref()
+    }
+
+    fn end_ready_check_if_passed(&mut self) {
+        if self.ready_check.as_ref().is_some_and(ReadyCheck::passed) {
+            self.ready_check = None;
+            self.events.push(LobbyEvent::ReadyCheckEnded { passed: true });
+        }
+    }
+
+    /// Fail the pending ready check and unready everyone who didn't confirm.
+    fn expire_ready_check(&mut self) {
+        let Some(check) = self.ready_check.take() else {
+            return;
+        };
+        for (player_id, member) in self.members.iter_mut() {
+            if member.is_ready && !check.is_confirmed(*player_id) {
+                member.is_ready = false;
+                self.events.push(LobbyEvent::ReadyChanged {
+                    player_id: *player_id,
+                    ready: false,
+                });
+            }
+        }
+        self.events.push(LobbyEvent::ReadyCheckEnded { passed: false });
+    }
+
+    /// Clear every member's ready flag.
+    pub fn reset_ready(&mut self) {
+        for (player_id, member) in self.members.iter_mut() {
+            if member.is_ready {
+                member.is_ready = false;
+                self.events.push(LobbyEvent::ReadyChanged {
+                    player_id: *player_id,
+                    ready: false,
+                });
+            }
+        }
+    }
+
     /// Set player connection state.
     pub fn set_connected(&mut self, player_id: i64, connected: bool) -> Result<(), LobbyError> {
         let member = self
             .members
             .get_mut(&player_id)
             .ok_or(LobbyError::NotMember)?;
-        member.is_connected = connected;
+        if member.is_connected != connected {
+            member.is_connected = connected;
+            self.events.push(LobbyEvent::ConnectionChanged {
+                player_id,
+                connected,
+            });
+        }
         Ok(())
     }
 
@@ -260,6 +712,16 @@ impl Lobby {
         !self.members.is_empty() && self.members.values().all(|m| m.is_ready)
     }
 
+    /// Check if the lobby is listed publicly.
+    pub fn is_public(&self) -> bool {
+        self.settings.visibility == Visibility::Public
+    }
+
+    /// Check if a new player could take a seat right now.
+    pub fn is_joinable(&self) -> bool {
+        !self.is_full() && !self.has_active_game()
+    }
+
     /// Check if lobby has an active game.
     pub fn has_active_game(&self) -> bool {
         self.active_game_id.is_some()
@@ -267,7 +729,10 @@ impl Lobby {
 
     /// Set the active game.
     pub fn set_active_game(&mut self, game_id: Option<String>) {
-        self.active_game_id = game_id;
+        if self.active_game_id != game_id {
+            self.active_game_id = game_id.clone();
+            self.events.push(LobbyEvent::ActiveGameChanged { game_id });
+        }
     }
 
     /// Transfer host to another player.
@@ -275,10 +740,211 @@ impl Lobby {
         if !self.members.contains_key(&new_host_id) {
             return Err(LobbyError::NotMember);
         }
-        self.host_id = Some(new_host_id);
+        self.set_host(Some(new_host_id));
         Ok(())
     }
 
+    /// Queue for a seat. Returns the 1-based waitlist position.
+    pub fn join_waitlist(&mut self, member: LobbyMember) -> Result<usize, LobbyError> {
+        let player_id = member.player_id;
+        if self.is_banned(player_id, &member.user_id) {
+            return Err(LobbyError::Banned);
+        }
+        if self.has_member(player_id) || self.is_waitlisted(player_id) {
+            return Err(LobbyError::AlreadyMember);
+        }
+        self.waitlist.push_back(member);
+        let position = self.waitlist.len();
+        self.events.push(LobbyEvent::WaitlistJoined {
+            player_id,
+            position,
+        });
+        Ok(position)
+    }
+
+    /// Leave the waitlist.
+    pub fn leave_waitlist(&mut self, player_id: i64) -> Option<LobbyMember> {
+        let index = self.waitlist.iter().position(|m| m.player_id == player_id)?;
+        let member = self.waitlist.remove(index)?;
+        self.events.push(LobbyEvent::WaitlistLeft { player_id });
+        Some(member)
+    }
+
+    /// Check if a player is on the waitlist.
+    pub fn is_waitlisted(&self, player_id: i64) -> bool {
+        self.waitlist.iter().any(|m| m.player_id == player_id)
+    }
+
+    /// Get waitlisted players, first in line first.
+    pub fn waitlist(&self) -> impl Iterator<Item = &LobbyMember> {
+        self.waitlist.iter()
+    }
+
+    /// Bar a player or user from the lobby, removing them from the members
+    /// and waitlist. Returns the members removed (more than one if a user
+    /// had several accounts in).
+    pub fn ban(&mut self, target: BanTarget) -> Vec<LobbyMember> {
+        let members: Vec<i64> = self
+            .members
+            .values()
+            .filter(|m| target.matches(m.player_id, &m.user_id))
+            .map(|m| m.player_id)
+            .collect();
+        let waitlisted: Vec<i64> = self
+            .waitlist
+            .iter()
+            .filter(|m| target.matches(m.player_id, &m.user_id))
+            .map(|m| m.player_id)
+            .collect();
+        self.bans.insert(target);
+        for player_id in waitlisted {
+            self.leave_waitlist(player_id);
+        }
+        members
+            .into_iter()
+            .filter_map(|player_id| self.remove_member(player_id))
+            .collect()
+    }
+
+    /// Lift a ban. Returns false if there was none.
+    pub fn unban(&mut self, target: &BanTarget) -> bool {
+        self.bans.remove(target)
+    }
+
+    /// Check if a player is banned, by player or user ID.
+    pub fn is_banned(&self, player_id: i64, user_id: &str) -> bool {
+        self.bans.iter().any(|ban| ban.matches(player_id, user_id))
+    }
+
+    /// Get the current bans.
+    pub fn bans(&self) -> impl Iterator<Item = &BanTarget> {
+        self.bans.iter()
+    }
+
+    /// Return the lobby to its pre-game state once a game is over.
+    ///
+    /// Clears ready flags and the active game, then fills free seats from the
+    /// waitlist. Players who can't be seated keep their place in the queue.
+    /// Returns the promoted player IDs.
+    pub fn reset_after_game(&mut self) -> Vec<i64> {
+        self.reset_ready();
+        self.set_active_game(None);
+
+        let mut promoted = Vec::new();
+        let mut passed_over = Vec::new();
+        while !self.is_full() {
+            let Some(mut member) = self.waitlist.pop_front() else {
+                break;
+            };
+            if self.check_admission(&member).is_err() {
+                passed_over.push(member);
+                continue;
+            }
+            let player_id = member.player_id;
+            member.is_ready = false;
+            member.joined_at = chrono::Utc::now();
+            self.events.push(LobbyEvent::WaitlistPromoted { player_id });
+            if self.add_member(member).is_ok() {
+                promoted.push(player_id);
+            }
+        }
+        for member in passed_over.into_iter().rev() {
+            self.waitlist.push_front(member);
+        }
+        promoted
+    }
+
+    /// Offer the host role to another member. Replaces any pending offer.
+    ///
+    /// The target has to accept before `now + timeout`.
+    pub fn offer_host(
+        &mut self,
+        from: i64,
+        to: i64,
+        now: chrono::DateTime<chrono::Utc>,
+        timeout: chrono::Duration,
+    ) -> Result<(), LobbyError> {
+        if !self.is_host(from) {
+            return Err(LobbyError::NotHost);
+        }
+        if from == to || !self.members.contains_key(&to) {
+            return Err(LobbyError::NotMember);
+        }
+
+        self.withdraw_host_offer();
+        self.host_offer = Some(HostOffer {
+            from,
+            to,
+            expires_at: now + timeout,
+        });
+        self.events.push(LobbyEvent::HostOffered { from, to });
+        Ok(())
+    }
+
+    /// Accept a pending host offer made to `player_id`.
+    pub fn accept_host(
+        &mut self,
+        player_id: i64,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), LobbyError> {
+        match &self.host_offer {
+            Some(offer)
+                if offer.to == player_id
+                    && Some(offer.from) == self.host_id
+                    && now < offer.expires_at =>
+            {
+                self.host_offer = None;
+                self.set_host(Some(player_id));
+                Ok(())
+            }
+            _ => Err(LobbyError::NoHostOffer),
+        }
+    }
+
+    /// Decline a pending host offer made to `player_id`.
+    pub fn decline_host(&mut self, player_id: i64) -> Result<(), LobbyError> {
+        if self.host_offer.as_ref().is_none_or(|o| o.to != player_id) {
+            return Err(LobbyError::NoHostOffer);
+        }
+        self.withdraw_host_offer();
+        Ok(())
+    }
+
+    /// Get the pending host offer.
+    pub fn host_offer(&self) -> Option<&HostOffer> {
+        self.host_offer.as_ref()
+    }
+
+    fn withdraw_host_offer(&mut self) {
+        if let Some(offer) = self.host_offer.take() {
+            self.events.push(LobbyEvent::HostOfferWithdrawn { to: offer.to });
+        }
+    }
+
+    /// Expire timed-out state. Returns true if a host offer or ready check
+    /// expired.
+    pub fn tick(&mut self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        let offer_expired = self.host_offer.as_ref().is_some_and(|o| now >= o.expires_at);
+        if offer_expired {
+            self.withdraw_host_offer();
+        }
+        let check_expired = self.ready_check.as_ref().is_some_and(|c| now >= c.expires_at);
+        if check_expired {
+            self.expire_ready_check();
+        }
+        offer_expired || check_expired
+    }
+
+    /// Sequence number of the latest lobby event.
+    pub fn event_seq(&self) -> u64 {
+        self.events.last_seq()
+    }
+
+    /// Get events after `seq`, or None if the client needs a full snapshot.
+    pub fn events_since(&self, seq: u64) -> Option<Vec<&SequencedEvent<LobbyEvent>>> {
+        self.events.events_since(seq)
+    }
+
     /// Convert to JSON for sending to clients.
     pub fn to_json(&self) -> serde_json::Value {
         let members: Vec<serde_json::Value> = self
@@ -301,19 +967,51 @@ impl Lobby {
 
         serde_json::json!({
             "lobby_id": self.id,
-            "lobby_type": match self.lobby_type {
-                LobbyType::Channel => "channel",
-                LobbyType::Custom => "custom",
-            },
+            "lobby_type": self.lobby_type.as_str(),
             "lobby_code": self.code,
             "channel_id": self.channel_id,
             "guild_id": self.guild_id,
             "players": members,
             "host_id": host_user_id,
             "max_players": self.max_players,
-            "active_game_id": self.active_game_id
+            "settings": self.settings.to_json(),
+            "active_game_id": self.active_game_id,
+            "ready_check": self.ready_check.as_ref().map(|c| c.to_json()),
+            "waitlist": self.waitlist.iter().map(|m| m.username.clone()).collect::<Vec<_>>(),
+            "event_seq": self.events.last_seq()
         })
     }
+
+    /// Get the redaction profile for a viewer of this lobby.
+    pub fn profile_for(&self, viewer_id: i64) -> RedactionProfile {
+        if self.is_host(viewer_id) {
+            RedactionProfile::Host
+        } else if self.members.contains_key(&viewer_id) {
+            RedactionProfile::Player
+        } else {
+            RedactionProfile::Public
+        }
+    }
+
+    /// Convert to JSON as seen by an audience.
+    ///
+    /// Join codes and Discord locations are only shown to members.
+    pub fn to_json_for(&self, profile: RedactionProfile) -> serde_json::Value {
+        let mut json = self.to_json();
+        if !profile.shows_join_details() {
+            redaction::remove_keys(&mut json, &["lobby_code", "channel_id", "guild_id"]);
+        }
+        if self.settings.visibility == Visibility::Private
+            && !matches!(profile, RedactionProfile::Host | RedactionProfile::Admin)
+        {
+            redaction::remove_keys(&mut json, &["lobby_code"]);
+        }
+        if !profile.shows_user_ids() {
+            redaction::remove_keys_in(&mut json, "players", &["user_id"]);
+            redaction::remove_keys(&mut json, &["host_id"]);
+        }
+        json
+    }
 }
 
 /// Lobby errors.
@@ -324,6 +1022,12 @@ pub enum LobbyError {
     NotMember,
     NotHost,
     GameInProgress,
+    InvalidSettings,
+    NoHostOffer,
+    Banned,
+    RejoinCooldown {
+        until: chrono::DateTime<chrono::Utc>,
+    },
 }
 
 impl std::fmt::Display for LobbyError {
@@ -334,6 +1038,29 @@ impl std::fmt::Display for LobbyError {
             Self::NotMember => write!(f, "Not a member of this lobby"),
             Self::NotHost => write!(f, "Not the lobby host"),
             Self::GameInProgress => write!(f, "A game is in progress"),
+            Self::InvalidSettings => write!(f, "Invalid lobby settings"),
+            Self::NoHostOffer => write!(f, "No pending host offer"),
+            Self::Banned => write!(f, "Banned from this lobby"),
+            Self::RejoinCooldown { until } => {
+                write!(f, "Can't rejoin this lobby until {}", until.to_rfc3339())
+            }
+        }
+    }
+}
+
+impl LobbyError {
+    /// Stable identifier clients use to look up a localized message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Full => "lobby_full",
+            Self::AlreadyMember => "lobby_already_member",
+            Self::NotMember => "lobby_not_member",
+            Self::NotHost => "lobby_not_host",
+            Self::GameInProgress => "lobby_game_in_progress",
+            Self::InvalidSettings => "lobby_invalid_settings",
+            Self::NoHostOffer => "lobby_no_host_offer",
+            Self::Banned => "lobby_banned",
+            Self::RejoinCooldown { .. } => "lobby_rejoin_cooldown",
         }
     }
 }
@@ -354,6 +1081,12 @@ pub struct LobbyManager {
 
     /// Player ID to lobby ID mapping
     player_index: HashMap<i64, String>,
+
+    /// Rejoin cooldown lengths
+    pub rejoin_policy: RejoinPolicy,
+
+    /// (player ID, lobby ID) to the time the player may rejoin
+    rejoin_cooldowns: HashMap<(i64, String), chrono::DateTime<chrono::Utc>>,
 }
 
 impl LobbyManager {
@@ -368,7 +1101,10 @@ impl LobbyManager {
                 .insert(channel_id.clone(), lobby.id.clone());
         }
         if let Some(code) = &lobby.code {
-            self.code_index.insert(code.clone(), lobby.id.clone());
+            self.code_index.insert(code.to_uppercase(), lobby.id.clone());
+        }
+        for player_id in lobby.member_ids() {
+            self.player_index.insert(player_id, lobby.id.clone());
         }
         self.lobbies.insert(lobby.id.clone(), lobby);
     }
@@ -432,12 +1168,35 @@ impl LobbyManager {
         }
     }
 
+    /// Generate a custom lobby code that no current lobby uses.
+    pub fn generate_code(&self, rng: &mut SeededRng) -> String {
+        loop {
+            let code: String = (0..LOBBY_CODE_LENGTH)
+                .map(|_| LOBBY_CODE_ALPHABET[rng.gen_range(LOBBY_CODE_ALPHABET.len())] as char)
+                .collect();
+            if !self.code_index.contains_key(&code) {
+                return code;
+            }
+        }
+    }
+
+    /// Create and add a custom lobby with a generated code.
+    pub fn create_custom(&mut self, rng: &mut SeededRng) -> &mut Lobby {
+        let lobby = Lobby::new_custom(self.generate_code(rng));
+        let lobby_id = lobby.id.clone();
+        self.add(lobby);
+        self.lobbies.get_mut(&lobby_id).unwrap()
+    }
+
     /// Add player to a lobby.
     pub fn add_player(&mut self, lobby_id: &str, member: LobbyMember) -> Result<(), LobbyError> {
         // Check if already in a lobby
         if self.player_index.contains_key(&member.player_id) {
             return Err(LobbyError::AlreadyMember);
         }
+        if let Some(until) = self.rejoin_cooldown(member.player_id, lobby_id) {
+            return Err(LobbyError::RejoinCooldown { until });
+        }
 
         let lobby = self.lobbies.get_mut(lobby_id).ok_or(LobbyError::NotMember)?;
         let player_id = member.player_id;
@@ -447,14 +1206,107 @@ impl LobbyManager {
         Ok(())
     }
 
+    /// Add a player to a lobby's waitlist. Returns the 1-based position.
+    pub fn join_waitlist(
+        &mut self,
+        lobby_id: &str,
+        member: LobbyMember,
+    ) -> Result<usize, LobbyError> {
+        if self.player_index.contains_key(&member.player_id) {
+            return Err(LobbyError::AlreadyMember);
+        }
+        if let Some(until) = self.rejoin_cooldown(member.player_id, lobby_id) {
+            return Err(LobbyError::RejoinCooldown { until });
+        }
+        let lobby = self.lobbies.get_mut(lobby_id).ok_or(LobbyError::NotMember)?;
+        lobby.join_waitlist(member)
+    }
+
+    /// Reset a lobby after its game ended, promoting waitlisted players.
+    ///
+    /// Players who have since joined another lobby are dropped from the
+    /// waitlist rather than promoted. Returns the promoted player IDs.
+    pub fn reset_after_game(&mut self, lobby_id: &str) -> Vec<i64> {
+        let Some(lobby) = self.lobbies.get_mut(lobby_id) else {
+            return Vec::new();
+        };
+        let elsewhere: Vec<i64> = lobby
+            .waitlist()
+            .map(|m| m.player_id)
+            .filter(|pid| self.player_index.contains_key(pid))
+            .collect();
+        for player_id in elsewhere {
+            lobby.leave_waitlist(player_id);
+        }
+
+        let promoted = lobby.reset_after_game();
+        for player_id in &promoted {
+            self.player_index.insert(*player_id, lobby_id.to_string());
+        }
+        promoted
+    }
+
     /// Remove player from their lobby.
+    ///
+    /// Leaving a ready check the player was asked to answer starts a rejoin
+    /// cooldown.
     pub fn remove_player(&mut self, player_id: i64) -> Option<(String, LobbyMember)> {
         let lobby_id = self.player_index.remove(&player_id)?;
         let lobby = self.lobbies.get_mut(&lobby_id)?;
+        let mid_ready_check = lobby
+            .ready_check()
+            .is_some_and(|check| check.required.contains(&player_id));
         let member = lobby.remove_member(player_id)?;
+        if mid_ready_check {
+            self.start_cooldown(player_id, &lobby_id, self.rejoin_policy.after_ready_leave);
+        }
         Some((lobby_id, member))
     }
 
+    /// Ban a player or user from a lobby, removing any matching members.
+    /// Returns the removed members.
+    pub fn ban(&mut self, lobby_id: &str, target: BanTarget) -> Vec<LobbyMember> {
+        let Some(lobby) = self.lobbies.get_mut(lobby_id) else {
+            return Vec::new();
+        };
+        let removed = lobby.ban(target);
+        for member in &removed {
+            self.player_index.remove(&member.player_id);
+        }
+        removed
+    }
+
+    /// Remove a player from their lobby and bar them from rejoining for
+    /// `rejoin_policy.after_kick`.
+    pub fn kick_player(&mut self, player_id: i64) -> Option<(String, LobbyMember)> {
+        let (lobby_id, member) = self.remove_player(player_id)?;
+        self.start_cooldown(player_id, &lobby_id, self.rejoin_policy.after_kick);
+        Some((lobby_id, member))
+    }
+
+    /// Get when a player may rejoin a lobby, if they are still cooling down.
+    pub fn rejoin_cooldown(
+        &self,
+        player_id: i64,
+        lobby_id: &str,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.rejoin_cooldowns
+            .get(&(player_id, lobby_id.to_string()))
+            .copied()
+            .filter(|until| *until > chrono::Utc::now())
+    }
+
+    fn start_cooldown(&mut self, player_id: i64, lobby_id: &str, duration: chrono::Duration) {
+        let now = chrono::Utc::now();
+        self.rejoin_cooldowns.retain(|_, until| *until > now);
+        let until = now + duration;
+        let entry = self
+            .rejoin_cooldowns
+            .entry((player_id, lobby_id.to_string()))
+            .or_insert(until);
+        *entry = (*entry).max(until);
+    }
+
     /// Remove a lobby entirely.
     pub fn remove(&mut self, lobby_id: &str) -> Option<Lobby> {
         let lobby = self.lobbies.remove(lobby_id)?;
@@ -464,7 +1316,7 @@ impl LobbyManager {
             self.channel_index.remove(channel_id);
         }
         if let Some(code) = &lobby.code {
-            self.code_index.remove(code);
+            self.code_index.remove(&code.to_uppercase());
         }
         for member in lobby.members() {
             self.player_index.remove(&member.player_id);
@@ -489,6 +1341,15 @@ impl LobbyManager {
         empty
     }
 
+    /// Expire timed-out state in every lobby. Returns IDs of lobbies where a
+    /// host offer or ready check expired.
+    pub fn tick(&mut self, now: chrono::DateTime<chrono::Utc>) -> Vec<String> {
+        self.lobbies
+            .values_mut()
+            .filter_map(|lobby| lobby.tick(now).then(|| lobby.id.clone()))
+            .collect()
+    }
+
     /// Count lobbies.
     pub fn count(&self) -> usize {
         self.lobbies.len()
@@ -498,6 +1359,109 @@ impl LobbyManager {
     pub fn lobby_ids(&self) -> impl Iterator<Item = &String> {
         self.lobbies.keys()
     }
+
+    /// Page through public lobbies, oldest first.
+    pub fn list_public_page(&self, after: Option<&Cursor>, limit: usize) -> Page<&Lobby> {
+        let lobbies = self.lobbies.values().filter(|l| l.is_public()).collect();
+        Page::from_items(lobbies, after, limit, SortOrder::Ascending, |l| {
+            Cursor::new(l.created_at, l.id.as_str())
+        })
+    }
+
+    /// Summaries of the public lobbies matching a filter, oldest first.
+    pub fn list_public(&self, filter: &LobbyFilter) -> Vec<LobbySummary> {
+        let mut lobbies: Vec<&Lobby> = self
+            .lobbies
+            .values()
+            .filter(|l| l.is_public() && filter.matches(l))
+            .collect();
+        lobbies.sort_by(|a, b| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)));
+        lobbies.into_iter().map(LobbySummary::new).collect()
+    }
+
+    /// Pick a public lobby in a guild (any guild if None) that a new player
+    /// could join right now and that passes `predicate`. Prefers the lobby
+    /// with the most players, then the oldest.
+    pub fn find_joinable(
+        &self,
+        guild_id: Option<&str>,
+        predicate: impl Fn(&Lobby) -> bool,
+    ) -> Option<&Lobby> {
+        self.lobbies
+            .values()
+            .filter(|l| l.is_public() && l.is_joinable())
+            .filter(|l| guild_id.is_none_or(|g| l.guild_id.as_deref() == Some(g)))
+            .filter(|l| predicate(l))
+            .min_by(|a, b| {
+                b.member_count()
+                    .cmp(&a.member_count())
+                    .then_with(|| (a.created_at, &a.id).cmp(&(b.created_at, &b.id)))
+            })
+    }
+}
+
+/// Narrows `LobbyManager::list_public`. The default matches every lobby.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LobbyFilter {
+    pub guild_id: Option<String>,
+    /// Language subtag or full tag, e.g. "pt" matches "pt-BR"
+    pub locale: Option<String>,
+    /// Skip full lobbies and ones with a game running
+    pub joinable_only: bool,
+}
+
+impl LobbyFilter {
+    pub fn matches(&self, lobby: &Lobby) -> bool {
+        let locale_matches = self.locale.as_ref().is_none_or(|locale| {
+            let lobby_locale = lobby.settings.locale.to_ascii_lowercase();
+            let locale = locale.to_ascii_lowercase();
+            lobby_locale == locale || lobby_locale.starts_with(&format!("{}-", locale))
+        });
+        self.guild_id.as_ref().is_none_or(|g| lobby.guild_id.as_ref() == Some(g))
+            && locale_matches
+            && (!self.joinable_only || lobby.is_joinable())
+    }
+}
+
+/// What a lobby browser shows about a public lobby.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LobbySummary {
+    pub lobby_id: String,
+    pub lobby_type: LobbyType,
+    pub player_count: usize,
+    pub max_players: usize,
+    pub host_name: Option<String>,
+    pub has_active_game: bool,
+    pub settings: LobbySettings,
+}
+
+impl LobbySummary {
+    pub fn new(lobby: &Lobby) -> Self {
+        Self {
+            lobby_id: lobby.id.clone(),
+            lobby_type: lobby.lobby_type,
+            player_count: lobby.member_count(),
+            max_players: lobby.max_players,
+            host_name: lobby
+                .host_id
+                .and_then(|id| lobby.get_member(id))
+                .map(|m| m.username.clone()),
+            has_active_game: lobby.has_active_game(),
+            settings: lobby.settings.clone(),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "lobby_id": self.lobby_id,
+            "lobby_type": self.lobby_type.as_str(),
+            "player_count": self.player_count,
+            "max_players": self.max_players,
+            "host_name": self.host_name,
+            "has_active_game": self.has_active_game,
+            "settings": self.settings.to_json()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -512,6 +1476,257 @@ mod tests {
         assert!(!lobby.is_full());
     }
 
+    #[test]
+    fn test_rejoin_cooldown_after_kick() {
+        let mut manager = LobbyManager::new();
+        let lobby_id = manager.find_or_create_channel("chan-1".to_string(), None).id.clone();
+        let member = || LobbyMember::new(1, "1000".to_string(), "Player1".to_string(), None);
+
+        manager.add_player(&lobby_id, member()).unwrap();
+        manager.kick_player(1).unwrap();
+        assert!(matches!(
+            manager.add_player(&lobby_id, member()),
+            Err(LobbyError::RejoinCooldown { .. })
+        ));
+
+        // Other lobbies are unaffected
+        let other_id = manager.find_or_create_channel("chan-2".to_string(), None).id.clone();
+        manager.add_player(&other_id, member()).unwrap();
+        manager.remove_player(1);
+
+        // Expired cooldowns no longer apply
+        let key = (1, lobby_id.clone());
+        manager.rejoin_cooldowns.insert(key, chrono::Utc::now() - chrono::Duration::seconds(1));
+        manager.add_player(&lobby_id, member()).unwrap();
+    }
+
+    #[test]
+    fn test_ban_list() {
+        let mut manager = LobbyManager::new();
+        let lobby_id = manager.find_or_create_channel("chan-1".to_string(), None).id.clone();
+        let member = |id: i64, user: &str| {
+            LobbyMember::new(id, user.to_string(), format!("Player{}", id), None)
+        };
+        manager.add_player(&lobby_id, member(1, "1000")).unwrap();
+        manager.add_player(&lobby_id, member(2, "2000")).unwrap();
+
+        let removed = manager.ban(&lobby_id, BanTarget::User("1000".to_string()));
+        assert_eq!(removed.len(), 1);
+        assert!(manager.get_for_player(1).is_none());
+        // Another account of the same user is still barred
+        let err = manager.add_player(&lobby_id, member(3, "1000")).unwrap_err();
+        assert_eq!(err, LobbyError::Banned);
+
+        let lobby = manager.get_mut(&lobby_id).unwrap();
+        lobby.ban(BanTarget::Player(4));
+        assert!(lobby.is_banned(4, "4000"));
+        assert_eq!(lobby.join_waitlist(member(4, "4000")), Err(LobbyError::Banned));
+        assert!(lobby.unban(&BanTarget::User("1000".to_string())));
+        assert!(!lobby.unban(&BanTarget::User("1000".to_string())));
+        manager.add_player(&lobby_id, member(1, "1000")).unwrap();
+    }
+
+    #[test]
+    fn test_rejoin_cooldown_after_ready_leave() {
+        let mut manager = LobbyManager::new();
+        let lobby_id = manager.find_or_create_channel("chan-1".to_string(), None).id.clone();
+        for pid in [1, 2] {
+            let member = LobbyMember::new(pid, pid.to_string(), "P".to_string(), None);
+            manager.add_player(&lobby_id, member).unwrap();
+        }
+
+        // Leaving with nobody ready is free
+        manager.remove_player(2);
+        assert!(manager.rejoin_cooldown(2, &lobby_id).is_none());
+
+        // So is leaving a ready member when no ready check is running
+        let member = LobbyMember::new(2, "2".to_string(), "P".to_string(), None);
+        manager.add_player(&lobby_id, member).unwrap();
+        manager.get_mut(&lobby_id).unwrap().set_ready(1, true).unwrap();
+        manager.remove_player(2);
+        assert!(manager.rejoin_cooldown(2, &lobby_id).is_none());
+
+        let lobby = manager.get_mut(&lobby_id).unwrap();
+        lobby.start_ready_check(chrono::Utc::now(), chrono::Duration::seconds(30)).unwrap();
+        let member = LobbyMember::new(3, "3".to_string(), "P".to_string(), None);
+        manager.add_player(&lobby_id, member).unwrap();
+        // Members who joined after the check started weren't asked
+        manager.remove_player(3);
+        assert!(manager.rejoin_cooldown(3, &lobby_id).is_none());
+        manager.remove_player(1);
+        assert!(manager.rejoin_cooldown(1, &lobby_id).is_some());
+    }
+
+    #[test]
+    fn test_reset_after_game_promotes_waitlist() {
+        let mut manager = LobbyManager::new();
+        let lobby_id = manager.find_or_create_channel("chan-1".to_string(), None).id.clone();
+        let member = |pid: i64| LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None);
+        manager.get_mut(&lobby_id).unwrap().max_players = 2;
+        for pid in [1, 2] {
+            manager.add_player(&lobby_id, member(pid)).unwrap();
+            manager.get_mut(&lobby_id).unwrap().set_ready(pid, true).unwrap();
+        }
+        manager.get_mut(&lobby_id).unwrap().set_active_game(Some("game-1".to_string()));
+
+        assert_eq!(manager.join_waitlist(&lobby_id, member(3)), Ok(1));
+        assert_eq!(manager.join_waitlist(&lobby_id, member(4)), Ok(2));
+        assert_eq!(manager.join_waitlist(&lobby_id, member(5)), Ok(3));
+
+        // Player 4 gave up and joined another lobby
+        let other_id = manager.find_or_create_channel("chan-2".to_string(), None).id.clone();
+        manager.add_player(&other_id, member(4)).unwrap();
+
+        // Player 2 left during the game, freeing one seat
+        manager.get_mut(&lobby_id).unwrap().set_ready(2, false).unwrap();
+        manager.remove_player(2);
+
+        assert_eq!(manager.reset_after_game(&lobby_id), vec![3]);
+        let lobby = manager.get(&lobby_id).unwrap();
+        assert!(!lobby.has_active_game());
+        assert_eq!(lobby.ready_count(), 0);
+        assert!(lobby.has_member(3));
+        assert_eq!(lobby.waitlist().map(|m| m.player_id).collect::<Vec<_>>(), vec![5]);
+        assert_eq!(manager.get_for_player(3).unwrap().id, lobby_id);
+
+        // A waitlisted player who can't be seated stays queued, unannounced
+        let lobby = manager.get_mut(&lobby_id).unwrap();
+        lobby.remove_member(3);
+        lobby.bans.insert(BanTarget::Player(6));
+        lobby.waitlist.push_front(member(6));
+        let seq = lobby.event_seq();
+        assert_eq!(lobby.reset_after_game(), vec![5]);
+        assert_eq!(lobby.waitlist().map(|m| m.player_id).collect::<Vec<_>>(), vec![6]);
+        let events = lobby.events_since(seq).unwrap();
+        let promoted: Vec<_> = events
+            .iter()
+            .filter_map(|e| match e.event {
+                LobbyEvent::WaitlistPromoted { player_id } => Some(player_id),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(promoted, vec![5]);
+    }
+
+    #[test]
+    fn test_host_offer_flow() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        for pid in [1, 2, 3] {
+            lobby
+                .add_member(LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None))
+                .unwrap();
+        }
+        let now = chrono::Utc::now();
+        let timeout = chrono::Duration::seconds(DEFAULT_HOST_OFFER_TIMEOUT_SECS);
+
+        assert_eq!(lobby.offer_host(2, 3, now, timeout), Err(LobbyError::NotHost));
+        lobby.offer_host(1, 2, now, timeout).unwrap();
+        assert_eq!(lobby.accept_host(3, now), Err(LobbyError::NoHostOffer));
+        assert!(lobby.is_host(1));
+
+        lobby.accept_host(2, now).unwrap();
+        assert!(lobby.is_host(2));
+        assert!(lobby.host_offer().is_none());
+
+        // Stale offers expire on tick and can't be accepted
+        lobby.offer_host(2, 3, now, timeout).unwrap();
+        assert!(!lobby.tick(now));
+        assert!(lobby.tick(now + timeout));
+        assert_eq!(lobby.accept_host(3, now), Err(LobbyError::NoHostOffer));
+        assert!(lobby.is_host(2));
+
+        // A host change withdraws the old host's offer
+        lobby.offer_host(2, 3, now, timeout).unwrap();
+        lobby.transfer_host(1).unwrap();
+        assert!(lobby.host_offer().is_none());
+        assert_eq!(lobby.accept_host(3, now), Err(LobbyError::NoHostOffer));
+        assert!(lobby.is_host(1));
+    }
+
+    #[test]
+    fn test_ready_check() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        for pid in [1, 2, 3] {
+            lobby
+                .add_member(LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None))
+                .unwrap();
+        }
+        let now = chrono::Utc::now();
+        let timeout = chrono::Duration::seconds(DEFAULT_READY_CHECK_TIMEOUT_SECS);
+
+        // Stale readiness doesn't count until confirmed
+        lobby.set_ready(2, true).unwrap();
+        lobby.start_ready_check(now, timeout).unwrap();
+        lobby.set_ready(1, true).unwrap();
+        lobby.set_ready(3, false).unwrap();
+        let check = lobby.ready_check().unwrap();
+        assert_eq!(check.pending().collect::<Vec<_>>(), vec![2]);
+        assert_eq!(lobby.to_json()["ready_check"]["declined"], serde_json::json!([3]));
+
+        assert!(!lobby.tick(now));
+        assert!(lobby.tick(now + timeout));
+        assert!(lobby.ready_check().is_none());
+        assert_eq!(lobby.ready_member_ids().collect::<Vec<_>>(), vec![1]);
+        let events = lobby.events_since(0).unwrap();
+        let last = &events.last().unwrap().event;
+        assert!(matches!(last, LobbyEvent::ReadyCheckEnded { passed: false }));
+
+        // Passes once everyone still in the lobby confirms
+        lobby.start_ready_check(now, timeout).unwrap();
+        lobby.set_ready(1, true).unwrap();
+        lobby.set_ready(2, true).unwrap();
+        lobby.remove_member(3);
+        assert!(lobby.ready_check().is_none());
+        assert!(lobby.all_ready());
+    }
+
+    #[test]
+    fn test_lobby_events() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        lobby
+            .add_member(LobbyMember::new(1, "1000".to_string(), "Player1".to_string(), None))
+            .unwrap();
+        let seq = lobby.event_seq();
+
+        lobby.set_ready(1, true).unwrap();
+        lobby.set_ready(1, true).unwrap(); // No change, no event
+        lobby.remove_member(1);
+
+        let events: Vec<&LobbyEvent> =
+            lobby.events_since(seq).unwrap().into_iter().map(|e| &e.event).collect();
+        assert_eq!(
+            events,
+            vec![
+                &LobbyEvent::ReadyChanged {
+                    player_id: 1,
+                    ready: true
+                },
+                &LobbyEvent::MemberLeft { player_id: 1 },
+                &LobbyEvent::HostChanged { host_id: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lobby_redaction() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        lobby
+            .add_member(LobbyMember::new(1, "1000".to_string(), "Player1".to_string(), None))
+            .unwrap();
+
+        assert_eq!(lobby.profile_for(1), RedactionProfile::Host);
+        assert_eq!(lobby.to_json_for(RedactionProfile::Player)["lobby_code"], "ABC123");
+
+        let json = lobby.to_json_for(lobby.profile_for(2));
+        assert!(json.get("lobby_code").is_none());
+        assert!(json.get("host_id").is_none());
+        assert!(json["players"][0].get("user_id").is_none());
+
+        lobby.settings.visibility = Visibility::Private;
+        assert!(lobby.to_json_for(RedactionProfile::Player).get("lobby_code").is_none());
+        assert_eq!(lobby.to_json_for(RedactionProfile::Host)["lobby_code"], "ABC123");
+    }
+
     #[test]
     fn test_lobby_members() {
         let mut lobby = Lobby::new_custom("ABC123".to_string());
@@ -659,4 +1874,162 @@ mod tests {
         let lobby2 = manager.find_or_create_channel("chan-1".to_string(), None);
         assert_eq!(lobby2.id, id1);
     }
+
+    #[test]
+    fn test_generate_code_unique() {
+        let mut manager = LobbyManager::new();
+        let code = manager.generate_code(&mut SeededRng::new(5));
+        assert_eq!(code.len(), LOBBY_CODE_LENGTH);
+        assert!(!code.contains(['0', 'O', '1', 'I']));
+
+        // Same seed skips the code that's now taken
+        manager.add(Lobby::new_custom(code.clone()));
+        assert_ne!(manager.generate_code(&mut SeededRng::new(5)), code);
+
+        let created = manager.create_custom(&mut SeededRng::new(5)).id.clone();
+        let created = manager.get(&created).unwrap().code.clone().unwrap();
+        assert_ne!(created, code);
+        assert!(manager.get_by_code(&created.to_lowercase()).is_some());
+        assert_eq!(manager.get_by_code(&code).unwrap().code.as_deref(), Some(code.as_str()));
+    }
+
+    #[test]
+    fn test_list_public_pages() {
+        let mut manager = LobbyManager::new();
+        let start = chrono::Utc::now();
+        for (i, code) in ["A", "B", "C", "D"].into_iter().enumerate() {
+            let mut lobby = Lobby::new_custom(code.to_string());
+            lobby.created_at = start + chrono::Duration::seconds(i as i64);
+            if code != "C" {
+                lobby.settings.visibility = Visibility::Public;
+            }
+            manager.add(lobby);
+        }
+
+        let first = manager.list_public_page(None, 2);
+        assert_eq!(first.total, 3);
+        let ids: Vec<&str> = first.items.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, vec!["custom-A", "custom-B"]);
+
+        let cursor = Cursor::decode(first.next_cursor.as_deref().unwrap()).unwrap();
+        let second = manager.list_public_page(Some(&cursor), 2);
+        assert_eq!(second.items[0].id, "custom-D");
+        assert!(second.next_cursor.is_none());
+    }
+
+    #[test]
+    fn test_list_public_summaries() {
+        let mut manager = LobbyManager::new();
+        let start = chrono::Utc::now();
+        for (i, (code, locale, visibility)) in [
+            ("A", "pt-BR", Visibility::Public),
+            ("B", "en", Visibility::Public),
+            ("C", "pt", Visibility::Unlisted),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut lobby = Lobby::new_custom(code.to_string());
+            lobby.created_at = start + chrono::Duration::seconds(i as i64);
+            lobby.settings.locale = locale.to_string();
+            lobby.settings.visibility = visibility;
+            manager.add(lobby);
+        }
+        let member = LobbyMember::new(1, "1000".to_string(), "Ana".to_string(), None);
+        manager.add_player("custom-A", member).unwrap();
+
+        assert_eq!(manager.list_public(&LobbyFilter::default()).len(), 2);
+        let filter = LobbyFilter {
+            locale: Some("pt".to_string()),
+            ..Default::default()
+        };
+        let summaries = manager.list_public(&filter);
+        assert_eq!(summaries.len(), 1);
+        let json = summaries[0].to_json();
+        assert_eq!(json["player_count"], 1);
+        assert_eq!(json["host_name"], "Ana");
+        assert_eq!(json["has_active_game"], false);
+        assert_eq!(json["settings"]["visibility"], "public");
+
+        manager.get_mut("custom-A").unwrap().active_game_id = Some("g".to_string());
+        let joinable = LobbyFilter {
+            joinable_only: true,
+            ..Default::default()
+        };
+        let ids: Vec<_> = manager.list_public(&joinable).into_iter().map(|s| s.lobby_id).collect();
+        assert_eq!(ids, vec!["custom-B"]);
+    }
+
+    #[test]
+    fn test_find_joinable() {
+        let mut manager = LobbyManager::new();
+        let start = chrono::Utc::now();
+        for (i, code) in ["A", "B", "C", "D"].into_iter().enumerate() {
+            let mut lobby = Lobby::new_custom(code.to_string());
+            lobby.created_at = start + chrono::Duration::seconds(i as i64);
+            lobby.guild_id = Some(if code == "D" { "g2" } else { "g1" }.to_string());
+            lobby.settings.visibility = if code == "A" {
+                Visibility::Unlisted
+            } else {
+                Visibility::Public
+            };
+            manager.add(lobby);
+        }
+        let member = LobbyMember::new(1, "1000".to_string(), "Ana".to_string(), None);
+        manager.add_player("custom-C", member).unwrap();
+
+        let found =
+            |m: &LobbyManager, guild| m.find_joinable(guild, |_| true).map(|l| l.id.clone());
+        assert_eq!(found(&manager, Some("g1")).as_deref(), Some("custom-C"));
+        assert_eq!(found(&manager, Some("g2")).as_deref(), Some("custom-D"));
+        assert_eq!(found(&manager, Some("g3")), None);
+
+        manager.get_mut("custom-C").unwrap().active_game_id = Some("g".to_string());
+        assert_eq!(found(&manager, Some("g1")).as_deref(), Some("custom-B"));
+        let german = manager.find_joinable(None, |l| l.settings.locale == "de");
+        assert!(german.is_none());
+    }
+
+    #[test]
+    fn test_set_locale() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        assert_eq!(lobby.settings.locale, DEFAULT_LOCALE);
+
+        lobby.set_locale("PT-BR").unwrap();
+        assert_eq!(lobby.settings.locale, "pt-BR");
+        assert_eq!(lobby.to_json()["settings"]["locale"], "pt-BR");
+
+        for invalid in ["", "e", "english", "en_US", "en-"] {
+            assert_eq!(lobby.set_locale(invalid), Err(LobbyError::InvalidSettings));
+        }
+        assert_eq!(lobby.set_locale("x").unwrap_err().code(), "lobby_invalid_settings");
+    }
+
+    #[test]
+    fn test_update_settings() {
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        lobby.add_member(LobbyMember::new(1, "1".to_string(), "A".to_string(), None)).unwrap();
+        lobby.add_member(LobbyMember::new(2, "2".to_string(), "B".to_string(), None)).unwrap();
+        let settings = LobbySettings {
+            locale: "ES".to_string(),
+            rounds: Some(3),
+            min_word_length: Some(4),
+            dictionary_id: Some("es-rae".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(lobby.update_settings(2, settings.clone()), Err(LobbyError::NotHost));
+        lobby.update_settings(1, settings).unwrap();
+        assert_eq!(lobby.settings.locale, "es");
+        assert_eq!(lobby.to_json()["settings"]["rounds"], 3);
+        let changed = lobby.events_since(0).unwrap();
+        assert!(matches!(changed.last().unwrap().event, LobbyEvent::SettingsChanged));
+
+        let invalid = LobbySettings { rounds: Some(0), ..Default::default() };
+        assert_eq!(lobby.update_settings(1, invalid), Err(LobbyError::InvalidSettings));
+
+        let config = lobby.settings.game_config(GameConfig::default());
+        assert_eq!(config.min_word_length, Some(4));
+        assert_eq!(config.dictionary_id.as_deref(), Some("es-rae"));
+        assert_eq!(config.turn_time_limit, None);
+    }
 }
@@ -6,6 +6,30 @@
 //! - `connection` - WebSocket connection tracking and reconnection
 //! - `lobby` - Lobby membership and configuration
 //! - `game` - Active game sessions
+//! - `chat` - Bounded, rate-limited chat buffers
+//! - `rng` - Seedable random number generation
+//! - `dictionary` - Word validity lookups
+//! - `solver` - Enumerates valid words on a board
+//! - `guild` - Per-guild resource quotas
+//! - `redaction` - What each audience may see in snapshots
+//! - `events` - Sequenced per-game and per-lobby event buffers
+//! - `authz` - Who may run privileged commands
+//! - `generation` - Weighted board generation and difficulty tiers
+//! - `archive` - Summaries of finished games
+//! - `audit` - Log of privileged commands
+//! - `pagination` - Cursor-based pages for manager listings
+//! - `health` - Invariant checks and the startup self-test
+//! - `scoring` - Word scores from letter values, multipliers and gems
+//! - `powerup` - Power-ups bought with gems
+//! - `replay` - Recorded games and reconstruction at any step
+//! - `hint` - Partial word reveals bought with gems
+//! - `stats` - Ranked results and end-of-game statistics
+//! - `daily` - Shared-seed daily challenges and their leaderboards
+//! - `bot` - Move selection for bot players
+//! - `letters` - Per-language tile sets, frequencies and point values
+//! - `path` - Validated word paths
+//! - `blitz` - Simultaneous-play finds and duplicate settling
+//! - `achievements` - Achievements unlocked during play
 //!
 //! # Architecture
 //!
@@ -57,19 +81,86 @@
 //! player_state.apply_mut(PlayerEvent::JoinLobby { lobby_id: "lobby-1".into() })?;
 //! ```
 
+pub mod achievements;
+pub mod archive;
+pub mod audit;
+pub mod authz;
+pub mod blitz;
+pub mod bot;
+pub mod chat;
 pub mod connection;
+pub mod daily;
+pub mod dictionary;
+pub mod events;
 pub mod game;
+pub mod generation;
+pub mod guild;
+pub mod health;
+pub mod hint;
+pub mod letters;
 pub mod lobby;
+pub mod pagination;
+pub mod path;
 pub mod player;
+pub mod powerup;
+pub mod redaction;
+pub mod replay;
+pub mod rng;
+pub mod scoring;
+pub mod solver;
+pub mod stats;
+
+use std::collections::HashSet;
+use std::time::Duration;
 
 // Re-export commonly used types
-pub use connection::{Connection, ConnectionManager, ConnectionStatus, PendingMessage};
+pub use achievements::{Achievement, AchievementTracker, Unlocked};
+pub use archive::{ArchivedGame, GameArchive};
+pub use audit::{AuditEntry, AuditLog};
+pub use authz::{Authorizer, Command, DefaultAuthorizer};
+pub use blitz::{BlitzAward, BlitzFind, BlitzState, DuplicateRule};
+pub use bot::{BotMove, BotStrategy, GreedyBot};
+pub use chat::{ChatBuffer, ChatError, ChatMessage};
+pub use connection::{
+    ActivityClass, CachedResponse, Connection, ConnectionConfig, ConnectionEvent,
+    ConnectionManager, ConnectionStatus, MessageKind, MessagePriority, OutboundMessage,
+    PendingMessage, Presence, ReplayPlan, SeqGap,
+};
+pub use daily::{ChallengeScore, DailyChallenge};
+pub use dictionary::{Dictionary, Trie, WordList};
+pub use events::{EventBuffer, GameEvent, LobbyEvent, SequencedEvent};
 pub use game::{
-    Game, GameError, GameManager, GamePlayer, GameStatus, Grid, GridCell, Multiplier, Position,
-    Spectator, TimerVoteState, GRID_SIZE,
+    CancelReason, CellChange, ChallengeConfig, ChallengeOutcome, ChallengeState, ChatPolicy,
+    EvictionPolicy, Game, GameConfig, GameError, GameManager, GameManagerError, GameMode,
+    GamePlayer, GameStatus, GameSummary, GemConfig, Grid, GridCell, GridExt, KickVoteConfig,
+    KickVoteState, LetterChange, Multiplier, MultiplierUse, Position, RerollConfig, RoundSummary,
+    ScoreDelta, ScoreField, ScoreSource, Spectator, SpectatorJoin, StartingScore, SubmissionLimits,
+    TieBreak, TimerVoteConfig, TimerVoteState, TurnAction, TurnAdvance, TurnExtensionConfig,
+    TurnOrderStrategy, TurnRecord, WordPlayResult, GRID_SIZE,
+};
+pub use generation::{
+    DifficultyTier, GridBuilder, GridConfig, LetterWeighting, LetterWeights, PlacementRules,
+    PremiumLayout,
+};
+pub use guild::{GuildQuota, GuildQuotas, GuildResource, GuildUsage};
+pub use health::{InvariantViolation, SelfTestReport, SelfTestStep};
+pub use hint::{Hint, HintConfig, HintStyle};
+pub use letters::{LetterSet, LetterValues, Tile};
+pub use lobby::{
+    BanTarget, HostOffer, Lobby, LobbyError, LobbyFilter, LobbyManager, LobbyMember, LobbySettings,
+    LobbySummary, LobbyType, ReadyCheck, RejoinPolicy, Visibility, DEFAULT_LOCALE,
+    MAX_LOBBY_PLAYERS,
 };
-pub use lobby::{Lobby, LobbyError, LobbyManager, LobbyMember, LobbyType, MAX_LOBBY_PLAYERS};
+pub use pagination::{Cursor, Page, SortOrder};
+pub use path::WordPath;
 pub use player::{InvalidTransition, PlayerEvent, PlayerLocation, PlayerState};
+pub use powerup::{PowerUp, PowerUpConfig, PowerUpEffect, PowerUpTarget, PowerUpUse};
+pub use redaction::RedactionProfile;
+pub use replay::{Replay, ReplayStep};
+pub use rng::{RngProvider, SeededRng};
+pub use scoring::{Handicap, ScoringBonus, ScoringRules, WordScore};
+pub use solver::{FoundWord, GridQuality};
+pub use stats::{GameResult, GameStats, PlayerStats, ResultEntry, WordPlay};
 
 /// Combined application state.
 ///
@@ -80,6 +171,18 @@ pub struct AppState {
     pub connections: ConnectionManager,
     pub lobbies: LobbyManager,
     pub games: GameManager,
+    /// Per-guild resource limits
+    pub guild_quotas: GuildQuotas,
+    /// Connection policy (grace periods)
+    pub connection_config: ConnectionConfig,
+    /// Permission policy for `execute` (None uses `DefaultAuthorizer`)
+    authorizer: Option<Box<dyn Authorizer>>,
+    /// Players `DefaultAuthorizer` lets run any command
+    pub admin_ids: HashSet<i64>,
+    /// Commands applied through `execute`
+    audit_log: AuditLog,
+    /// Randomness for games and lobby codes; seed it for reproducible runs
+    pub rng: RngProvider,
     /// Individual player state machines
     player_states: std::collections::HashMap<i64, PlayerState>,
 }
@@ -113,11 +216,937 @@ impl AppState {
         event: PlayerEvent,
     ) -> Result<(), InvalidTransition> {
         let state = self.player_state(player_id);
-        state.apply_mut(event)
+        state.apply_mut(event)?;
+
+        let class = ActivityClass::from(state.location());
+        self.connections.set_activity_class(player_id, class);
+        Ok(())
+    }
+
+    /// Set a player's connection state across all managers.
+    ///
+    /// Keeps the `Connection` status, the `LobbyMember::is_connected` flag and
+    /// the `GamePlayer::is_connected` flag in sync. Disconnecting starts the
+    /// reconnection grace period but leaves the player's location intact, so
+    /// they return to the same lobby/game on reconnect; the hard `Disconnect`
+    /// transition happens in [`AppState::cleanup`] once the grace expires.
+    pub fn set_player_connected(
+        &mut self,
+        player_id: i64,
+        connected: bool,
+    ) -> Result<PresenceChange, &'static str> {
+        let grace = self.connection_config.grace_period_for(
+            self.get_player_state(player_id)
+                .map(|s| s.location())
+                .unwrap_or(&PlayerLocation::Disconnected),
+        );
+        if let Some(conn) = self.connections.get_mut(player_id) {
+            if connected {
+                conn.reconnect()?;
+            } else if conn.status.is_connected() {
+                conn.disconnect_with_grace(grace);
+            }
+        }
+
+        let lobby_id = self.lobbies.get_for_player_mut(player_id).and_then(|lobby| {
+            lobby.set_connected(player_id, connected).ok()?;
+            Some(lobby.id.clone())
+        });
+
+        let game_id = self.games.get_for_player_mut(player_id).and_then(|game| {
+            game.set_player_connected(player_id, connected).ok()?;
+            Some(game.id.clone())
+        });
+
+        let mut player_event = None;
+        if connected {
+            let state = self.player_state(player_id);
+            if !state.is_connected() && state.apply_mut(PlayerEvent::Connect).is_ok() {
+                player_event = Some(PlayerEvent::Connect);
+            }
+        }
+
+        Ok(PresenceChange {
+            player_id,
+            connected,
+            lobby_id,
+            game_id,
+            player_event,
+        })
+    }
+
+    /// Replace the permission policy used by [`AppState::execute`].
+    pub fn set_authorizer(&mut self, authorizer: Box<dyn Authorizer>) {
+        self.authorizer = Some(authorizer);
+    }
+
+    /// Check permissions for a command, then apply it.
+    ///
+    /// Applied commands are recorded in the audit log.
+    pub fn execute(&mut self, actor_id: i64, command: Command) -> Result<(), AppError> {
+        match &self.authorizer {
+            Some(authorizer) => authorizer.authorize(self, actor_id, &command)?,
+            None => DefaultAuthorizer.authorize(self, actor_id, &command)?,
+        }
+
+        self.apply_command(actor_id, command.clone())?;
+        self.audit_log.record(actor_id, command);
+        Ok(())
+    }
+
+    /// Get the log of commands applied through `execute`.
+    pub fn audit_log(&self) -> &AuditLog {
+        &self.audit_log
+    }
+
+    fn apply_command(&mut self, actor_id: i64, command: Command) -> Result<(), AppError> {
+        match command {
+            Command::KickPlayer {
+                lobby_id,
+                player_id,
+            } => {
+                let lobby = self.lobbies.get(&lobby_id).ok_or(AppError::LobbyNotFound)?;
+                if !lobby.has_member(player_id) {
+                    return Err(LobbyError::NotMember.into());
+                }
+                if self.games.get_for_player(player_id).is_some_and(|g| g.status.is_started()) {
+                    return Err(LobbyError::GameInProgress.into());
+                }
+                self.lobbies.kick_player(player_id);
+                let in_this_lobby = self
+                    .get_player_state(player_id)
+                    .is_some_and(|s| s.lobby_id() == Some(lobby_id.as_str()));
+                if in_this_lobby {
+                    self.apply_player_event(player_id, PlayerEvent::LeaveLobby)?;
+                }
+                Ok(())
+            }
+            Command::TransferHost {
+                lobby_id,
+                player_id,
+            } => {
+                let lobby = self.lobbies.get_mut(&lobby_id).ok_or(AppError::LobbyNotFound)?;
+                Ok(lobby.transfer_host(player_id)?)
+            }
+            Command::SetMaxPlayers {
+                lobby_id,
+                max_players,
+            } => {
+                let lobby = self.lobbies.get_mut(&lobby_id).ok_or(AppError::LobbyNotFound)?;
+                if max_players == 0
+                    || max_players > MAX_LOBBY_PLAYERS
+                    || max_players < lobby.member_count()
+                {
+                    return Err(LobbyError::InvalidSettings.into());
+                }
+                lobby.max_players = max_players;
+                Ok(())
+            }
+            Command::SetLocale { lobby_id, locale } => {
+                let lobby = self.lobbies.get_mut(&lobby_id).ok_or(AppError::LobbyNotFound)?;
+                Ok(lobby.set_locale(&locale)?)
+            }
+            Command::CancelGame { game_id, reason } => {
+                let game = self.games.get_mut(&game_id).ok_or(AppError::GameNotFound)?;
+                if game.status.is_terminal() {
+                    return Err(GameError::InvalidStatus.into());
+                }
+                game.cancel(CancelReason::Custom(reason));
+                self.release_game(&game_id);
+                Ok(())
+            }
+            Command::AbortGame { game_id } => {
+                let game = self.games.get_mut(&game_id).ok_or(AppError::GameNotFound)?;
+                game.abort(actor_id)?;
+                self.release_game(&game_id);
+                Ok(())
+            }
+            Command::ForceSkipTurn { game_id } => {
+                let game = self.games.get_mut(&game_id).ok_or(AppError::GameNotFound)?;
+                game.force_skip_turn(actor_id)?;
+                Ok(())
+            }
+            Command::AdjustScore {
+                game_id,
+                player_id,
+                amount,
+                reason,
+            } => {
+                let game = self.games.get_mut(&game_id).ok_or(AppError::GameNotFound)?;
+                game.force_adjust_score(player_id, amount, &reason, actor_id)?;
+                Ok(())
+            }
+            Command::ForceEndGame { game_id } => {
+                let game = self.games.get_mut(&game_id).ok_or(AppError::GameNotFound)?;
+                game.force_end(actor_id)?;
+                self.release_game(&game_id);
+                Ok(())
+            }
+            Command::RemoveGamePlayer { game_id, player_id } => {
+                let game = self.games.get(&game_id).ok_or(AppError::GameNotFound)?;
+                if !game.has_player(player_id) {
+                    return Err(GameError::NotPlayer.into());
+                }
+                self.games.remove_player(player_id, actor_id)?;
+                if self.games.get(&game_id).is_some_and(|g| g.status.is_terminal()) {
+                    self.release_game(&game_id);
+                }
+                let in_this_game = self
+                    .get_player_state(player_id)
+                    .is_some_and(|s| s.game_id() == Some(game_id.as_str()));
+                if in_this_game {
+                    self.apply_player_event(player_id, PlayerEvent::LeaveGame)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// End a game early as an admin and reset its lobby.
+    ///
+    /// Returns the final results.
+    pub fn force_end_game(&mut self, actor_id: i64, game_id: &str) -> Result<GameResult, AppError> {
+        self.execute(
+            actor_id,
+            Command::ForceEndGame {
+                game_id: game_id.to_string(),
+            },
+        )?;
+        let game = self.games.get(game_id).ok_or(AppError::GameNotFound)?;
+        Ok(game.result())
+    }
+
+    /// Abort a game before it starts and release its lobby.
+    ///
+    /// In lobbies with a host only the host may abort; otherwise any player in
+    /// the game may. Clears the lobby's active game, resets ready flags and
+    /// returns players to the lobby.
+    pub fn abort_game(&mut self, game_id: &str, initiator_id: i64) -> Result<(), AppError> {
+        self.execute(
+            initiator_id,
+            Command::AbortGame {
+                game_id: game_id.to_string(),
+            },
+        )
+    }
+
+    /// Advance timers in every game (see [`GameManager::tick`]) and run the
+    /// terminal-state hook for the ones that ended. Returns IDs of games that
+    /// changed.
+    pub fn tick_games(&mut self, now: chrono::DateTime<chrono::Utc>) -> Vec<String> {
+        let changed = self.games.tick(now);
+        for game_id in &changed {
+            if self.games.get(game_id).is_some_and(|g| g.status.is_terminal()) {
+                self.release_game(game_id);
+            }
+        }
+        changed
+    }
+
+    /// Terminal-state hook: detach a game from its lobby, return its players
+    /// there and promote waitlisted players into freed seats. Does nothing
+    /// for a game that was already released.
+    fn release_game(&mut self, game_id: &str) {
+        let Some(game) = self.games.get(game_id) else {
+            return;
+        };
+        let lobby_id = game.lobby_id.clone();
+        let player_ids: Vec<i64> = game.player_ids_in_order().to_vec();
+
+        let is_active_game = self
+            .lobbies
+            .get(&lobby_id)
+            .is_some_and(|l| l.active_game_id.as_deref() == Some(game_id));
+        let promoted = if is_active_game {
+            self.lobbies.reset_after_game(&lobby_id)
+        } else {
+            Vec::new()
+        };
+
+        for player_id in player_ids {
+            let in_this_game = self
+                .get_player_state(player_id)
+                .is_some_and(|s| s.game_id() == Some(game_id));
+            if in_this_game {
+                let _ = self.apply_player_event(player_id, PlayerEvent::LeaveGame);
+            }
+        }
+        for player_id in promoted {
+            let _ = self.apply_player_event(
+                player_id,
+                PlayerEvent::JoinLobby {
+                    lobby_id: lobby_id.clone(),
+                },
+            );
+        }
+    }
+
+    /// Turn a spectating player into a player of the game they watch, even
+    /// mid-game.
+    ///
+    /// Returns the catch-up snapshot from [`Game::promote_spectator`].
+    pub fn become_player(
+        &mut self,
+        player_id: i64,
+        starting_score: StartingScore,
+    ) -> Result<serde_json::Value, AppError> {
+        let state = self.player_state(player_id);
+        state.apply(PlayerEvent::BecomePlayer)?;
+        let game_id = state.game_id().map(str::to_string).ok_or(AppError::GameNotFound)?;
+
+        let snapshot = self.games.promote_spectator(&game_id, player_id, starting_score)?;
+        self.apply_player_event(player_id, PlayerEvent::BecomePlayer)?;
+        Ok(snapshot)
+    }
+
+    /// End a game normally and reset its lobby.
+    ///
+    /// Returns the final results. See [`Game::end`].
+    pub fn end_game(&mut self, game_id: &str) -> Result<GameResult, AppError> {
+        let game = self.games.get_mut(game_id).ok_or(AppError::GameNotFound)?;
+        let result = game.end()?;
+        self.release_game(game_id);
+        Ok(result)
+    }
+
+    /// Run a client command at most once per request ID.
+    ///
+    /// If `request_id` was already handled on the player's connection, the
+    /// stored result is returned without running `handler`, so a retried
+    /// command (e.g. after replay on reconnect) doesn't apply twice. Without a
+    /// request ID the handler always runs.
+    pub fn handle_request<F>(
+        &mut self,
+        player_id: i64,
+        request_id: Option<&str>,
+        handler: F,
+    ) -> serde_json::Value
+    where
+        F: FnOnce(&mut AppState) -> serde_json::Value,
+    {
+        let Some(request_id) = request_id else {
+            return handler(self);
+        };
+        if let Some(cached) = self
+            .connections
+            .get(player_id)
+            .and_then(|c| c.cached_response(request_id))
+        {
+            return cached.clone();
+        }
+
+        let result = handler(self);
+        if let Some(conn) = self.connections.get_mut(player_id) {
+            conn.record_response(request_id.to_string(), result.clone());
+        }
+        result
+    }
+
+    /// Resolve everyone who should receive broadcasts about a game or lobby.
+    ///
+    /// A game's audience includes its lobby's members; a lobby's audience
+    /// includes the players and spectators of its active game.
+    pub fn audience(&self, target: &AudienceTarget) -> Audience {
+        let (game, lobby) = match target {
+            AudienceTarget::Game(game_id) => {
+                let game = self.games.get(game_id);
+                (game, game.and_then(|g| self.lobbies.get(&g.lobby_id)))
+            }
+            AudienceTarget::Lobby(lobby_id) => {
+                let lobby = self.lobbies.get(lobby_id);
+                let game = lobby
+                    .and_then(|l| l.active_game_id.as_deref())
+                    .and_then(|id| self.games.get(id));
+                (game, lobby)
+            }
+        };
+
+        let mut audience = Audience::default();
+        if let Some(game) = game {
+            audience.player_ids = game.player_ids_in_order().to_vec();
+            audience.spectator_ids = game.spectators().map(|s| s.player_id).collect();
+            audience.spectator_ids.sort_unstable();
+        }
+        if let Some(lobby) = lobby {
+            audience.lobby_member_ids = lobby.member_ids().collect();
+            audience.lobby_member_ids.sort_unstable();
+        }
+        audience
+    }
+
+    /// Get the redaction profile for a viewer of a game, including `Host`
+    /// for the host of the game's lobby.
+    pub fn game_profile_for(&self, game_id: &str, viewer_id: i64) -> Option<RedactionProfile> {
+        let game = self.games.get(game_id)?;
+        let is_host = self
+            .lobbies
+            .get(&game.lobby_id)
+            .is_some_and(|l| l.is_host(viewer_id));
+        Some(if is_host {
+            RedactionProfile::Host
+        } else {
+            game.profile_for(viewer_id)
+        })
+    }
+
+    /// Convert a lobby to JSON, optionally with its `n` most recent game
+    /// results under `recent_games`.
+    pub fn lobby_to_json(
+        &self,
+        lobby_id: &str,
+        recent_games: Option<usize>,
+    ) -> Option<serde_json::Value> {
+        let mut json = self.lobbies.get(lobby_id)?.to_json();
+        if let Some(n) = recent_games {
+            let results: Vec<serde_json::Value> = self
+                .games
+                .recent_results(lobby_id, n)
+                .iter()
+                .map(|g| g.to_json())
+                .collect();
+            json["recent_games"] = results.into();
+        }
+        Some(json)
+    }
+
+    /// Get an overview of where a player is and how reachable they are.
+    pub fn player_overview(&self, player_id: i64) -> PlayerOverview {
+        let conn = self.connections.get(player_id);
+        PlayerOverview {
+            player_id,
+            username: conn.map(|c| c.username.clone()),
+            avatar_url: conn.and_then(|c| c.avatar_url.clone()),
+            location: self
+                .get_player_state(player_id)
+                .map(|s| s.location().clone())
+                .unwrap_or_default(),
+            presence: conn.map(|c| c.presence()).unwrap_or(Presence::Offline),
+        }
+    }
+
+    /// Assemble everything a reconnecting client needs in one bundle.
+    ///
+    /// Lobby and game snapshots are redacted for the player; the game is the
+    /// one they play in or, failing that, the one their location points at
+    /// (e.g. while spectating).
+    pub fn resync(&self, player_id: i64) -> SyncBundle {
+        let overview = self.player_overview(player_id);
+
+        let lobby = self
+            .lobbies
+            .get_for_player(player_id)
+            .map(|l| l.to_json_for(l.profile_for(player_id)));
+
+        let game = self
+            .games
+            .get_for_player(player_id)
+            .or_else(|| overview.location.game_id().and_then(|id| self.games.get(id)))
+            .and_then(|g| {
+                let profile = self.game_profile_for(&g.id, player_id)?;
+                Some(g.to_json_for(profile))
+            });
+
+        let replay = self
+            .connections
+            .get(player_id)
+            .map(|c| c.replay_plan())
+            .unwrap_or_default();
+
+        SyncBundle {
+            overview,
+            lobby,
+            game,
+            replay,
+        }
+    }
+
+    /// Update a player's display name and avatar everywhere it is stored.
+    ///
+    /// Propagates the change from the connection into the player's lobby
+    /// member and game player entries so snapshots stay consistent.
+    pub fn update_player_identity(
+        &mut self,
+        player_id: i64,
+        username: String,
+        avatar_url: Option<String>,
+    ) -> IdentityChange {
+        self.connections
+            .update_identity(player_id, username.clone(), avatar_url.clone());
+
+        let lobby_id = self.lobbies.get_for_player_mut(player_id).and_then(|lobby| {
+            let member = lobby.get_member_mut(player_id)?;
+            member.username = username.clone();
+            member.avatar_url = avatar_url.clone();
+            Some(lobby.id.clone())
+        });
+
+        let game_id = self.games.get_for_player_mut(player_id).and_then(|game| {
+            let player = game.get_player_mut(player_id)?;
+            player.username = username;
+            player.avatar_url = avatar_url;
+            Some(game.id.clone())
+        });
+
+        IdentityChange {
+            player_id,
+            lobby_id,
+            game_id,
+        }
+    }
+
+    /// Reconcile state after it has been restored from a snapshot.
+    ///
+    /// Restored connections are moved into their reconnection grace period and
+    /// their players marked disconnected in lobbies and games, so clients that
+    /// come back in time resume their sessions via [`AppState::resume_session`].
+    pub fn reconcile_after_restore(&mut self, grace_period: Duration) -> Vec<PresenceChange> {
+        self.connections
+            .reconcile_restored(grace_period)
+            .into_iter()
+            .filter_map(|pid| self.set_player_connected(pid, false).ok())
+            .collect()
+    }
+
+    /// Resume a session by token, restoring presence flags.
+    ///
+    /// Returns the player ID and the messages to replay.
+    pub fn resume_session(&mut self, token: &str) -> Result<(i64, ReplayPlan), &'static str> {
+        let (conn, plan) = self.connections.resume_session(token)?;
+        let player_id = conn.player_id;
+        self.set_player_connected(player_id, true)?;
+        Ok((player_id, plan))
+    }
+
+    /// Report a guild's current resource usage against its quota.
+    ///
+    /// Games are counted while they are not yet terminal.
+    pub fn guild_usage(&self, guild_id: &str) -> GuildUsage {
+        let connections = self
+            .connections
+            .all_players()
+            .into_iter()
+            .filter_map(|pid| self.connections.get(pid))
+            .filter(|c| c.guild_id.as_deref() == Some(guild_id))
+            .count();
+
+        let guild_lobbies: Vec<&String> = self
+            .lobbies
+            .lobby_ids()
+            .filter(|id| {
+                self.lobbies
+                    .get(id)
+                    .is_some_and(|l| l.guild_id.as_deref() == Some(guild_id))
+            })
+            .collect();
+
+        let games = guild_lobbies
+            .iter()
+            .flat_map(|id| self.games.history_for_lobby(id, usize::MAX))
+            .filter(|g| !g.status.is_terminal())
+            .count();
+
+        GuildUsage {
+            guild_id: guild_id.to_string(),
+            connections,
+            lobbies: guild_lobbies.len(),
+            games,
+            quota: *self.guild_quotas.for_guild(guild_id),
+        }
+    }
+
+    /// Fail if the guild has no room for another `resource`.
+    fn check_guild_quota(
+        &self,
+        guild_id: Option<&str>,
+        resource: GuildResource,
+    ) -> Result<(), AppError> {
+        let Some(guild_id) = guild_id else {
+            return Ok(());
+        };
+        if self.guild_usage(guild_id).has_room_for(resource) {
+            Ok(())
+        } else {
+            Err(AppError::GuildQuotaExceeded {
+                guild_id: guild_id.to_string(),
+                resource,
+            })
+        }
+    }
+
+    /// Add a connection, enforcing the guild's connection quota.
+    ///
+    /// Replacing a player's existing connection in the same guild is always allowed.
+    pub fn add_connection(&mut self, conn: Connection) -> Result<(), AppError> {
+        let replacing = self
+            .connections
+            .get(conn.player_id)
+            .is_some_and(|c| c.guild_id == conn.guild_id);
+        if !replacing {
+            self.check_guild_quota(conn.guild_id.as_deref(), GuildResource::Connections)?;
+        }
+        self.connections.add(conn);
+        Ok(())
+    }
+
+    /// Add a lobby, enforcing the guild's lobby quota.
+    pub fn add_lobby(&mut self, lobby: Lobby) -> Result<(), AppError> {
+        self.check_guild_quota(lobby.guild_id.as_deref(), GuildResource::Lobbies)?;
+        self.lobbies.add(lobby);
+        Ok(())
+    }
+
+    /// Create a custom lobby with a freshly generated code, enforcing the
+    /// lobby quota. Returns the lobby ID.
+    pub fn create_custom_lobby(&mut self, guild_id: Option<String>) -> Result<String, AppError> {
+        self.check_guild_quota(guild_id.as_deref(), GuildResource::Lobbies)?;
+        let lobby = self.lobbies.create_custom(&mut self.rng.fork());
+        lobby.guild_id = guild_id;
+        Ok(lobby.id.clone())
+    }
+
+    /// Find or create a channel lobby, enforcing the lobby quota on creation.
+    pub fn find_or_create_channel_lobby(
+        &mut self,
+        channel_id: String,
+        guild_id: Option<String>,
+    ) -> Result<&mut Lobby, AppError> {
+        if self.lobbies.get_by_channel(&channel_id).is_none() {
+            self.check_guild_quota(guild_id.as_deref(), GuildResource::Lobbies)?;
+        }
+        Ok(self.lobbies.find_or_create_channel(channel_id, guild_id))
+    }
+
+    /// Add a game, enforcing the quota of its lobby's guild.
+    pub fn add_game(&mut self, game: Game) -> Result<(), AppError> {
+        let guild_id = self
+            .lobbies
+            .get(&game.lobby_id)
+            .and_then(|l| l.guild_id.clone());
+        self.check_guild_quota(guild_id.as_deref(), GuildResource::Games)?;
+        self.games.add(game).map_err(GameError::from)?;
+        Ok(())
+    }
+
+    /// Create a game for a lobby's ready members and start tracking it.
+    ///
+    /// Players are ordered by `config.turn_order` (the strategy stays in the
+    /// game's config so the order can be reproduced), the game's RNG is seeded
+    /// from `self.rng`, the lobby's active game is set and players move from
+    /// the lobby into the game.
+    pub fn create_game_from_lobby(
+        &mut self,
+        lobby_id: &str,
+        game_id: String,
+        grid: Grid,
+        config: GameConfig,
+    ) -> Result<&mut Game, AppError> {
+        let lobby = self.lobbies.get(lobby_id).ok_or(AppError::LobbyNotFound)?;
+        if lobby.has_active_game() {
+            return Err(LobbyError::GameInProgress.into());
+        }
+
+        let mut members: Vec<&LobbyMember> = lobby.members().filter(|m| m.is_ready).collect();
+        if members.is_empty() {
+            return Err(GameError::NotEnoughPlayers.into());
+        }
+        members.sort_by_key(|m| (m.joined_at, m.player_id));
+        let players: Vec<GamePlayer> = members
+            .into_iter()
+            .map(|m| {
+                let mut player = GamePlayer::new(
+                    m.player_id,
+                    m.user_id.clone(),
+                    m.username.clone(),
+                    m.avatar_url.clone(),
+                    0,
+                );
+                player.rating = m.rating;
+                player.is_connected = m.is_connected;
+                player
+            })
+            .collect();
+        let players = config.turn_order.apply(players)?;
+        let locale = lobby.settings.locale.clone();
+        let rounds = lobby.settings.rounds;
+        let config = lobby.settings.game_config(config);
+
+        let mut game = Game::new(game_id.clone(), lobby_id.to_string(), grid)
+            .with_config(config)
+            .with_locale(locale)
+            .with_seed(self.rng.next_seed());
+        if let Some(rounds) = rounds {
+            game.max_rounds = rounds;
+        }
+        for player in players {
+            game.add_player(player)?;
+        }
+        let player_ids = game.player_ids_in_order().to_vec();
+        self.add_game(game)?;
+
+        if let Some(lobby) = self.lobbies.get_mut(lobby_id) {
+            lobby.set_active_game(Some(game_id.clone()));
+        }
+        for player_id in player_ids {
+            let in_this_lobby = self
+                .get_player_state(player_id)
+                .is_some_and(|s| s.lobby_id() == Some(lobby_id));
+            if in_this_lobby {
+                let _ = self.apply_player_event(
+                    player_id,
+                    PlayerEvent::StartGame {
+                        game_id: game_id.clone(),
+                    },
+                );
+            }
+        }
+
+        self.games.get_mut(&game_id).ok_or(AppError::GameNotFound)
+    }
+
+    /// Create a game for a lobby's ready members on a freshly generated board.
+    ///
+    /// English boards come from a [`GridBuilder`] with the letter weights in
+    /// `config.grid`, resolved against the ready members' ratings; the
+    /// weighting used is recorded on the game. Boards for a configured letter
+    /// set are drawn from it instead. Premium cells come from
+    /// `config.grid.premiums`; settings that can't be satisfied fail with
+    /// `InvalidGridConfig`. The board is drawn from a fork of `self.rng`.
+    pub fn create_generated_game_from_lobby(
+        &mut self,
+        lobby_id: &str,
+        game_id: String,
+        config: GameConfig,
+    ) -> Result<&mut Game, AppError> {
+        let lobby = self.lobbies.get(lobby_id).ok_or(AppError::LobbyNotFound)?;
+        let rng = &mut self.rng.fork();
+        let ratings: Vec<Option<i32>> =
+            lobby.members().filter(|m| m.is_ready).map(|m| m.rating).collect();
+        if let Some(set) = &config.grid.letters {
+            let mut grid = set.generate(rng);
+            if let Some(premiums) = &config.grid.premiums {
+                premiums.apply(&mut grid, rng)?;
+            }
+            return self.create_game_from_lobby(lobby_id, game_id, grid, config);
+        }
+        let builder = GridBuilder::new()
+            .with_weighting(config.grid.resolve(&ratings))
+            .with_premiums(config.grid.premiums.clone().unwrap_or_default());
+        let grid = builder.try_build(rng)?;
+
+        let game = self.create_game_from_lobby(lobby_id, game_id, grid, config)?;
+        game.letter_weighting = Some(builder.weighting().clone());
+        Ok(game)
+    }
+
+    /// Cross-check the managers' indexes, lobby/game links, player
+    /// locations and score audit trails.
+    pub fn check_invariants(&self) -> Vec<InvariantViolation> {
+        let mut violations = Vec::new();
+
+        for lobby_id in self.lobbies.lobby_ids() {
+            let Some(lobby) = self.lobbies.get(lobby_id) else {
+                continue;
+            };
+            for player_id in lobby.member_ids() {
+                if self.lobbies.get_for_player(player_id).map(|l| &l.id) != Some(lobby_id) {
+                    violations.push(InvariantViolation::new(
+                        "lobby_index",
+                        format!("player {} in lobby {} is not indexed to it", player_id, lobby_id),
+                    ));
+                }
+            }
+            if let Some(game_id) = &lobby.active_game_id {
+                if self.games.get(game_id).is_none_or(|g| g.status.is_terminal()) {
+                    violations.push(InvariantViolation::new(
+                        "lobby_active_game",
+                        format!("lobby {} points at missing or ended game {}", lobby_id, game_id),
+                    ));
+                }
+            }
+        }
+
+        for game in self.games.iter() {
+            if !game.status.is_terminal() {
+                for player_id in game.player_ids_in_order() {
+                    let indexed = if game.config.mode.is_solo() {
+                        self.games.get_solo_for_player(*player_id)
+                    } else {
+                        self.games.get_for_player(*player_id)
+                    };
+                    if indexed.map(|g| &g.id) != Some(&game.id) {
+                        violations.push(InvariantViolation::new(
+                            "game_index",
+                            format!(
+                                "player {} in game {} is not indexed to it",
+                                player_id, game.id
+                            ),
+                        ));
+                    }
+                }
+            }
+            for player in game.players() {
+                if player.verify_score().is_err() {
+                    violations.push(InvariantViolation::new(
+                        "score_audit",
+                        format!("player {} in game {}", player.player_id, game.id),
+                    ));
+                }
+            }
+        }
+
+        for (player_id, state) in &self.player_states {
+            let in_place = match state.location() {
+                PlayerLocation::InLobby { lobby_id } => {
+                    self.lobbies.get(lobby_id).is_some_and(|l| l.has_member(*player_id))
+                }
+                PlayerLocation::InGame { game_id, .. } => {
+                    self.games.get(game_id).is_some_and(|g| g.has_player(*player_id))
+                }
+                PlayerLocation::Spectating { game_id, .. } => self
+                    .games
+                    .get(game_id)
+                    .is_some_and(|g| g.spectators().any(|s| s.player_id == *player_id)),
+                PlayerLocation::Disconnected | PlayerLocation::Connected => true,
+            };
+            if !in_place {
+                violations.push(InvariantViolation::new(
+                    "player_location",
+                    format!(
+                        "player {} is not where their state says ({})",
+                        player_id,
+                        state.location()
+                    ),
+                ));
+            }
+        }
+
+        violations
+    }
+
+    /// Run a scripted session through a throwaway `AppState` using this
+    /// state's connection policy: connect two players, form a lobby, play
+    /// and end a game, then tear everything down, checking invariants along
+    /// the way. This state is not touched.
+    pub fn self_test(&self) -> SelfTestReport {
+        let started = std::time::Instant::now();
+        let mut report = SelfTestReport::default();
+        let mut scratch = AppState::new();
+        scratch.connection_config = self.connection_config;
+        scratch.run_self_test(&mut report);
+        report.violations.extend(scratch.check_invariants());
+        report.elapsed = started.elapsed();
+        report
+    }
+
+    fn run_self_test(&mut self, report: &mut SelfTestReport) {
+        const PLAYERS: [i64; 2] = [1, 2];
+        const GAME_ID: &str = "self-test-game";
+        let lobby = Lobby::new_custom("SELFTEST".to_string());
+        let lobby_id = lobby.id.clone();
+
+        let connected = PLAYERS.iter().try_for_each(|&pid| {
+            self.add_connection(Connection::new(
+                pid,
+                pid.to_string(),
+                format!("SelfTest{}", pid),
+                None,
+                format!("self-test-session-{}", pid),
+            ))?;
+            self.apply_player_event(pid, PlayerEvent::Connect)?;
+            Ok::<(), AppError>(())
+        });
+        if !report.step("connect", connected) {
+            return;
+        }
+
+        let joined = self.add_lobby(lobby).and_then(|_| {
+            PLAYERS.iter().try_for_each(|&pid| {
+                let member =
+                    LobbyMember::new(pid, pid.to_string(), format!("SelfTest{}", pid), None);
+                self.lobbies.add_player(&lobby_id, member)?;
+                self.apply_player_event(
+                    pid,
+                    PlayerEvent::JoinLobby {
+                        lobby_id: lobby_id.clone(),
+                    },
+                )?;
+                self.lobbies
+                    .get_mut(&lobby_id)
+                    .ok_or(AppError::LobbyNotFound)?
+                    .set_ready(pid, true)?;
+                Ok(())
+            })
+        });
+        if !report.step("join_lobby", joined) {
+            return;
+        }
+
+        let created = self
+            .create_generated_game_from_lobby(
+                &lobby_id,
+                GAME_ID.to_string(),
+                GameConfig::default(),
+            )
+            .and_then(|game| {
+                game.start()?;
+                let current = game.current_player_id().ok_or(GameError::NotEnoughPlayers)?;
+                game.pass_turn(current)?;
+                Ok(())
+            });
+        if !report.step("play_game", created) {
+            return;
+        }
+
+        let synced = match self.resync(PLAYERS[0]).game {
+            Some(_) => Ok(()),
+            None => Err("resync bundle is missing the game"),
+        };
+        if !report.step("resync", synced) {
+            return;
+        }
+        report.violations.extend(self.check_invariants());
+
+        if !report.step("end_game", self.end_game(GAME_ID).map(|_| ())) {
+            return;
+        }
+
+        let torn_down = PLAYERS.iter().try_for_each(|&pid| {
+            self.lobbies.remove_player(pid);
+            self.apply_player_event(pid, PlayerEvent::LeaveLobby)?;
+            self.apply_player_event(pid, PlayerEvent::Disconnect)?;
+            self.connections.remove(pid);
+            self.remove_player_state(pid);
+            Ok::<(), AppError>(())
+        });
+        self.games.remove(GAME_ID);
+        self.lobbies.remove(&lobby_id);
+        let empty = self.lobbies.count() == 0
+            && self.games.count() == 0
+            && self.connections.total_count() == 0;
+        let torn_down = torn_down.map_err(|e| e.to_string()).and_then(|_| {
+            if empty {
+                Ok(())
+            } else {
+                Err("state not empty after teardown".to_string())
+            }
+        });
+        report.step("teardown", torn_down);
     }
 
     /// Cleanup stale connections and remove expired players.
     pub fn cleanup(&mut self) -> CleanupResult {
+        // Games can also end inside the game itself (forfeits, `Game::end`),
+        // so release their lobbies before they're removed
+        let ended: Vec<String> = self
+            .games
+            .iter()
+            .filter(|g| g.status.is_terminal())
+            .map(|g| g.id.clone())
+            .collect();
+        for game_id in &ended {
+            self.release_game(game_id);
+        }
+
         let expired_connections = self.connections.expire_stale();
         let empty_lobbies = self.lobbies.cleanup_empty();
         let finished_games = self.games.cleanup_finished();
@@ -137,12 +1166,193 @@ impl AppState {
     }
 }
 
+/// Errors from orchestration helpers that span several managers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppError {
+    GameNotFound,
+    LobbyNotFound,
+    /// The actor may not run this command
+    Forbidden,
+    GuildQuotaExceeded {
+        guild_id: String,
+        resource: GuildResource,
+    },
+    Game(GameError),
+    Lobby(LobbyError),
+    Transition(InvalidTransition),
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::GameNotFound => write!(f, "Game not found"),
+            Self::LobbyNotFound => write!(f, "Lobby not found"),
+            Self::Forbidden => write!(f, "You don't have permission to do that"),
+            Self::GuildQuotaExceeded { resource, .. } => {
+                write!(f, "Server limit reached for {} in this guild", resource.as_str())
+            }
+            Self::Game(e) => write!(f, "{}", e),
+            Self::Lobby(e) => write!(f, "{}", e),
+            Self::Transition(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl AppError {
+    /// Stable identifier clients use to look up a localized message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::GameNotFound => "game_not_found",
+            Self::LobbyNotFound => "lobby_not_found",
+            Self::Forbidden => "forbidden",
+            Self::GuildQuotaExceeded { .. } => "guild_quota_exceeded",
+            Self::Game(e) => e.code(),
+            Self::Lobby(e) => e.code(),
+            Self::Transition(_) => "invalid_transition",
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<GameError> for AppError {
+    fn from(e: GameError) -> Self {
+        Self::Game(e)
+    }
+}
+
+impl From<LobbyError> for AppError {
+    fn from(e: LobbyError) -> Self {
+        Self::Lobby(e)
+    }
+}
+
+impl From<InvalidTransition> for AppError {
+    fn from(e: InvalidTransition) -> Self {
+        Self::Transition(e)
+    }
+}
+
+/// Result of a presence change applied by [`AppState::set_player_connected`].
+///
+/// Tells the caller which lobby and game need to be notified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresenceChange {
+    pub player_id: i64,
+    pub connected: bool,
+    /// Lobby whose member flag was updated
+    pub lobby_id: Option<String>,
+    /// Game whose player flag was updated
+    pub game_id: Option<String>,
+    /// Player state machine event applied as a side effect
+    pub player_event: Option<PlayerEvent>,
+}
+
+/// What a broadcast is about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AudienceTarget {
+    Game(String),
+    Lobby(String),
+}
+
+/// Recipients of a broadcast, from [`AppState::audience`].
+///
+/// The lists may overlap (game players are usually lobby members too); use
+/// [`Audience::all`] for a deduplicated recipient list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Audience {
+    /// Game players, in turn order
+    pub player_ids: Vec<i64>,
+    pub spectator_ids: Vec<i64>,
+    pub lobby_member_ids: Vec<i64>,
+}
+
+impl Audience {
+    /// Every recipient once, sorted.
+    pub fn all(&self) -> Vec<i64> {
+        let mut ids: Vec<i64> = self
+            .player_ids
+            .iter()
+            .chain(&self.spectator_ids)
+            .chain(&self.lobby_member_ids)
+            .copied()
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Check if there are no recipients.
+    pub fn is_empty(&self) -> bool {
+        self.player_ids.is_empty()
+            && self.spectator_ids.is_empty()
+            && self.lobby_member_ids.is_empty()
+    }
+}
+
+/// A player's location and presence, as shown to clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerOverview {
+    pub player_id: i64,
+    pub username: Option<String>,
+    pub avatar_url: Option<String>,
+    pub location: PlayerLocation,
+    pub presence: Presence,
+}
+
+impl PlayerOverview {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "username": self.username,
+            "avatar_url": self.avatar_url,
+            "location": self.location.to_string(),
+            "lobby_id": self.location.lobby_id(),
+            "game_id": self.location.game_id(),
+            "presence": self.presence.as_str()
+        })
+    }
+}
+
+/// Everything a reconnecting client needs, from [`AppState::resync`].
+#[derive(Debug, Clone)]
+pub struct SyncBundle {
+    pub overview: PlayerOverview,
+    /// Lobby snapshot, redacted for the player
+    pub lobby: Option<serde_json::Value>,
+    /// Game snapshot, redacted for the player
+    pub game: Option<serde_json::Value>,
+    /// Unacknowledged messages from the player's connection
+    pub replay: ReplayPlan,
+}
+
+impl SyncBundle {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "player": self.overview.to_json(),
+            "lobby": self.lobby,
+            "game": self.game,
+            "replay": self.replay.to_json()
+        })
+    }
+}
+
+/// Result of [`AppState::update_player_identity`].
+///
+/// Tells the caller which lobby and game snapshots changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityChange {
+    pub player_id: i64,
+    pub lobby_id: Option<String>,
+    pub game_id: Option<String>,
+}
+
 /// Result of cleanup operation.
 #[derive(Debug, Default)]
 pub struct CleanupResult {
     pub expired_connections: Vec<i64>,
     pub empty_lobbies: Vec<String>,
-    pub finished_games: Vec<String>,
+    /// Removed games, with the reason for cancelled ones
+    pub finished_games: Vec<(String, Option<CancelReason>)>,
 }
 
 impl CleanupResult {
@@ -171,4 +1381,792 @@ mod tests {
             .unwrap();
         assert!(state.get_player_state(1).unwrap().is_connected());
     }
+
+    #[test]
+    fn test_player_event_updates_activity_class() {
+        let mut state = AppState::new();
+        state.connections.add(Connection::new(
+            1,
+            "1000".to_string(),
+            "P1".to_string(),
+            None,
+            "session-1".to_string(),
+        ));
+
+        state.apply_player_event(1, PlayerEvent::Connect).unwrap();
+        state
+            .apply_player_event(1, PlayerEvent::JoinLobby { lobby_id: "lobby-1".to_string() })
+            .unwrap();
+        state
+            .apply_player_event(1, PlayerEvent::StartGame { game_id: "game-1".to_string() })
+            .unwrap();
+        assert_eq!(
+            state.connections.get(1).unwrap().activity_class,
+            ActivityClass::InGame
+        );
+
+        state.apply_player_event(1, PlayerEvent::LeaveGame).unwrap();
+        assert_eq!(
+            state.connections.get(1).unwrap().activity_class,
+            ActivityClass::InLobby
+        );
+    }
+
+    #[test]
+    fn test_guild_quotas_enforced() {
+        let mut state = AppState::new();
+        state.guild_quotas = GuildQuotas::new(GuildQuota {
+            max_connections: Some(1),
+            max_lobbies: Some(1),
+            max_games: Some(1),
+        });
+
+        let conn = |pid: i64, guild: &str| {
+            Connection::new(pid, pid.to_string(), format!("P{}", pid), None, format!("s-{}", pid))
+                .with_guild(guild.to_string())
+        };
+        state.add_connection(conn(1, "g1")).unwrap();
+        assert!(matches!(
+            state.add_connection(conn(2, "g1")),
+            Err(AppError::GuildQuotaExceeded {
+                resource: GuildResource::Connections,
+                ..
+            })
+        ));
+        // Reconnecting the same player and other guilds are fine
+        state.add_connection(conn(1, "g1")).unwrap();
+        state.add_connection(conn(3, "g2")).unwrap();
+
+        let lobby_id = state
+            .find_or_create_channel_lobby("chan-1".to_string(), Some("g1".to_string()))
+            .unwrap()
+            .id
+            .clone();
+        assert!(state
+            .find_or_create_channel_lobby("chan-2".to_string(), Some("g1".to_string()))
+            .is_err());
+
+        state
+            .add_game(Game::new("game-1".to_string(), lobby_id.clone(), make_grid()))
+            .unwrap();
+        assert!(state
+            .add_game(Game::new("game-2".to_string(), lobby_id.clone(), make_grid()))
+            .is_err());
+
+        // Finished games free up the slot
+        state.games.get_mut("game-1").unwrap().cancel(CancelReason::Custom("done".to_string()));
+        state
+            .add_game(Game::new("game-2".to_string(), lobby_id, make_grid()))
+            .unwrap();
+
+        let usage = state.guild_usage("g1");
+        assert_eq!((usage.connections, usage.lobbies, usage.games), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_resume_session_after_restore() {
+        let mut state = AppState::new();
+        state.connections.add(Connection::new(
+            1,
+            "1000".to_string(),
+            "P1".to_string(),
+            None,
+            "session-1".to_string(),
+        ));
+        let lobby_id = state
+            .lobbies
+            .find_or_create_channel("chan-1".to_string(), None)
+            .id
+            .clone();
+        state
+            .lobbies
+            .add_player(
+                &lobby_id,
+                LobbyMember::new(1, "1000".to_string(), "P1".to_string(), None),
+            )
+            .unwrap();
+
+        let changes = state.reconcile_after_restore(Duration::from_secs(60));
+        assert_eq!(changes.len(), 1);
+        assert!(!state.lobbies.get(&lobby_id).unwrap().get_member(1).unwrap().is_connected);
+
+        let (player_id, plan) = state.resume_session("session-1").unwrap();
+        assert_eq!(player_id, 1);
+        assert!(plan.is_empty());
+        assert!(state.lobbies.get(&lobby_id).unwrap().get_member(1).unwrap().is_connected);
+    }
+
+    #[test]
+    fn test_update_player_identity_propagates() {
+        let mut state = AppState::new();
+        state.connections.add(Connection::new(
+            1,
+            "1000".to_string(),
+            "Old".to_string(),
+            None,
+            "session-1".to_string(),
+        ));
+        let lobby_id = state
+            .lobbies
+            .find_or_create_channel("chan-1".to_string(), None)
+            .id
+            .clone();
+        state
+            .lobbies
+            .add_player(
+                &lobby_id,
+                LobbyMember::new(1, "1000".to_string(), "Old".to_string(), None),
+            )
+            .unwrap();
+        let mut game = Game::new("game-1".to_string(), lobby_id.clone(), make_grid());
+        game.add_player(GamePlayer::new(1, "1000".to_string(), "Old".to_string(), None, 0))
+            .unwrap();
+        state.games.add(game).unwrap();
+
+        let change = state.update_player_identity(1, "New".to_string(), None);
+        assert_eq!(change.game_id.as_deref(), Some("game-1"));
+
+        assert_eq!(state.connections.get(1).unwrap().username, "New");
+        assert_eq!(
+            state.lobbies.get(&lobby_id).unwrap().get_member(1).unwrap().username,
+            "New"
+        );
+        assert_eq!(
+            state.games.get("game-1").unwrap().get_player(1).unwrap().username,
+            "New"
+        );
+    }
+
+    #[test]
+    fn test_player_overview_presence() {
+        let mut state = AppState::new();
+        assert_eq!(state.player_overview(1).presence, Presence::Offline);
+
+        state.connections.add(Connection::new(
+            1,
+            "1000".to_string(),
+            "P1".to_string(),
+            None,
+            "session-1".to_string(),
+        ));
+        state.apply_player_event(1, PlayerEvent::Connect).unwrap();
+        state.connections.get_mut(1).unwrap().degraded = true;
+
+        let overview = state.player_overview(1);
+        assert_eq!(overview.presence, Presence::Unstable);
+        assert_eq!(overview.location, PlayerLocation::Connected);
+        assert_eq!(overview.to_json()["presence"], "unstable");
+    }
+
+    fn make_grid() -> Grid {
+        std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')))
+    }
+
+    #[test]
+    fn test_games_ending_on_their_own_release_lobby() {
+        let mut state = AppState::new();
+        for (code, game_id) in [("AAA", "game-1"), ("BBB", "game-2")] {
+            let mut lobby = Lobby::new_custom(code.to_string());
+            let lobby_id = lobby.id.clone();
+            let pid = if game_id == "game-1" { 1 } else { 2 };
+            lobby
+                .add_member(LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None))
+                .unwrap();
+            lobby.set_active_game(Some(game_id.to_string()));
+            state.lobbies.add(lobby);
+
+            let mut game = Game::new(game_id.to_string(), lobby_id.clone(), make_grid());
+            game.config.max_duration = Some(chrono::Duration::minutes(30));
+            game.add_player(GamePlayer::new(pid, pid.to_string(), format!("P{}", pid), None, 0))
+                .unwrap();
+            game.start().unwrap();
+            state.games.add(game).unwrap();
+            state.apply_player_event(pid, PlayerEvent::Connect).unwrap();
+            state.apply_player_event(pid, PlayerEvent::JoinLobby { lobby_id }).unwrap();
+            state
+                .apply_player_event(pid, PlayerEvent::StartGame { game_id: game_id.to_string() })
+                .unwrap();
+        }
+
+        // game-1 runs out of time; game-2 is ended directly on the game
+        let later = chrono::Utc::now() + chrono::Duration::hours(1);
+        state.games.get_mut("game-2").unwrap().config.max_duration = None;
+        assert_eq!(state.tick_games(later), vec!["game-1".to_string()]);
+        assert!(!state.lobbies.get("custom-AAA").unwrap().has_active_game());
+        assert!(state.get_player_state(1).unwrap().is_in_lobby());
+
+        state.games.get_mut("game-2").unwrap().end().unwrap();
+        state.cleanup();
+        assert!(!state.lobbies.get("custom-BBB").unwrap().has_active_game());
+        assert!(state.get_player_state(2).unwrap().is_in_lobby());
+        assert!(state.check_invariants().is_empty());
+    }
+
+    #[test]
+    fn test_abort_game_releases_lobby() {
+        let mut state = AppState::new();
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        let lobby_id = lobby.id.clone();
+        for pid in [1, 2] {
+            lobby
+                .add_member(LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None))
+                .unwrap();
+            lobby.set_ready(pid, true).unwrap();
+        }
+        lobby.set_active_game(Some("game-1".to_string()));
+        state.lobbies.add(lobby);
+
+        let mut game = Game::new("game-1".to_string(), lobby_id.clone(), make_grid());
+        for pid in [1, 2] {
+            game.add_player(GamePlayer::new(pid, pid.to_string(), format!("P{}", pid), None, 0))
+                .unwrap();
+            state.apply_player_event(pid, PlayerEvent::Connect).unwrap();
+            state
+                .apply_player_event(pid, PlayerEvent::JoinLobby { lobby_id: lobby_id.clone() })
+                .unwrap();
+            state
+                .apply_player_event(pid, PlayerEvent::StartGame { game_id: "game-1".to_string() })
+                .unwrap();
+        }
+        state.games.add(game).unwrap();
+
+        // Player 2 is not the host
+        assert_eq!(
+            state.abort_game("game-1", 2),
+            Err(AppError::Lobby(LobbyError::NotHost))
+        );
+
+        state.abort_game("game-1", 1).unwrap();
+        let lobby = state.lobbies.get(&lobby_id).unwrap();
+        assert!(!lobby.has_active_game());
+        assert_eq!(lobby.ready_count(), 0);
+        assert_eq!(state.games.get("game-1").unwrap().status, GameStatus::Cancelled);
+        assert!(!state.get_player_state(2).unwrap().is_in_game());
+        assert!(state.get_player_state(2).unwrap().is_in_lobby());
+    }
+
+    #[test]
+    fn test_become_player_mid_game() {
+        let mut state = AppState::new();
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), make_grid());
+        for pid in [1, 2] {
+            game.add_player(GamePlayer::new(pid, pid.to_string(), format!("P{}", pid), None, 0))
+                .unwrap();
+        }
+        game.add_spectator(Spectator {
+            player_id: 9,
+            user_id: "9".to_string(),
+            username: "Watcher".to_string(),
+            avatar_url: None,
+        })
+        .unwrap();
+        game.start().unwrap();
+        game.get_player_mut(1)
+            .unwrap()
+            .add_score(ScoreSource::Word("TEST".to_string()), 10);
+        state.games.add(game).unwrap();
+
+        // Must be spectating first
+        assert!(matches!(
+            state.become_player(9, StartingScore::Average),
+            Err(AppError::Transition(_))
+        ));
+
+        *state.player_state(9) = PlayerState::at(PlayerLocation::Spectating {
+            lobby_id: "lobby-1".to_string(),
+            game_id: "game-1".to_string(),
+        });
+        let snapshot = state.become_player(9, StartingScore::Average).unwrap();
+        assert_eq!(snapshot["game_id"], "game-1");
+        assert!(state.get_player_state(9).unwrap().is_playing());
+        assert_eq!(state.games.get_for_player(9).unwrap().id, "game-1");
+
+        let game = state.games.get("game-1").unwrap();
+        let player = game.get_player(9).unwrap();
+        assert_eq!((player.score(), player.turn_order), (5, 2));
+        assert!(player.verify_score().is_ok());
+        assert_eq!(game.spectator_count(), 0);
+    }
+
+    #[test]
+    fn test_self_test_passes() {
+        let state = AppState::new();
+        let report = state.self_test();
+        assert!(report.passed(), "{}", report.to_json());
+        assert_eq!(report.steps.len(), 6);
+        assert_eq!(state.lobbies.count(), 0);
+    }
+
+    #[test]
+    fn test_check_invariants_detects_broken_links() {
+        let mut state = AppState::new();
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        lobby.set_active_game(Some("missing".to_string()));
+        state.lobbies.add(lobby);
+        state.apply_player_event(1, PlayerEvent::Connect).unwrap();
+        state
+            .apply_player_event(1, PlayerEvent::JoinLobby { lobby_id: "custom-ABC123".to_string() })
+            .unwrap();
+
+        let invariants: Vec<&str> =
+            state.check_invariants().iter().map(|v| v.invariant).collect();
+        assert!(invariants.contains(&"lobby_active_game"));
+        assert!(invariants.contains(&"player_location"));
+    }
+
+    #[test]
+    fn test_resync_bundle() {
+        let mut state = AppState::new();
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        let lobby_id = lobby.id.clone();
+        lobby
+            .add_member(LobbyMember::new(1, "1000".to_string(), "P1".to_string(), None))
+            .unwrap();
+        state.lobbies.add(lobby);
+
+        let mut game = Game::new("game-1".to_string(), lobby_id.clone(), make_grid());
+        game.add_player(GamePlayer::new(1, "1000".to_string(), "P1".to_string(), None, 0))
+            .unwrap();
+        state.games.add(game).unwrap();
+
+        let mut conn = Connection::new(
+            1,
+            "1000".to_string(),
+            "P1".to_string(),
+            None,
+            "session-1".to_string(),
+        );
+        conn.send(serde_json::json!({"type": "missed"}));
+        state.connections.add(conn);
+        state.apply_player_event(1, PlayerEvent::Connect).unwrap();
+        state
+            .apply_player_event(1, PlayerEvent::JoinLobby { lobby_id: lobby_id.clone() })
+            .unwrap();
+
+        let bundle = state.resync(1);
+        assert_eq!(bundle.overview.location.lobby_id(), Some(lobby_id.as_str()));
+        assert_eq!(bundle.lobby.as_ref().unwrap()["lobby_id"], lobby_id);
+        assert_eq!(bundle.game.as_ref().unwrap()["game_id"], "game-1");
+        assert_eq!(bundle.replay.last_seq(), Some(1));
+        assert_eq!(bundle.to_json()["replay"]["messages"][0]["message"]["type"], "missed");
+
+        let empty = state.resync(2);
+        assert!(empty.lobby.is_none() && empty.game.is_none() && empty.replay.is_empty());
+    }
+
+    #[test]
+    fn test_set_player_connected_syncs_flags() {
+        let mut state = AppState::new();
+        state.connections.add(Connection::new(
+            1,
+            "1000".to_string(),
+            "P1".to_string(),
+            None,
+            "session-1".to_string(),
+        ));
+        let lobby_id = state
+            .lobbies
+            .find_or_create_channel("chan-1".to_string(), None)
+            .id
+            .clone();
+        state
+            .lobbies
+            .add_player(
+                &lobby_id,
+                LobbyMember::new(1, "1000".to_string(), "P1".to_string(), None),
+            )
+            .unwrap();
+        let mut game = Game::new("game-1".to_string(), lobby_id.clone(), make_grid());
+        game.add_player(GamePlayer::new(1, "1000".to_string(), "P1".to_string(), None, 0))
+            .unwrap();
+        state.games.add(game).unwrap();
+
+        let change = state.set_player_connected(1, false).unwrap();
+        assert_eq!(change.lobby_id.as_deref(), Some(lobby_id.as_str()));
+        assert_eq!(change.game_id.as_deref(), Some("game-1"));
+        assert!(!state.connections.get(1).unwrap().status.is_connected());
+        assert!(!state.lobbies.get(&lobby_id).unwrap().get_member(1).unwrap().is_connected);
+        assert!(!state.games.get("game-1").unwrap().get_player(1).unwrap().is_connected);
+
+        let change = state.set_player_connected(1, true).unwrap();
+        assert_eq!(change.player_event, Some(PlayerEvent::Connect));
+        assert!(state.connections.get(1).unwrap().status.is_connected());
+        assert!(state.lobbies.get(&lobby_id).unwrap().get_member(1).unwrap().is_connected);
+        assert!(state.games.get("game-1").unwrap().get_player(1).unwrap().is_connected);
+    }
+
+    #[test]
+    fn test_disconnect_grace_depends_on_location() {
+        let mut state = AppState::new();
+        state.connection_config.connected_grace = Duration::ZERO;
+        for pid in [1, 2] {
+            state.connections.add(Connection::new(
+                pid,
+                format!("{}", pid * 1000),
+                format!("P{}", pid),
+                None,
+                format!("session-{}", pid),
+            ));
+            state.apply_player_event(pid, PlayerEvent::Connect).unwrap();
+        }
+        state
+            .apply_player_event(
+                2,
+                PlayerEvent::JoinLobby {
+                    lobby_id: "lobby-1".to_string(),
+                },
+            )
+            .unwrap();
+
+        state.set_player_connected(1, false).unwrap();
+        state.set_player_connected(2, false).unwrap();
+
+        assert!(!state.connections.get(1).unwrap().status.is_reconnectable());
+        assert!(state.connections.get(2).unwrap().status.is_reconnectable());
+    }
+
+    #[test]
+    fn test_handle_request_dedupes_retries() {
+        let mut state = AppState::new();
+        state.connections.add(Connection::new(
+            1,
+            "1000".to_string(),
+            "P1".to_string(),
+            None,
+            "session-1".to_string(),
+        ));
+
+        let mut calls = 0;
+        for _ in 0..2 {
+            let result = state.handle_request(1, Some("req-1"), |_| {
+                calls += 1;
+                serde_json::json!({ "calls": calls })
+            });
+            assert_eq!(result["calls"], 1);
+        }
+
+        state.handle_request(1, None, |_| {
+            calls += 1;
+            serde_json::Value::Null
+        });
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn test_audience_resolution() {
+        let mut state = AppState::new();
+        let lobby_id = state
+            .lobbies
+            .find_or_create_channel("chan-1".to_string(), None)
+            .id
+            .clone();
+        for pid in [1, 2, 3] {
+            state
+                .lobbies
+                .add_player(
+                    &lobby_id,
+                    LobbyMember::new(pid, format!("{}", pid * 1000), format!("P{}", pid), None),
+                )
+                .unwrap();
+        }
+
+        let mut game = Game::new("game-1".to_string(), lobby_id.clone(), make_grid());
+        for (order, pid) in [2, 1].into_iter().enumerate() {
+            game.add_player(GamePlayer::new(
+                pid,
+                format!("{}", pid * 1000),
+                format!("P{}", pid),
+                None,
+                order as u8,
+            ))
+            .unwrap();
+        }
+        game.add_spectator(Spectator {
+            player_id: 9,
+            user_id: "9000".to_string(),
+            username: "Watcher".to_string(),
+            avatar_url: None,
+        })
+        .unwrap();
+        state.games.add(game).unwrap();
+        state
+            .lobbies
+            .get_mut(&lobby_id)
+            .unwrap()
+            .set_active_game(Some("game-1".to_string()));
+
+        let audience = state.audience(&AudienceTarget::Game("game-1".to_string()));
+        assert_eq!(audience.player_ids, vec![2, 1]);
+        assert_eq!(audience.spectator_ids, vec![9]);
+        assert_eq!(audience.lobby_member_ids, vec![1, 2, 3]);
+        assert_eq!(audience.all(), vec![1, 2, 3, 9]);
+
+        assert_eq!(state.audience(&AudienceTarget::Lobby(lobby_id)), audience);
+        assert!(state
+            .audience(&AudienceTarget::Game("missing".to_string()))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_execute_kick_and_settings() {
+        let mut state = AppState::new();
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        let lobby_id = lobby.id.clone();
+        for pid in [1, 2] {
+            lobby
+                .add_member(LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None))
+                .unwrap();
+        }
+        state.lobbies.add(lobby);
+        for pid in [1, 2] {
+            state.apply_player_event(pid, PlayerEvent::Connect).unwrap();
+            state
+                .apply_player_event(pid, PlayerEvent::JoinLobby { lobby_id: lobby_id.clone() })
+                .unwrap();
+        }
+
+        let settings = Command::SetMaxPlayers {
+            lobby_id: lobby_id.clone(),
+            max_players: 1,
+        };
+        assert_eq!(
+            state.execute(1, settings),
+            Err(AppError::Lobby(LobbyError::InvalidSettings))
+        );
+
+        state
+            .execute(
+                1,
+                Command::KickPlayer {
+                    lobby_id: lobby_id.clone(),
+                    player_id: 2,
+                },
+            )
+            .unwrap();
+        assert!(!state.lobbies.get(&lobby_id).unwrap().has_member(2));
+        assert_eq!(state.get_player_state(2).unwrap().location(), &PlayerLocation::Connected);
+        assert!(state.lobbies.rejoin_cooldown(2, &lobby_id).is_some());
+    }
+
+    #[test]
+    fn test_admin_controls_are_audited() {
+        let mut state = AppState::new();
+        state.admin_ids.insert(99);
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        let lobby_id = lobby.id.clone();
+        let mut game = Game::new("game-1".to_string(), lobby_id.clone(), make_grid());
+        for pid in [1, 2] {
+            lobby
+                .add_member(LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None))
+                .unwrap();
+            game.add_player(GamePlayer::new(pid, pid.to_string(), format!("P{}", pid), None, 0))
+                .unwrap();
+            state.apply_player_event(pid, PlayerEvent::Connect).unwrap();
+            state
+                .apply_player_event(pid, PlayerEvent::JoinLobby { lobby_id: lobby_id.clone() })
+                .unwrap();
+            state
+                .apply_player_event(pid, PlayerEvent::StartGame { game_id: "game-1".to_string() })
+                .unwrap();
+        }
+        lobby.set_active_game(Some("game-1".to_string()));
+        state.lobbies.add(lobby);
+        game.start().unwrap();
+        state.games.add(game).unwrap();
+
+        let remove = Command::RemoveGamePlayer {
+            game_id: "game-1".to_string(),
+            player_id: 2,
+        };
+        assert_eq!(state.execute(1, remove.clone()), Err(AppError::Forbidden));
+        assert!(state.audit_log().is_empty());
+
+        state.execute(99, remove).unwrap();
+        assert!(state.games.get_for_player(2).is_none());
+        assert!(!state.get_player_state(2).unwrap().is_in_game());
+
+        let result = state.force_end_game(99, "game-1").unwrap();
+        assert_eq!(result.entries.len(), 1);
+        assert!(!state.lobbies.get(&lobby_id).unwrap().has_active_game());
+        assert_eq!(state.audit_log().for_game("game-1").count(), 2);
+    }
+
+    #[test]
+    fn test_custom_authorizer() {
+        #[derive(Debug)]
+        struct DenyAll;
+        impl Authorizer for DenyAll {
+            fn authorize(&self, _: &AppState, _: i64, _: &Command) -> Result<(), AppError> {
+                Err(LobbyError::NotHost.into())
+            }
+        }
+
+        let mut state = AppState::new();
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        let lobby_id = lobby.id.clone();
+        lobby
+            .add_member(LobbyMember::new(1, "1".to_string(), "P1".to_string(), None))
+            .unwrap();
+        state.lobbies.add(lobby);
+        state.set_authorizer(Box::new(DenyAll));
+
+        let command = Command::SetMaxPlayers {
+            lobby_id,
+            max_players: 4,
+        };
+        assert_eq!(state.execute(1, command), Err(AppError::Lobby(LobbyError::NotHost)));
+    }
+
+    #[test]
+    fn test_seeded_rng_reproducible() {
+        let run = |seed| {
+            let mut state = AppState {
+                rng: RngProvider::new(seed),
+                ..Default::default()
+            };
+            let lobby_id = state.create_custom_lobby(None).unwrap();
+            let lobby = state.lobbies.get_mut(&lobby_id).unwrap();
+            lobby.add_member(LobbyMember::new(1, "1".to_string(), "P1".to_string(), None)).unwrap();
+            lobby.set_ready(1, true).unwrap();
+            let game = state
+                .create_game_from_lobby(&lobby_id, "g".to_string(), make_grid(), Default::default())
+                .unwrap();
+            (lobby_id, game.rng_seed())
+        };
+
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+    }
+
+    #[test]
+    fn test_create_game_from_lobby() {
+        let mut state = AppState::new();
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        let lobby_id = lobby.id.clone();
+        for (pid, rating) in [(1, 1800), (2, 1200), (3, 1500)] {
+            let member = LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None)
+                .with_rating(rating);
+            lobby.add_member(member).unwrap();
+            if pid != 3 {
+                lobby.set_ready(pid, true).unwrap();
+            }
+        }
+        let settings = LobbySettings {
+            locale: "de".to_string(),
+            rounds: Some(3),
+            turn_seconds: Some(45),
+            ..Default::default()
+        };
+        lobby.update_settings(1, settings).unwrap();
+        state.lobbies.add(lobby);
+        for pid in [1, 2, 3] {
+            state.apply_player_event(pid, PlayerEvent::Connect).unwrap();
+            state
+                .apply_player_event(pid, PlayerEvent::JoinLobby { lobby_id: lobby_id.clone() })
+                .unwrap();
+        }
+
+        let config = GameConfig {
+            turn_order: TurnOrderStrategy::ByRating,
+            ..Default::default()
+        };
+        let game = state
+            .create_game_from_lobby(&lobby_id, "game-1".to_string(), make_grid(), config)
+            .unwrap();
+        assert_eq!(game.player_ids_in_order(), &[2, 1]);
+        assert_eq!(game.config.turn_order, TurnOrderStrategy::ByRating);
+        assert_eq!(game.to_json()["locale"], "de");
+        assert_eq!(game.max_rounds, 3);
+        assert_eq!(game.config.turn_time_limit, Some(chrono::Duration::seconds(45)));
+
+        assert!(state.get_player_state(1).unwrap().is_in_game());
+        assert!(!state.get_player_state(3).unwrap().is_in_game());
+        assert_eq!(
+            state.lobbies.get(&lobby_id).unwrap().active_game_id.as_deref(),
+            Some("game-1")
+        );
+        let again = state.create_game_from_lobby(
+            &lobby_id,
+            "game-2".to_string(),
+            make_grid(),
+            GameConfig::default(),
+        );
+        let err = again.unwrap_err();
+        assert_eq!(err, AppError::Lobby(LobbyError::GameInProgress));
+        assert_eq!(err.code(), "lobby_game_in_progress");
+
+        state.games.get_mut("game-1").unwrap().start().unwrap();
+        state.end_game("game-1").unwrap();
+        assert!(!state.lobbies.get(&lobby_id).unwrap().has_active_game());
+        assert!(state.get_player_state(1).unwrap().is_in_lobby());
+    }
+
+    #[test]
+    fn test_generated_game_records_weighting() {
+        let mut state = AppState::new();
+        let mut lobby = Lobby::new_custom("ABC123".to_string());
+        let lobby_id = lobby.id.clone();
+        for (pid, rating) in [(1, 700), (2, 900)] {
+            let member = LobbyMember::new(pid, pid.to_string(), format!("P{}", pid), None)
+                .with_rating(rating);
+            lobby.add_member(member).unwrap();
+            lobby.set_ready(pid, true).unwrap();
+        }
+        state.lobbies.add(lobby);
+
+        let config = GameConfig {
+            grid: GridConfig {
+                adaptive_difficulty: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let game = state
+            .create_generated_game_from_lobby(&lobby_id, "game-1".to_string(), config)
+            .unwrap();
+
+        let weighting = game.letter_weighting.as_ref().unwrap();
+        assert_eq!(weighting.average_rating, Some(800));
+        assert_eq!(weighting.tier, DifficultyTier::Beginner);
+        assert_eq!(game.to_json()["letter_weighting"]["tier"], "beginner");
+
+        // Built boards get the vowel floor
+        let vowels = game.grid.iter().flatten().filter(|c| "AEIOU".contains(c.letter)).count();
+        assert!(vowels >= generation::DEFAULT_MIN_VOWELS);
+    }
+
+    #[test]
+    fn test_lobby_json_recent_games() {
+        let mut state = AppState::new();
+        let lobby_id = state
+            .lobbies
+            .find_or_create_channel("chan-1".to_string(), None)
+            .id
+            .clone();
+        for id in ["game-1", "game-2"] {
+            let mut game = Game::new(id.to_string(), lobby_id.clone(), make_grid());
+            game.add_player(GamePlayer::new(1, "1000".to_string(), "P1".to_string(), None, 0))
+                .unwrap();
+            game.start().unwrap();
+            game.end().unwrap();
+            state.games.add(game).unwrap();
+        }
+        // One archived, one still live
+        state.games.cleanup_finished();
+        let mut game = Game::new("game-3".to_string(), lobby_id.clone(), make_grid());
+        game.add_player(GamePlayer::new(1, "1000".to_string(), "P1".to_string(), None, 0))
+            .unwrap();
+        game.start().unwrap();
+        game.end().unwrap();
+        state.games.add(game).unwrap();
+
+        let json = state.lobby_to_json(&lobby_id, Some(2)).unwrap();
+        let recent = json["recent_games"].as_array().unwrap();
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0]["game_id"], "game-3");
+        assert_eq!(recent[0]["winner"], "P1");
+
+        let json = state.lobby_to_json(&lobby_id, None).unwrap();
+        assert!(json.get("recent_games").is_none());
+    }
 }
@@ -0,0 +1,172 @@
+//! Cursor-based pagination for manager listings.
+//!
+//! Cursors record the sort key of the last item returned (a timestamp plus
+//! an ID to break ties) rather than an offset, so adding or removing items
+//! between requests never skips or repeats the items that remain.
+
+/// Default page size.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Maximum page size callers may request.
+pub const MAX_PAGE_SIZE: usize = 200;
+
+/// Position in a listing: the sort key of the last item already returned.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cursor {
+    pub at: chrono::DateTime<chrono::Utc>,
+    pub id: String,
+}
+
+impl Cursor {
+    pub fn new(at: chrono::DateTime<chrono::Utc>, id: impl Into<String>) -> Self {
+        Self { at, id: id.into() }
+    }
+
+    /// Encode as an opaque token for clients.
+    pub fn encode(&self) -> String {
+        format!(
+            "{}.{:09}:{}",
+            self.at.timestamp(),
+            self.at.timestamp_subsec_nanos(),
+            self.id
+        )
+    }
+
+    /// Decode a token produced by [`Cursor::encode`].
+    pub fn decode(token: &str) -> Option<Self> {
+        let (at, id) = token.split_once(':')?;
+        let (secs, nanos) = at.split_once('.')?;
+        let at = chrono::DateTime::from_timestamp(secs.parse().ok()?, nanos.parse().ok()?)?;
+        Some(Self::new(at, id))
+    }
+}
+
+/// Direction of a listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Oldest first
+    #[default]
+    Ascending,
+    /// Newest first
+    Descending,
+}
+
+/// One page of a listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Token for the next page (None on the last page)
+    pub next_cursor: Option<String>,
+    /// Number of items in the whole listing
+    pub total: usize,
+}
+
+impl<T> Page<T> {
+    /// Paginate items by their cursor key.
+    ///
+    /// Items may be in any order; they are sorted by `key` in `order`, and
+    /// those at or before `after` are skipped. `limit` is clamped to
+    /// `1..=MAX_PAGE_SIZE`.
+    pub fn from_items<F>(
+        items: Vec<T>,
+        after: Option<&Cursor>,
+        limit: usize,
+        order: SortOrder,
+        key: F,
+    ) -> Self
+    where
+        F: Fn(&T) -> Cursor,
+    {
+        let total = items.len();
+        let limit = limit.clamp(1, MAX_PAGE_SIZE);
+
+        let mut keyed: Vec<(Cursor, T)> =
+            items.into_iter().map(|item| (key(&item), item)).collect();
+        keyed.sort_by(|a, b| match order {
+            SortOrder::Ascending => a.0.cmp(&b.0),
+            SortOrder::Descending => b.0.cmp(&a.0),
+        });
+        if let Some(after) = after {
+            keyed.retain(|(k, _)| match order {
+                SortOrder::Ascending => k > after,
+                SortOrder::Descending => k < after,
+            });
+        }
+
+        let has_more = keyed.len() > limit;
+        keyed.truncate(limit);
+        let next_cursor = if has_more {
+            keyed.last().map(|(k, _)| k.encode())
+        } else {
+            None
+        };
+
+        Self {
+            items: keyed.into_iter().map(|(_, item)| item).collect(),
+            next_cursor,
+            total,
+        }
+    }
+
+    /// Transform the items, keeping the cursor and total.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            total: self.total,
+        }
+    }
+
+    /// Convert to JSON, rendering each item with `item_json`.
+    pub fn to_json<F>(&self, item_json: F) -> serde_json::Value
+    where
+        F: Fn(&T) -> serde_json::Value,
+    {
+        serde_json::json!({
+            "items": self.items.iter().map(item_json).collect::<Vec<_>>(),
+            "next_cursor": self.next_cursor,
+            "total": self.total
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: i64) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = Cursor::new(at(1_700_000_000), "custom-AB:C");
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+
+        // Full precision, so items created in the same microsecond stay apart
+        let now = Cursor::new(chrono::Utc::now(), "x");
+        assert_eq!(Cursor::decode(&now.encode()), Some(now));
+        assert_eq!(Cursor::decode("garbage"), None);
+    }
+
+    #[test]
+    fn test_pages_stable_under_changes() {
+        let key = |(secs, id): &(i64, &str)| Cursor::new(at(*secs), *id);
+        let mut items = vec![(1, "a"), (2, "b"), (2, "c"), (3, "d")];
+
+        let first = Page::from_items(items.clone(), None, 2, SortOrder::Ascending, key);
+        assert_eq!(first.items, vec![(1, "a"), (2, "b")]);
+        assert_eq!(first.total, 4);
+
+        // Removing a returned item and inserting an older one don't shift the next page
+        items.retain(|i| i.1 != "b");
+        items.push((0, "z"));
+        let cursor = Cursor::decode(first.next_cursor.as_deref().unwrap()).unwrap();
+        let second = Page::from_items(items.clone(), Some(&cursor), 2, SortOrder::Ascending, key);
+        assert_eq!(second.items, vec![(2, "c"), (3, "d")]);
+        assert_eq!(second.next_cursor, None);
+
+        let newest = Page::from_items(items, None, 1, SortOrder::Descending, key);
+        assert_eq!(newest.items, vec![(3, "d")]);
+    }
+}
@@ -0,0 +1,126 @@
+//! Validated word paths.
+//!
+//! A `WordPath` can only be built from positions that are on the board,
+//! each adjacent to the one before, with no cell used twice and enough cells
+//! for a word. Whether the cells are blocked depends on the board, so
+//! `Game::validate_path` still checks that.
+
+use std::collections::HashSet;
+
+use super::game::{GameError, Grid, Position};
+
+/// A path of cells spelling a word.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WordPath(Vec<Position>);
+
+impl WordPath {
+    /// Validate a path at least `min_len` cells long.
+    pub fn new(positions: Vec<Position>, min_len: usize) -> Result<Self, GameError> {
+        if positions.len() < min_len {
+            return Err(GameError::PathTooShort);
+        }
+        let mut seen = HashSet::new();
+        for (i, pos) in positions.iter().enumerate() {
+            if !pos.is_valid() || !seen.insert(*pos) {
+                return Err(GameError::InvalidPath);
+            }
+            if i > 0 && !positions[i - 1].is_adjacent_to(pos) {
+                return Err(GameError::InvalidPath);
+            }
+        }
+        Ok(Self(positions))
+    }
+
+    /// Parse a path from a JSON array of `{"row", "col"}` objects.
+    pub fn from_json(value: &serde_json::Value, min_len: usize) -> Result<Self, GameError> {
+        let cells = value.as_array().ok_or(GameError::InvalidPath)?;
+        let positions = cells
+            .iter()
+            .map(|cell| {
+                let coord = |key: &str| cell.get(key).and_then(|v| v.as_u64());
+                match (coord("row"), coord("col")) {
+                    (Some(row), Some(col)) => Ok(Position::new(row as usize, col as usize)),
+                    _ => Err(GameError::InvalidPath),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(positions, min_len)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Position> {
+        self.0.iter()
+    }
+
+    pub fn as_slice(&self) -> &[Position] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Always false unless built with a `min_len` of 0.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn into_inner(self) -> Vec<Position> {
+        self.0
+    }
+
+    /// The word the path spells on a board.
+    pub fn word(&self, grid: &Grid) -> String {
+        self.0
+            .iter()
+            .flat_map(|p| grid[p.row][p.col].letters())
+            .collect()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Array(self.0.iter().map(|p| p.to_json()).collect())
+    }
+}
+
+impl AsRef<[Position]> for WordPath {
+    fn as_ref(&self) -> &[Position] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::game::GridCell;
+
+    #[test]
+    fn test_new_validates() {
+        let cat = vec![Position::new(0, 0), Position::new(0, 1), Position::new(1, 2)];
+        let path = WordPath::new(cat.clone(), 3).unwrap();
+        assert_eq!(path.len(), 3);
+
+        assert_eq!(WordPath::new(cat, 4), Err(GameError::PathTooShort));
+        let invalid = [
+            vec![Position::new(0, 0), Position::new(0, 2)],
+            vec![Position::new(0, 0), Position::new(0, 1), Position::new(0, 0)],
+            vec![Position::new(4, 4), Position::new(4, 5)],
+        ];
+        for positions in invalid {
+            assert_eq!(WordPath::new(positions, 2), Err(GameError::InvalidPath));
+        }
+    }
+
+    #[test]
+    fn test_word_and_json() {
+        let mut grid: Grid = std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')));
+        grid[0][0] = GridCell::new('C');
+        grid[1][1] = GridCell::new('T');
+        let cat = vec![Position::new(0, 0), Position::new(0, 1), Position::new(1, 1)];
+        let path = WordPath::new(cat, 3).unwrap();
+        assert_eq!(path.word(&grid), "CAT");
+
+        let parsed = WordPath::from_json(&path.to_json(), 3).unwrap();
+        assert_eq!(parsed, path);
+        let bad = serde_json::json!([{ "row": 0 }]);
+        assert_eq!(WordPath::from_json(&bad, 1), Err(GameError::InvalidPath));
+    }
+}
@@ -0,0 +1,144 @@
+//! Power-ups bought with gems.
+//!
+//! Power-ups are used by the current player during their turn and don't end
+//! it. Prices and strength live in `PowerUpConfig` on the game's config;
+//! `Game::use_power_up` checks the target, charges the gems and applies the
+//! effect.
+
+use super::game::Position;
+
+/// Power-up kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUp {
+    /// Replace the letter on one tile (keeping its multiplier and gem)
+    SwapTile,
+    /// Show the user the best unused word on the board
+    RevealWord,
+    /// Cut time from an opponent's next turn
+    FreezeOpponentTimer,
+}
+
+impl PowerUp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SwapTile => "swap_tile",
+            Self::RevealWord => "reveal_word",
+            Self::FreezeOpponentTimer => "freeze_opponent_timer",
+        }
+    }
+}
+
+/// What a power-up is used on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PowerUpTarget {
+    /// No target (e.g. `RevealWord`)
+    None,
+    /// A tile and the letter to put on it (`SwapTile`)
+    Tile { position: Position, letter: char },
+    /// Another player (`FreezeOpponentTimer`)
+    Player(i64),
+}
+
+impl PowerUpTarget {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::None => serde_json::Value::Null,
+            Self::Tile { position, letter } => serde_json::json!({
+                "position": position.to_json(),
+                "letter": letter.to_string()
+            }),
+            Self::Player(player_id) => serde_json::json!({ "player_id": player_id }),
+        }
+    }
+}
+
+/// Prices (in gems) and strength of power-ups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerUpConfig {
+    pub swap_tile_cost: i32,
+    pub reveal_word_cost: i32,
+    pub freeze_cost: i32,
+    /// Seconds `FreezeOpponentTimer` takes off the target's next turn
+    pub freeze_seconds: u32,
+}
+
+impl Default for PowerUpConfig {
+    fn default() -> Self {
+        Self {
+            swap_tile_cost: 3,
+            reveal_word_cost: 4,
+            freeze_cost: 2,
+            freeze_seconds: 15,
+        }
+    }
+}
+
+impl PowerUpConfig {
+    /// Gem cost of a power-up.
+    pub fn cost(&self, power_up: PowerUp) -> i32 {
+        match power_up {
+            PowerUp::SwapTile => self.swap_tile_cost,
+            PowerUp::RevealWord => self.reveal_word_cost,
+            PowerUp::FreezeOpponentTimer => self.freeze_cost,
+        }
+    }
+}
+
+/// Result of using a power-up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PowerUpEffect {
+    TileSwapped { position: Position, letter: char },
+    /// Only for the user; not broadcast in game events
+    WordRevealed { word: String, path: Vec<Position> },
+    TimerFrozen { target_id: i64, seconds: u32 },
+}
+
+impl PowerUpEffect {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::TileSwapped { position, letter } => serde_json::json!({
+                "type": "tile_swapped",
+                "position": position.to_json(),
+                "letter": letter.to_string()
+            }),
+            Self::WordRevealed { word, path } => serde_json::json!({
+                "type": "word_revealed",
+                "word": word,
+                "path": path.iter().map(|p| p.to_json()).collect::<Vec<_>>()
+            }),
+            Self::TimerFrozen { target_id, seconds } => serde_json::json!({
+                "type": "timer_frozen",
+                "target_id": target_id,
+                "seconds": seconds
+            }),
+        }
+    }
+}
+
+/// A power-up that was used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerUpUse {
+    pub player_id: i64,
+    pub round: u8,
+    pub power_up: PowerUp,
+    /// Gems spent
+    pub gems: i32,
+    pub effect: PowerUpEffect,
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_costs() {
+        let config = PowerUpConfig {
+            reveal_word_cost: 6,
+            ..Default::default()
+        };
+        assert_eq!(config.cost(PowerUp::SwapTile), 3);
+        assert_eq!(config.cost(PowerUp::RevealWord), 6);
+        assert_eq!(PowerUp::FreezeOpponentTimer.as_str(), "freeze_opponent_timer");
+    }
+}
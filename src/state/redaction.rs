@@ -0,0 +1,92 @@
+//! Redaction profiles for snapshots.
+//!
+//! Each `to_json_for` implementation starts from the full snapshot and asks
+//! the viewer's profile what to strip, so the policy for who sees what lives
+//! here rather than in each server.
+
+/// Who a snapshot is being rendered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RedactionProfile {
+    /// A player in the game or member of the lobby
+    Player,
+    /// The lobby host
+    Host,
+    /// Watching a game without playing
+    Spectator,
+    /// Anyone else (e.g. public listings)
+    Public,
+    /// Operators and diagnostics tooling
+    Admin,
+}
+
+impl RedactionProfile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Player => "player",
+            Self::Host => "host",
+            Self::Spectator => "spectator",
+            Self::Public => "public",
+            Self::Admin => "admin",
+        }
+    }
+
+    /// Discord user IDs.
+    pub fn shows_user_ids(&self) -> bool {
+        !matches!(self, Self::Public)
+    }
+
+    /// Lobby join codes and the Discord channel/guild a lobby lives in.
+    pub fn shows_join_details(&self) -> bool {
+        matches!(self, Self::Player | Self::Host | Self::Admin)
+    }
+
+    /// Who is spectating (everyone else only sees a count).
+    pub fn shows_spectator_identities(&self) -> bool {
+        matches!(self, Self::Host | Self::Admin)
+    }
+
+    /// The spectator-only chat channel.
+    pub fn shows_spectator_chat(&self) -> bool {
+        matches!(self, Self::Spectator | Self::Admin)
+    }
+
+    /// Internal connection state (sequence numbers, buffers, guild).
+    pub fn shows_diagnostics(&self) -> bool {
+        matches!(self, Self::Admin)
+    }
+}
+
+/// Remove keys from a JSON object. Non-objects are left untouched.
+pub(crate) fn remove_keys(json: &mut serde_json::Value, keys: &[&str]) {
+    if let Some(object) = json.as_object_mut() {
+        for key in keys {
+            object.remove(*key);
+        }
+    }
+}
+
+/// Remove keys from every object in the JSON array at `field`.
+pub(crate) fn remove_keys_in(json: &mut serde_json::Value, field: &str, keys: &[&str]) {
+    if let Some(items) = json.get_mut(field).and_then(|v| v.as_array_mut()) {
+        for item in items {
+            remove_keys(item, keys);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_keys_in() {
+        let mut json = serde_json::json!({
+            "code": "ABCD",
+            "players": [{ "user_id": "1", "username": "A" }]
+        });
+        remove_keys(&mut json, &["code"]);
+        remove_keys_in(&mut json, "players", &["user_id"]);
+
+        assert_eq!(json, serde_json::json!({ "players": [{ "username": "A" }] }));
+    }
+}
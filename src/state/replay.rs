@@ -0,0 +1,609 @@
+//! Game replays.
+//!
+//! A game built with `Game::with_replay_recording` keeps the ordered list of
+//! state-changing calls made on it. `Game::export_replay` bundles them with
+//! the starting grid, configuration and RNG seed, and
+//! `Replay::reconstruct_at` plays a prefix of them back into a fresh `Game`,
+//! so any point of a finished game can be inspected.
+//!
+//! Steps are re-applied through the same `Game` methods, so timestamps in a
+//! reconstructed game are those of the reconstruction, not the original.
+
+use std::sync::Arc;
+
+use super::dictionary::Dictionary;
+use super::game::{
+    CancelReason, CellChange, Game, GameConfig, GamePlayer, Grid, Position, Spectator,
+    StartingScore, SubmissionLimits,
+};
+use super::powerup::{PowerUp, PowerUpTarget};
+
+/// One recorded state change.
+#[derive(Debug, Clone)]
+pub enum ReplayStep {
+    Join(GamePlayer),
+    SetTurnOrder(Vec<i64>),
+    PromoteSpectator {
+        spectator: Spectator,
+        starting_score: StartingScore,
+    },
+    Start,
+    AdvanceTurn,
+    SetTurnDuration(Option<chrono::Duration>),
+    /// The current turn ran out
+    ExpireTurn,
+    PlayWord { player_id: i64, path: Vec<Position> },
+    PlayBlitzWord {
+        player_id: i64,
+        path: Vec<Position>,
+        at: chrono::DateTime<chrono::Utc>,
+    },
+    Pass { player_id: i64 },
+    Forfeit { player_id: i64 },
+    ExtendTurn { player_id: i64, seconds: u32 },
+    UsePowerUp {
+        player_id: i64,
+        power_up: PowerUp,
+        target: PowerUpTarget,
+    },
+    Hint { player_id: i64 },
+    /// A word marked used without being played
+    UseWord(String),
+    SetConnected { player_id: i64, connected: bool },
+    /// Bonuses moved by `Game::reroll_bonuses`
+    GridChanged(Vec<CellChange>),
+    RefreshCells(Vec<Position>),
+    StartTimerVote { initiator_id: i64 },
+    AddTimerVote { voter_id: i64 },
+    StartKickVote { initiator_id: i64, target_id: i64 },
+    AddKickVote { voter_id: i64 },
+    ChallengeWord { challenger_id: i64 },
+    /// Outside ruling on the open challenge
+    ResolveChallenge(bool),
+    Pause(String),
+    Resume,
+    ForceSkip { by: i64 },
+    AdjustScore {
+        player_id: i64,
+        amount: i32,
+        reason: String,
+        /// The admin who made the change, None for rule adjustments
+        by: Option<i64>,
+    },
+    RemovePlayer { player_id: i64, by: i64 },
+    ForceEnd { by: i64 },
+    End,
+    /// The game's time ran out (no overtime)
+    TimeUp,
+    Cancel(CancelReason),
+    Abort { by: i64 },
+}
+
+impl ReplayStep {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Join(_) => "join",
+            Self::SetTurnOrder(_) => "set_turn_order",
+            Self::PromoteSpectator { .. } => "promote_spectator",
+            Self::Start => "start",
+            Self::AdvanceTurn => "advance_turn",
+            Self::SetTurnDuration(_) => "set_turn_duration",
+            Self::ExpireTurn => "expire_turn",
+            Self::PlayWord { .. } => "play_word",
+            Self::PlayBlitzWord { .. } => "play_blitz_word",
+            Self::Pass { .. } => "pass",
+            Self::Forfeit { .. } => "forfeit",
+            Self::ExtendTurn { .. } => "extend_turn",
+            Self::UsePowerUp { .. } => "use_power_up",
+            Self::Hint { .. } => "hint",
+            Self::UseWord(_) => "use_word",
+            Self::SetConnected { .. } => "set_connected",
+            Self::GridChanged(_) => "grid_changed",
+            Self::RefreshCells(_) => "refresh_cells",
+            Self::StartTimerVote { .. } => "start_timer_vote",
+            Self::AddTimerVote { .. } => "add_timer_vote",
+            Self::StartKickVote { .. } => "start_kick_vote",
+            Self::AddKickVote { .. } => "add_kick_vote",
+            Self::ChallengeWord { .. } => "challenge_word",
+            Self::ResolveChallenge(_) => "resolve_challenge",
+            Self::Pause(_) => "pause",
+            Self::Resume => "resume",
+            Self::ForceSkip { .. } => "force_skip",
+            Self::AdjustScore { .. } => "adjust_score",
+            Self::RemovePlayer { .. } => "remove_player",
+            Self::ForceEnd { .. } => "force_end",
+            Self::End => "end",
+            Self::TimeUp => "time_up",
+            Self::Cancel(_) => "cancel",
+            Self::Abort { .. } => "abort",
+        }
+    }
+
+    /// Re-apply this step to a game.
+    ///
+    /// Steps are only recorded when they succeeded, so errors here mean the
+    /// replay was applied out of order; they are ignored.
+    pub fn apply(&self, game: &mut Game) {
+        match self {
+            Self::Join(player) => {
+                let _ = game.add_player(player.clone());
+            }
+            Self::SetTurnOrder(order) => {
+                let _ = game.set_turn_order(order);
+            }
+            Self::PromoteSpectator {
+                spectator,
+                starting_score,
+            } => {
+                let _ = game.add_spectator(spectator.clone());
+                let _ = game.promote_spectator(spectator.player_id, *starting_score);
+            }
+            Self::Start => {
+                let _ = game.start();
+            }
+            Self::AdvanceTurn => {
+                game.advance_turn();
+            }
+            Self::SetTurnDuration(limit) => game.set_turn_duration(*limit),
+            Self::ExpireTurn => {
+                let now = game.turn_deadline.unwrap_or_else(chrono::Utc::now);
+                game.expire_turn(now);
+            }
+            Self::PlayWord { player_id, path } => {
+                let _ = game.submit_word(*player_id, path);
+            }
+            Self::PlayBlitzWord {
+                player_id,
+                path,
+                at,
+            } => {
+                let _ = game.play_blitz_word(*player_id, path, *at);
+            }
+            Self::Pass { player_id } => {
+                let _ = game.pass_turn(*player_id);
+            }
+            Self::Forfeit { player_id } => {
+                let _ = game.forfeit(*player_id);
+            }
+            Self::ExtendTurn { player_id, seconds } => {
+                let _ = game.extend_turn(*player_id, *seconds);
+            }
+            Self::UsePowerUp {
+                player_id,
+                power_up,
+                target,
+            } => {
+                let _ = game.use_power_up(*player_id, *power_up, target.clone());
+            }
+            Self::Hint { player_id } => {
+                game.generate_hint(*player_id);
+            }
+            Self::UseWord(word) => game.use_word(word),
+            Self::SetConnected {
+                player_id,
+                connected,
+            } => {
+                let _ = game.set_player_connected(*player_id, *connected);
+            }
+            Self::GridChanged(changes) => {
+                for change in changes {
+                    if let Some(cell) = game.get_cell_mut(change.position) {
+                        cell.multiplier = change.multiplier;
+                        cell.has_gem = change.has_gem;
+                    }
+                }
+            }
+            Self::RefreshCells(path) => {
+                game.refresh_cells(path);
+            }
+            Self::StartTimerVote { initiator_id } => {
+                let _ = game.start_timer_vote(*initiator_id);
+            }
+            Self::AddTimerVote { voter_id } => {
+                let _ = game.add_timer_vote(*voter_id);
+            }
+            Self::StartKickVote {
+                initiator_id,
+                target_id,
+            } => {
+                let _ = game.start_kick_vote(*initiator_id, *target_id);
+            }
+            Self::AddKickVote { voter_id } => {
+                let _ = game.add_kick_vote(*voter_id);
+            }
+            Self::ChallengeWord { challenger_id } => {
+                let _ = game.challenge_word(*challenger_id);
+            }
+            Self::ResolveChallenge(valid) => {
+                let _ = game.resolve_challenge(*valid);
+            }
+            Self::Pause(reason) => {
+                let _ = game.pause(reason);
+            }
+            Self::Resume => {
+                let _ = game.resume();
+            }
+            Self::ForceSkip { by } => {
+                let _ = game.force_skip_turn(*by);
+            }
+            Self::AdjustScore {
+                player_id,
+                amount,
+                reason,
+                by,
+            } => {
+                let _ = match by {
+                    Some(by) => game.force_adjust_score(*player_id, *amount, reason, *by),
+                    None => game.adjust_score(*player_id, *amount, reason),
+                };
+            }
+            Self::RemovePlayer { player_id, by } => {
+                let _ = game.remove_player(*player_id, *by);
+            }
+            Self::ForceEnd { by } => {
+                let _ = game.force_end(*by);
+            }
+            Self::End => {
+                let _ = game.end();
+            }
+            Self::TimeUp => {
+                if game.status.is_started() {
+                    game.time_up();
+                }
+            }
+            Self::Cancel(reason) => game.cancel(reason.clone()),
+            Self::Abort { by } => {
+                let _ = game.abort(*by);
+            }
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut json = match self {
+            Self::Join(player) => serde_json::json!({
+                "player_id": player.player_id,
+                "user_id": player.user_id,
+                "username": player.username,
+                "avatar_url": player.avatar_url,
+                "rating": player.rating
+            }),
+            Self::PromoteSpectator {
+                spectator,
+                starting_score,
+            } => serde_json::json!({
+                "player_id": spectator.player_id,
+                "user_id": spectator.user_id,
+                "username": spectator.username,
+                "avatar_url": spectator.avatar_url,
+                "starting_score": starting_score.as_str()
+            }),
+            Self::SetTurnOrder(order) => serde_json::json!({ "order": order }),
+            Self::SetTurnDuration(limit) => serde_json::json!({
+                "seconds": limit.map(|d| d.num_seconds())
+            }),
+            Self::PlayWord { player_id, path } => serde_json::json!({
+                "player_id": player_id,
+                "path": path.iter().map(|p| p.to_json()).collect::<Vec<_>>()
+            }),
+            Self::PlayBlitzWord {
+                player_id,
+                path,
+                at,
+            } => serde_json::json!({
+                "player_id": player_id,
+                "path": path.iter().map(|p| p.to_json()).collect::<Vec<_>>(),
+                "at": at.to_rfc3339()
+            }),
+            Self::Pass { player_id } | Self::Forfeit { player_id } | Self::Hint { player_id } => {
+                serde_json::json!({ "player_id": player_id })
+            }
+            Self::ExtendTurn { player_id, seconds } => serde_json::json!({
+                "player_id": player_id,
+                "seconds": seconds
+            }),
+            Self::UsePowerUp {
+                player_id,
+                power_up,
+                target,
+            } => serde_json::json!({
+                "player_id": player_id,
+                "power_up": power_up.as_str(),
+                "target": target.to_json()
+            }),
+            Self::UseWord(word) => serde_json::json!({ "word": word }),
+            Self::SetConnected {
+                player_id,
+                connected,
+            } => serde_json::json!({
+                "player_id": player_id,
+                "connected": connected
+            }),
+            Self::GridChanged(changes) => serde_json::json!({
+                "changes": changes.iter().map(|c| c.to_json()).collect::<Vec<_>>()
+            }),
+            Self::RefreshCells(path) => serde_json::json!({
+                "path": path.iter().map(|p| p.to_json()).collect::<Vec<_>>()
+            }),
+            Self::StartTimerVote { initiator_id } => {
+                serde_json::json!({ "initiator_id": initiator_id })
+            }
+            Self::AddTimerVote { voter_id } | Self::AddKickVote { voter_id } => {
+                serde_json::json!({ "voter_id": voter_id })
+            }
+            Self::StartKickVote {
+                initiator_id,
+                target_id,
+            } => serde_json::json!({
+                "initiator_id": initiator_id,
+                "target_id": target_id
+            }),
+            Self::ChallengeWord { challenger_id } => {
+                serde_json::json!({ "challenger_id": challenger_id })
+            }
+            Self::ResolveChallenge(valid) => serde_json::json!({ "valid": valid }),
+            Self::Pause(reason) => serde_json::json!({ "reason": reason }),
+            Self::Cancel(reason) => serde_json::json!({ "reason": reason.to_json() }),
+            Self::ForceSkip { by } | Self::ForceEnd { by } | Self::Abort { by } => {
+                serde_json::json!({ "by": by })
+            }
+            Self::AdjustScore {
+                player_id,
+                amount,
+                reason,
+                by,
+            } => serde_json::json!({
+                "player_id": player_id,
+                "amount": amount,
+                "reason": reason,
+                "by": by
+            }),
+            Self::RemovePlayer { player_id, by } => serde_json::json!({
+                "player_id": player_id,
+                "by": by
+            }),
+            Self::Start
+            | Self::AdvanceTurn
+            | Self::ExpireTurn
+            | Self::Resume
+            | Self::End
+            | Self::TimeUp => serde_json::json!({}),
+        };
+        json["type"] = self.as_str().into();
+        json
+    }
+}
+
+/// Steps recorded on a game, with the state they start from.
+#[derive(Debug, Clone)]
+pub(crate) struct ReplayLog {
+    pub(crate) grid: Grid,
+    pub(crate) config: GameConfig,
+    pub(crate) max_rounds: u8,
+    pub(crate) steps: Vec<ReplayStep>,
+}
+
+impl ReplayLog {
+    pub(crate) fn new(grid: &Grid, config: &GameConfig, max_rounds: u8) -> Self {
+        Self {
+            grid: grid.clone(),
+            config: config.clone(),
+            max_rounds,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Capture the starting state (again, if it changed before the start).
+    pub(crate) fn snapshot(&mut self, grid: &Grid, config: &GameConfig, max_rounds: u8) {
+        self.grid = grid.clone();
+        self.config = config.clone();
+        self.max_rounds = max_rounds;
+    }
+}
+
+/// A recorded game, from `Game::export_replay`.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    pub game_id: String,
+    pub lobby_id: String,
+    /// Seed of the game's random number generator
+    pub seed: u64,
+    /// Grid before the first step
+    pub grid: Grid,
+    pub config: GameConfig,
+    pub max_rounds: u8,
+    pub locale: String,
+    pub dictionary: Option<Arc<dyn Dictionary>>,
+    pub steps: Vec<ReplayStep>,
+}
+
+impl Replay {
+    /// Number of recorded steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Check if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Rebuild the game as it was after the first `step` steps (all of them
+    /// if `step` is past the end).
+    ///
+    /// Submission rate limits are lifted, since the steps are replayed
+    /// back to back.
+    pub fn reconstruct_at(&self, step: usize) -> Game {
+        let config = GameConfig {
+            submissions: SubmissionLimits::default(),
+            ..self.config.clone()
+        };
+        let mut game = Game::new(self.game_id.clone(), self.lobby_id.clone(), self.grid.clone())
+            .with_config(config)
+            .with_locale(self.locale.clone())
+            .with_seed(self.seed);
+        game.max_rounds = self.max_rounds;
+        if let Some(dictionary) = &self.dictionary {
+            game = game.with_dictionary(Arc::clone(dictionary));
+        }
+
+        for s in self.steps.iter().take(step) {
+            s.apply(&mut game);
+        }
+        game
+    }
+
+    /// Rebuild the final state of the game.
+    pub fn reconstruct(&self) -> Game {
+        self.reconstruct_at(self.steps.len())
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "game_id": self.game_id,
+            "lobby_id": self.lobby_id,
+            "seed": self.seed.to_string(),
+            "grid": self.grid.iter().map(|row| {
+                row.iter().map(|cell| cell.to_json()).collect::<Vec<_>>()
+            }).collect::<Vec<_>>(),
+            "max_rounds": self.max_rounds,
+            "locale": self.locale,
+            "steps": self.steps.iter().map(|s| s.to_json()).collect::<Vec<_>>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::blitz::DuplicateRule;
+    use crate::state::game::{GameMode, GameStatus, GridCell};
+
+    fn player(player_id: i64, turn_order: u8) -> GamePlayer {
+        let name = format!("player{}", player_id);
+        GamePlayer::new(player_id, name.clone(), name, None, turn_order)
+    }
+
+    fn grid() -> Grid {
+        std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')))
+    }
+
+    #[test]
+    fn test_reconstruct_at() {
+        let mut grid = grid();
+        grid[0][0] = GridCell::new('C').with_gem();
+        grid[0][2] = GridCell::new('T');
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid)
+            .with_seed(7)
+            .with_replay_recording();
+        game.add_player(player(1, 0)).unwrap();
+        game.add_player(player(2, 1)).unwrap();
+        game.start().unwrap();
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        game.submit_word(1, &path).unwrap();
+        game.pass_turn(2).unwrap();
+        game.pause("break").unwrap();
+        game.resume().unwrap();
+        game.end().unwrap();
+
+        let replay = game.export_replay().unwrap();
+        assert_eq!(replay.len(), 8);
+        assert_eq!(replay.seed, 7);
+
+        let end = replay.reconstruct();
+        assert_eq!(end.status, GameStatus::Finished);
+        assert_eq!(end.get_player(1).unwrap().score(), game.get_player(1).unwrap().score());
+        assert_eq!(end.to_json()["grid"], game.to_json()["grid"]);
+
+        // After the play, before the pass
+        let mid = replay.reconstruct_at(4);
+        assert_eq!(mid.status, GameStatus::InProgress);
+        assert!(mid.is_player_turn(2));
+        assert!(mid.used_words.contains("CAT"));
+        assert!(!mid.grid[0][0].has_gem);
+
+        let json = replay.to_json();
+        assert_eq!(json["steps"][3]["type"], "play_word");
+        assert_eq!(json["steps"][5]["reason"], "break");
+    }
+
+    #[test]
+    fn test_replay_max_duration() {
+        let config = GameConfig {
+            max_duration: Some(chrono::Duration::minutes(30)),
+            ..Default::default()
+        };
+        let later = chrono::Utc::now() + chrono::Duration::hours(1);
+
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid())
+            .with_config(config.clone())
+            .with_replay_recording();
+        game.add_player(player(1, 0)).unwrap();
+        game.start().unwrap();
+        assert!(game.tick(later));
+        let replay = game.export_replay().unwrap();
+        assert_eq!(replay.to_json()["steps"][2]["type"], "time_up");
+        assert_eq!(replay.reconstruct().status, GameStatus::Finished);
+
+        // A game that never started is cancelled
+        let mut idle = Game::new("game-2".to_string(), "lobby-1".to_string(), grid())
+            .with_config(config)
+            .with_replay_recording();
+        idle.add_player(player(1, 0)).unwrap();
+        assert!(idle.tick(later));
+        let end = idle.export_replay().unwrap().reconstruct();
+        assert_eq!(end.status, GameStatus::Cancelled);
+        assert_eq!(end.cancel_reason, Some(CancelReason::TimedOut));
+    }
+
+    #[test]
+    fn test_replay_blitz() {
+        let config = GameConfig {
+            mode: GameMode::Blitz {
+                window: chrono::Duration::minutes(2),
+                duplicates: DuplicateRule::EarliestWins,
+            },
+            ..Default::default()
+        };
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid())
+            .with_config(config)
+            .with_replay_recording();
+        game.add_player(player(1, 0)).unwrap();
+        game.add_player(player(2, 1)).unwrap();
+        game.start().unwrap();
+        let row = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        game.submit_blitz_word(1, &row).unwrap();
+        game.submit_blitz_word(2, &row).unwrap();
+        assert!(game.tick(game.ends_at.unwrap()));
+
+        // The closing window settles duplicates in the reconstruction too
+        let end = game.export_replay().unwrap().reconstruct();
+        assert_eq!(end.status, GameStatus::Finished);
+        for player_id in 1..=2 {
+            let score = |g: &Game| g.get_player(player_id).unwrap().score();
+            assert_eq!(score(&end), score(&game));
+        }
+        assert!(end.get_player(1).unwrap().score() > 0);
+    }
+
+    #[test]
+    fn test_replay_solo_time_limit() {
+        let config = GameConfig {
+            mode: GameMode::Solo {
+                time_limit: Some(chrono::Duration::minutes(3)),
+            },
+            ..Default::default()
+        };
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid())
+            .with_config(config)
+            .with_replay_recording();
+        game.add_player(player(1, 0)).unwrap();
+        game.start().unwrap();
+        let row = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        game.submit_word(1, &row).unwrap();
+        assert!(game.tick(game.ends_at.unwrap()));
+
+        let end = game.export_replay().unwrap().reconstruct();
+        assert_eq!(end.status, GameStatus::Finished);
+        assert_eq!(end.get_player(1).unwrap().score(), game.get_player(1).unwrap().score());
+    }
+}
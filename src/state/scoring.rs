@@ -0,0 +1,263 @@
+//! Word scoring.
+//!
+//! A word scores the sum of its letter values, with `DoubleLetter` and
+//! `TripleLetter` cells multiplying their own letter and each `DoubleWord`
+//! cell doubling the whole word. Every gem cell on the path awards one gem.
+//! `ScoringRules` on the game config can add flat bonuses on top, and a
+//! player's `Handicap` adjusts what they earn.
+
+use std::collections::HashSet;
+
+use super::game::{Grid, Multiplier, Position};
+
+/// Letters worth at least this much count as rare for `rarity_bonus`.
+pub const RARE_LETTER_VALUE: u8 = 3;
+
+/// Kinds of flat scoring bonus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoringBonus {
+    LongWord,
+    Rarity,
+    DistinctLetters,
+    /// Extra points from a `Handicap::Multiplier`
+    Handicap,
+}
+
+impl ScoringBonus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LongWord => "long_word",
+            Self::Rarity => "rarity",
+            Self::DistinctLetters => "distinct_letters",
+            Self::Handicap => "handicap",
+        }
+    }
+}
+
+/// A per-player scoring adjustment, to even out mixed-skill games.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handicap {
+    /// Scale each word's points by this percentage (e.g. 150 = half again)
+    Multiplier { percent: u32 },
+    /// Points added at the end of every round
+    RoundBonus(i32),
+}
+
+impl Handicap {
+    /// Apply a `Multiplier` handicap to a word's score, as a bonus.
+    pub fn apply(&self, score: &mut WordScore) {
+        let Self::Multiplier { percent } = self else {
+            return;
+        };
+        let extra = score.points * *percent as i32 / 100 - score.points;
+        if extra != 0 {
+            score.bonuses.push((ScoringBonus::Handicap, extra));
+            score.points += extra;
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Multiplier { percent } => {
+                serde_json::json!({ "type": "multiplier", "percent": percent })
+            }
+            Self::RoundBonus(points) => {
+                serde_json::json!({ "type": "round_bonus", "points": points })
+            }
+        }
+    }
+}
+
+/// Flat bonuses added after multipliers. A bonus of 0 is off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoringRules {
+    /// Points for words of at least `long_word_length` letters
+    pub long_word_bonus: i32,
+    pub long_word_length: usize,
+    /// Points for words whose letters are all worth `RARE_LETTER_VALUE` or more
+    pub rarity_bonus: i32,
+    /// Points for words using at least `distinct_letters` different letters
+    pub distinct_letters_bonus: i32,
+    pub distinct_letters: usize,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            long_word_bonus: 0,
+            long_word_length: 6,
+            rarity_bonus: 0,
+            distinct_letters_bonus: 0,
+            distinct_letters: 7,
+        }
+    }
+}
+
+impl ScoringRules {
+    /// Score a path, then add any bonuses it earns.
+    pub fn score(&self, grid: &Grid, path: &[Position]) -> WordScore {
+        let mut score = score_path(grid, path);
+        let cells: Vec<_> = path
+            .iter()
+            .filter(|p| p.is_valid())
+            .map(|p| &grid[p.row][p.col])
+            .collect();
+        if cells.is_empty() {
+            return score;
+        }
+
+        let distinct: HashSet<char> = cells.iter().map(|c| c.letter).collect();
+        let earned = [
+            (
+                ScoringBonus::LongWord,
+                self.long_word_bonus,
+                cells.len() >= self.long_word_length,
+            ),
+            (
+                ScoringBonus::Rarity,
+                self.rarity_bonus,
+                cells.iter().all(|c| c.value >= RARE_LETTER_VALUE),
+            ),
+            (
+                ScoringBonus::DistinctLetters,
+                self.distinct_letters_bonus,
+                distinct.len() >= self.distinct_letters,
+            ),
+        ];
+        for (bonus, points, applies) in earned {
+            if applies && points != 0 {
+                score.bonuses.push((bonus, points));
+                score.points += points;
+            }
+        }
+        score
+    }
+}
+
+/// Breakdown of a word's score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordScore {
+    pub word: String,
+    /// Letter values after letter multipliers
+    pub letter_points: i32,
+    /// Product of the word multipliers on the path
+    pub word_multiplier: i32,
+    /// Flat bonuses from `ScoringRules`, and any handicap adjustment
+    pub bonuses: Vec<(ScoringBonus, i32)>,
+    /// Final score (`letter_points * word_multiplier`, plus bonuses)
+    pub points: i32,
+    /// Gems collected from the path
+    pub gems: i32,
+}
+
+impl WordScore {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "word": self.word,
+            "letter_points": self.letter_points,
+            "word_multiplier": self.word_multiplier,
+            "bonuses": self.bonuses.iter().map(|(bonus, points)| serde_json::json!({
+                "bonus": bonus.as_str(),
+                "points": points
+            })).collect::<Vec<_>>(),
+            "points": self.points,
+            "gems": self.gems
+        })
+    }
+}
+
+/// Score the cells along a path.
+///
+/// The path isn't validated; positions off the board are skipped. Use
+/// `Game::score_path` to validate and score in one step. No bonuses are
+/// applied; see [`ScoringRules::score`].
+pub fn score_path(grid: &Grid, path: &[Position]) -> WordScore {
+    let mut word = String::new();
+    let mut letter_points = 0;
+    let mut word_multiplier = 1;
+    let mut gems = 0;
+
+    for cell in path
+        .iter()
+        .filter(|p| p.is_valid())
+        .map(|p| &grid[p.row][p.col])
+    {
+        word.extend(cell.letters());
+        let value = cell.value as i32;
+        letter_points += match cell.multiplier {
+            Some(Multiplier::DoubleLetter) => value * 2,
+            Some(Multiplier::TripleLetter) => value * 3,
+            Some(Multiplier::DoubleWord) => {
+                word_multiplier *= 2;
+                value
+            }
+            None => value,
+        };
+        if cell.has_gem {
+            gems += 1;
+        }
+    }
+
+    WordScore {
+        word,
+        letter_points,
+        word_multiplier,
+        bonuses: Vec::new(),
+        points: letter_points * word_multiplier,
+        gems,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::game::GridCell;
+
+    #[test]
+    fn test_score_path_multipliers() {
+        let mut grid: Grid = std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')));
+        grid[0][0] = GridCell::new('C').with_multiplier(Multiplier::TripleLetter);
+        grid[0][1] = GridCell::new('A').with_multiplier(Multiplier::DoubleWord).with_gem();
+        grid[0][2] = GridCell::new('T').with_gem();
+
+        let path = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        let score = score_path(&grid, &path);
+        assert_eq!(score.word, "CAT");
+        // C=3*3, A=1, T=1, doubled
+        assert_eq!(score.letter_points, 11);
+        assert_eq!(score.points, 22);
+        assert_eq!(score.gems, 2);
+
+        grid[1][1] = GridCell::new('S').with_multiplier(Multiplier::DoubleLetter);
+        let score = score_path(&grid, &[Position::new(1, 1)]);
+        assert_eq!((score.points, score.word_multiplier), (2, 1));
+    }
+
+    #[test]
+    fn test_scoring_rules_bonuses() {
+        let mut grid: Grid = std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')));
+        for (col, letter) in "BCMPB".chars().enumerate() {
+            grid[0][col] = GridCell::new(letter);
+        }
+        let row: Vec<Position> = (0..5).map(|col| Position::new(0, col)).collect();
+        let rules = ScoringRules {
+            long_word_bonus: 10,
+            long_word_length: 5,
+            rarity_bonus: 5,
+            distinct_letters_bonus: 7,
+            distinct_letters: 5,
+        };
+
+        // BCMPB: 15 points, long and rare but only 4 distinct letters
+        let score = rules.score(&grid, &row);
+        assert_eq!(
+            score.bonuses,
+            vec![(ScoringBonus::LongWord, 10), (ScoringBonus::Rarity, 5)]
+        );
+        assert_eq!(score.points, 30);
+
+        // Off by default
+        assert!(ScoringRules::default().score(&grid, &row).bonuses.is_empty());
+        assert_eq!(rules.score(&grid, &row[..3]).bonuses, vec![(ScoringBonus::Rarity, 5)]);
+    }
+}
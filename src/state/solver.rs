@@ -0,0 +1,215 @@
+//! Board solver.
+//!
+//! Enumerates every dictionary word that can be traced on a grid by a
+//! depth-first search over adjacent cells, pruning branches whose letters
+//! don't prefix any word. Paths are scored like plays (see `scoring`), so the
+//! path kept for each word is the one a player would want.
+
+use std::collections::HashMap;
+
+use super::dictionary::Dictionary;
+use super::game::{Grid, Position, GRID_SIZE};
+use super::scoring;
+
+/// A word found on the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundWord {
+    pub word: String,
+    pub path: Vec<Position>,
+    /// Score of the path, multipliers included
+    pub points: i32,
+}
+
+impl FoundWord {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "word": self.word,
+            "path": self.path.iter().map(|p| p.to_json()).collect::<Vec<_>>(),
+            "points": self.points
+        })
+    }
+}
+
+/// Find all words of at least `min_len` letters on the grid.
+///
+/// Each word appears once, with its highest-scoring path. Results are sorted by
+/// points (descending), then alphabetically.
+pub fn solve(grid: &Grid, dict: &dyn Dictionary, min_len: usize) -> Vec<FoundWord> {
+    let mut search = Search {
+        grid,
+        dict,
+        min_len,
+        visited: [[false; GRID_SIZE]; GRID_SIZE],
+        path: Vec::new(),
+        word: String::new(),
+        found: HashMap::new(),
+    };
+
+    for row in 0..GRID_SIZE {
+        for col in 0..GRID_SIZE {
+            search.visit(Position::new(row, col));
+        }
+    }
+
+    let mut words: Vec<FoundWord> = search.found.into_values().collect();
+    words.sort_by(|a, b| b.points.cmp(&a.points).then_with(|| a.word.cmp(&b.word)));
+    words
+}
+
+/// DFS state.
+struct Search<'a> {
+    grid: &'a Grid,
+    dict: &'a dyn Dictionary,
+    min_len: usize,
+    visited: [[bool; GRID_SIZE]; GRID_SIZE],
+    path: Vec<Position>,
+    word: String,
+    found: HashMap<String, FoundWord>,
+}
+
+impl Search<'_> {
+    fn visit(&mut self, pos: Position) {
+        let cell = &self.grid[pos.row][pos.col];
+        if cell.blocked {
+            return;
+        }
+        self.visited[pos.row][pos.col] = true;
+        self.path.push(pos);
+        let len = self.word.len();
+        self.word.extend(cell.letters());
+
+        if self.dict.is_prefix(&self.word) {
+            if self.path.len() >= self.min_len && self.dict.contains(&self.word) {
+                self.record();
+            }
+            for next in pos.neighbors() {
+                if !self.visited[next.row][next.col] {
+                    self.visit(next);
+                }
+            }
+        }
+
+        self.word.truncate(len);
+        self.path.pop();
+        self.visited[pos.row][pos.col] = false;
+    }
+
+    fn record(&mut self) {
+        let points = scoring::score_path(self.grid, &self.path).points;
+
+        let better = self
+            .found
+            .get(&self.word)
+            .is_none_or(|existing| points > existing.points);
+        if better {
+            self.found.insert(
+                self.word.clone(),
+                FoundWord {
+                    word: self.word.clone(),
+                    path: self.path.clone(),
+                    points,
+                },
+            );
+        }
+    }
+}
+
+/// How playable a board is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GridQuality {
+    /// Number of distinct valid words
+    pub word_count: usize,
+    /// Length of the longest valid word
+    pub max_word_length: usize,
+    /// Sum of points over all valid words
+    pub total_points: i32,
+}
+
+impl GridQuality {
+    /// Measure quality from solver output.
+    pub fn from_words(words: &[FoundWord]) -> Self {
+        Self {
+            word_count: words.len(),
+            max_word_length: words.iter().map(|w| w.path.len()).max().unwrap_or(0),
+            total_points: words.iter().map(|w| w.points).sum(),
+        }
+    }
+
+    /// Check if every metric is at least the corresponding minimum.
+    pub fn meets(&self, min: &GridQuality) -> bool {
+        self.word_count >= min.word_count
+            && self.max_word_length >= min.max_word_length
+            && self.total_points >= min.total_points
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "word_count": self.word_count,
+            "max_word_length": self.max_word_length,
+            "total_points": self.total_points
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::dictionary::WordList;
+    use crate::state::game::{GridCell, Multiplier};
+
+    fn grid_from_rows(rows: [&str; GRID_SIZE]) -> Grid {
+        std::array::from_fn(|r| {
+            let letters: Vec<char> = rows[r].chars().collect();
+            std::array::from_fn(|c| GridCell::new(letters[c]))
+        })
+    }
+
+    #[test]
+    fn test_solve_finds_words() {
+        let grid = grid_from_rows(["CAXXX", "TXXXX", "XXXXX", "XXXXX", "XXXXX"]);
+        let dict = WordList::from_words(["cat", "act", "at", "tax", "dog"]);
+
+        let words: Vec<String> = solve(&grid, &dict, 3).into_iter().map(|w| w.word).collect();
+
+        // TAX: 1 + 1 + 8 beats CAT/ACT: 3 + 1 + 1
+        assert_eq!(words, vec!["TAX", "ACT", "CAT"]);
+    }
+
+    #[test]
+    fn test_solve_prefers_multipliers() {
+        let mut grid = grid_from_rows(["CATXX", "XXXXX", "XXXXX", "XXXXX", "XXXXX"]);
+        grid[1][1] = GridCell::new('A').with_multiplier(Multiplier::DoubleWord);
+        let dict = WordList::from_words(["cat"]);
+
+        let words = solve(&grid, &dict, 3);
+        assert_eq!(words[0].path[1], Position::new(1, 1));
+        assert_eq!(words[0].points, 10);
+    }
+
+    #[test]
+    fn test_solve_no_cell_reuse() {
+        let grid = grid_from_rows(["ABXXX", "XXXXX", "XXXXX", "XXXXX", "XXXXX"]);
+        let dict = WordList::from_words(["aba"]);
+
+        assert!(solve(&grid, &dict, 3).is_empty());
+    }
+
+    #[test]
+    fn test_quality_meets() {
+        let grid = grid_from_rows(["CAXXX", "TXXXX", "XXXXX", "XXXXX", "XXXXX"]);
+        let dict = WordList::from_words(["cat", "act"]);
+        let quality = GridQuality::from_words(&solve(&grid, &dict, 3));
+
+        assert_eq!(quality.word_count, 2);
+        assert_eq!(quality.max_word_length, 3);
+        assert_eq!(quality.total_points, 10);
+        assert!(quality.meets(&GridQuality {
+            word_count: 2,
+            ..Default::default()
+        }));
+        assert!(!quality.meets(&GridQuality {
+            max_word_length: 4,
+            ..Default::default()
+        }));
+    }
+}
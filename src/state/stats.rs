@@ -0,0 +1,348 @@
+//! End-of-game results and statistics.
+//!
+//! `GameResult` ranks the players for `Game::end`; `GameStats` is computed
+//! from the move log and per-player turn timing when a game finishes, for
+//! results screens.
+
+use super::achievements::Achievement;
+use super::game::{Game, GameMode, ScoreField, ScoreSource, TurnAction};
+
+/// A player's line in the results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultEntry {
+    /// 1-based; tied players share a placement
+    pub placement: usize,
+    pub player_id: i64,
+    pub user_id: String,
+    pub username: String,
+    pub score: i32,
+    pub gems: i32,
+    /// Words played, in order
+    pub words: Vec<String>,
+    /// Part of `score` that came from a handicap
+    pub handicap_points: i32,
+    /// Time spent on finished turns, pauses excluded
+    pub turn_time: chrono::Duration,
+    /// Average time per finished turn (None if no turn finished)
+    pub average_turn_time: Option<chrono::Duration>,
+    /// Turns that ran out of time
+    pub timeouts: u32,
+    /// In the order they were unlocked
+    pub achievements: Vec<Achievement>,
+    pub forfeited: bool,
+    /// False if `score` or `gems` don't match the player's audit trail
+    pub score_verified: bool,
+}
+
+impl ResultEntry {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "placement": self.placement,
+            "player_id": self.player_id,
+            "user_id": self.user_id,
+            "username": self.username,
+            "score": self.score,
+            "gems": self.gems,
+            "words": self.words,
+            "handicap_points": self.handicap_points,
+            "turn_time_ms": self.turn_time.num_milliseconds(),
+            "average_turn_ms": self.average_turn_time.map(|d| d.num_milliseconds()),
+            "timeouts": self.timeouts,
+            "achievements": self.achievements.iter().map(|a| a.as_str()).collect::<Vec<_>>(),
+            "forfeited": self.forfeited,
+            "score_verified": self.score_verified
+        })
+    }
+}
+
+/// Ranked results of a game, from `Game::end` or `Game::result`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameResult {
+    /// Highest score first, with players who forfeited ranked last
+    pub entries: Vec<ResultEntry>,
+    /// Players sharing first place (none if everyone forfeited)
+    pub winners: Vec<i64>,
+    /// Time from start to end (None until the game has ended)
+    pub duration: Option<chrono::Duration>,
+    /// Shared score of a co-op game
+    pub team_score: Option<i32>,
+}
+
+impl GameResult {
+    /// Rank the players of a game in its current state.
+    pub fn from_game(game: &Game) -> Self {
+        let mut players: Vec<_> = game.players().collect();
+        players.sort_by_key(|p| (p.forfeited, std::cmp::Reverse(p.score()), p.turn_order));
+
+        let mut entries: Vec<ResultEntry> = Vec::with_capacity(players.len());
+        for (index, p) in players.into_iter().enumerate() {
+            let placement = match entries.last() {
+                Some(prev) if prev.score == p.score() && prev.forfeited == p.forfeited => {
+                    prev.placement
+                }
+                _ => index + 1,
+            };
+            entries.push(ResultEntry {
+                placement,
+                player_id: p.player_id,
+                user_id: p.user_id.clone(),
+                username: p.username.clone(),
+                score: p.score(),
+                gems: p.gems(),
+                words: p.words_played.clone(),
+                handicap_points: p
+                    .score_history()
+                    .iter()
+                    .filter(|d| d.field == ScoreField::Score)
+                    .filter(|d| matches!(&d.source, ScoreSource::Bonus(b) if b == "handicap"))
+                    .map(|d| d.amount)
+                    .sum(),
+                turn_time: p.turn_time,
+                average_turn_time: p.average_turn_time(),
+                timeouts: p.timeouts,
+                achievements: game
+                    .achievements()
+                    .unlocked(p.player_id)
+                    .iter()
+                    .map(|u| u.achievement)
+                    .collect(),
+                forfeited: p.forfeited,
+                score_verified: p.verify_score().is_ok(),
+            });
+        }
+        let winners = match game.config.mode {
+            GameMode::Coop { target, .. } => {
+                let won = target.is_none() || game.coop_target_reached();
+                entries
+                    .iter()
+                    .filter(|e| won && !e.forfeited)
+                    .map(|e| e.player_id)
+                    .collect()
+            }
+            _ => entries
+                .iter()
+                .filter(|e| e.placement == 1 && !e.forfeited)
+                .map(|e| e.player_id)
+                .collect(),
+        };
+
+        Self {
+            entries,
+            winners,
+            duration: game.started_at.zip(game.ended_at).map(|(start, end)| end - start),
+            team_score: game.config.mode.is_coop().then(|| game.team_score()),
+        }
+    }
+
+    /// Get a player's entry.
+    pub fn entry(&self, player_id: i64) -> Option<&ResultEntry> {
+        self.entries.iter().find(|e| e.player_id == player_id)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "entries": self.entries.iter().map(|e| e.to_json()).collect::<Vec<_>>(),
+            "winners": self.winners,
+            "duration_secs": self.duration.map(|d| d.num_seconds()),
+            "team_score": self.team_score
+        })
+    }
+}
+
+/// A single word play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordPlay {
+    pub player_id: i64,
+    pub word: String,
+    pub points: i32,
+}
+
+impl WordPlay {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "player_id": self.player_id,
+            "word": self.word,
+            "points": self.points
+        })
+    }
+}
+
+/// One player's statistics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerStats {
+    pub player_id: i64,
+    pub username: String,
+    pub score: i32,
+    /// Longest word played (the earliest one on ties)
+    pub longest_word: Option<String>,
+    /// Highest-scoring single play (the earliest one on ties)
+    pub best_play: Option<WordPlay>,
+    pub words_played: usize,
+    /// Gems picked up from the board (not counting gems spent)
+    pub gems_collected: i32,
+    /// Average time per finished turn (None if no turn finished)
+    pub average_turn_time: Option<chrono::Duration>,
+    pub forfeited: bool,
+}
+
+impl PlayerStats {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "player_id": self.player_id,
+            "username": self.username,
+            "score": self.score,
+            "longest_word": self.longest_word,
+            "best_play": self.best_play.as_ref().map(|p| p.to_json()),
+            "words_played": self.words_played,
+            "gems_collected": self.gems_collected,
+            "average_turn_ms": self.average_turn_time.map(|d| d.num_milliseconds()),
+            "forfeited": self.forfeited
+        })
+    }
+}
+
+/// Summary of a finished game, from `Game::stats`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameStats {
+    /// Per-player statistics, in turn order
+    pub players: Vec<PlayerStats>,
+    /// Highest-scoring play of the game
+    pub best_word: Option<WordPlay>,
+}
+
+impl GameStats {
+    /// Compute statistics for a game in its current state.
+    pub fn from_game(game: &Game) -> Self {
+        let mut players: Vec<PlayerStats> = game
+            .players()
+            .map(|p| PlayerStats {
+                player_id: p.player_id,
+                username: p.username.clone(),
+                score: p.score(),
+                longest_word: None,
+                best_play: None,
+                words_played: 0,
+                gems_collected: 0,
+                average_turn_time: p.average_turn_time(),
+                forfeited: p.forfeited,
+            })
+            .collect();
+        players.sort_by_key(|s| game.get_player(s.player_id).map(|p| p.turn_order));
+
+        let mut best_word: Option<WordPlay> = None;
+        for record in game.moves() {
+            let TurnAction::PlayWord {
+                word, points, gems, ..
+            } = &record.action
+            else {
+                continue;
+            };
+            let Some(stats) = players.iter_mut().find(|s| s.player_id == record.player_id) else {
+                continue;
+            };
+            let play = WordPlay {
+                player_id: record.player_id,
+                word: word.clone(),
+                points: *points,
+            };
+
+            stats.words_played += 1;
+            stats.gems_collected += gems;
+            if stats.longest_word.as_ref().is_none_or(|w| word.len() > w.len()) {
+                stats.longest_word = Some(word.clone());
+            }
+            if stats.best_play.as_ref().is_none_or(|p| play.points > p.points) {
+                stats.best_play = Some(play.clone());
+            }
+            if best_word.as_ref().is_none_or(|p| play.points > p.points) {
+                best_word = Some(play);
+            }
+        }
+
+        Self { players, best_word }
+    }
+
+    /// Get a player's statistics.
+    pub fn player(&self, player_id: i64) -> Option<&PlayerStats> {
+        self.players.iter().find(|s| s.player_id == player_id)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "players": self.players.iter().map(|s| s.to_json()).collect::<Vec<_>>(),
+            "best_word": self.best_word.as_ref().map(|p| p.to_json())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::game::{GamePlayer, GameStatus, Grid, GridCell, Position, ScoreSource};
+
+    #[test]
+    fn test_stats_on_end() {
+        let mut grid: Grid = std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')));
+        grid[0][0] = GridCell::new('C').with_gem();
+        grid[0][2] = GridCell::new('T');
+        grid[1][0] = GridCell::new('Z');
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid);
+        for (player_id, turn_order) in [(1, 0), (2, 1)] {
+            let name = format!("player{}", player_id);
+            let player = GamePlayer::new(player_id, name.clone(), name, None, turn_order);
+            game.add_player(player).unwrap();
+        }
+        game.start().unwrap();
+
+        let cat = [Position::new(0, 0), Position::new(0, 1), Position::new(0, 2)];
+        let zaaa = [
+            Position::new(1, 0),
+            Position::new(1, 1),
+            Position::new(1, 2),
+            Position::new(1, 3),
+        ];
+        game.submit_word(1, &cat).unwrap();
+        game.pass_turn(2).unwrap();
+        game.submit_word(1, &zaaa).unwrap();
+        assert!(game.stats().is_none());
+        game.end().unwrap();
+        assert_eq!(game.status, GameStatus::Finished);
+
+        let stats = game.stats().unwrap();
+        let first = stats.player(1).unwrap();
+        assert_eq!(first.words_played, 2);
+        assert_eq!(first.gems_collected, 1);
+        assert_eq!(first.longest_word.as_deref(), Some("ZAAA"));
+        assert_eq!(first.best_play.as_ref().unwrap().word, "ZAAA");
+        assert!(first.average_turn_time.is_some());
+        assert_eq!(stats.player(2).unwrap().words_played, 0);
+        let result = game.result();
+        let entry = result.entries.iter().find(|e| e.player_id == 1).unwrap();
+        assert_eq!(entry.average_turn_time, first.average_turn_time);
+        assert_eq!(entry.timeouts, 0);
+        assert_eq!(stats.best_word.as_ref().unwrap().player_id, 1);
+        assert_eq!(stats.players[0].player_id, 1);
+        assert_eq!(game.to_json()["stats"]["best_word"]["word"], "ZAAA");
+    }
+
+    #[test]
+    fn test_result_placements() {
+        let grid: Grid = std::array::from_fn(|_| std::array::from_fn(|_| GridCell::new('A')));
+        let mut game = Game::new("game-1".to_string(), "lobby-1".to_string(), grid);
+        for (player_id, score) in [(1, 5), (2, 9), (3, 9)] {
+            let name = format!("player{}", player_id);
+            let mut player = GamePlayer::new(player_id, name.clone(), name, None, player_id as u8);
+            player.add_score(ScoreSource::Adjustment("test".to_string()), score);
+            game.add_player(player).unwrap();
+        }
+        game.start().unwrap();
+
+        let result = game.end().unwrap();
+        let placements: Vec<(i64, usize)> =
+            result.entries.iter().map(|e| (e.player_id, e.placement)).collect();
+        assert_eq!(placements, vec![(2, 1), (3, 1), (1, 3)]);
+        assert_eq!(result.winners, vec![2, 3]);
+        assert!(result.duration.is_some());
+        assert_eq!(result.to_json()["entries"][2]["username"], "player1");
+    }
+}